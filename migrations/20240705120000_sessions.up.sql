@@ -0,0 +1,17 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS sessions (
+  id CHAR(36) PRIMARY KEY NOT NULL,
+  user_id CHAR(36) NOT NULL,
+  family_id CHAR(36) NOT NULL,
+  refresh_token_hash VARCHAR(64) NOT NULL UNIQUE,
+  user_agent VARCHAR(255) DEFAULT NULL,
+  ip_address VARCHAR(45) DEFAULT NULL,
+  expires_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  revoked_at TIMESTAMP NULL,
+  replaced_by CHAR(36) DEFAULT NULL,
+  created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+  updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+  INDEX idx_sessions_user_id (user_id),
+  INDEX idx_sessions_family_id (family_id),
+  CONSTRAINT fk_user_id_sessions FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
@@ -0,0 +1,9 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS revoked_tokens (
+  jti CHAR(36) PRIMARY KEY NOT NULL,
+  user_id CHAR(36) NOT NULL,
+  expires_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+  INDEX idx_revoked_tokens_expires_at (expires_at),
+  CONSTRAINT fk_user_id_revoked_tokens FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
@@ -0,0 +1,20 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS two_factor_auth (
+  user_id CHAR(36) PRIMARY KEY NOT NULL,
+  secret VARCHAR(64) NOT NULL,
+  confirmed_at TIMESTAMP NULL,
+  last_used_step BIGINT DEFAULT NULL,
+  created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+  updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+  CONSTRAINT fk_user_id_two_factor_auth FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS two_factor_recovery_codes (
+  id CHAR(36) PRIMARY KEY NOT NULL,
+  user_id CHAR(36) NOT NULL,
+  code_hash VARCHAR(64) NOT NULL,
+  used_at TIMESTAMP NULL,
+  created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+  INDEX idx_two_factor_recovery_codes_user_id (user_id),
+  CONSTRAINT fk_user_id_two_factor_recovery_codes FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
@@ -0,0 +1,11 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS otp_rate_limits (
+  action VARCHAR(16) NOT NULL,
+  subject_type VARCHAR(16) NOT NULL,
+  subject VARCHAR(64) NOT NULL,
+  attempts INT NOT NULL DEFAULT 0,
+  window_started_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  last_attempt_at TIMESTAMP NULL,
+  locked_until TIMESTAMP NULL,
+  PRIMARY KEY (action, subject_type, subject)
+);
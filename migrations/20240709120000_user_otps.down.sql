@@ -0,0 +1,6 @@
+-- Add down migration script here
+ALTER TABLE users
+ADD COLUMN mobile_token VARCHAR(5) AFTER mobile,
+ADD COLUMN mobile_token_expire_at TIMESTAMP NULL AFTER mobile_token;
+
+DROP TABLE IF EXISTS user_otps;
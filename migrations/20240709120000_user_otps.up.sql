@@ -0,0 +1,15 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS user_otps (
+  user_id CHAR(36) PRIMARY KEY NOT NULL,
+  code_hash VARCHAR(64) NOT NULL,
+  salt VARCHAR(32) NOT NULL,
+  attempts INT NOT NULL DEFAULT 0,
+  expires_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+  CONSTRAINT fk_user_id_user_otps FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+-- OTPs are no longer stored in plain text on the users table
+ALTER TABLE users
+DROP COLUMN mobile_token,
+DROP COLUMN mobile_token_expire_at;
@@ -0,0 +1,4 @@
+-- Add up migration script here
+-- Set for self-registered accounts until their first successful OTP verification
+ALTER TABLE users
+ADD COLUMN verification_expires_at TIMESTAMP NULL DEFAULT NULL AFTER protected;
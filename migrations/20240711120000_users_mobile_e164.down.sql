@@ -0,0 +1,4 @@
+-- Add down migration script here
+UPDATE users SET mobile = SUBSTRING(mobile, 2) WHERE mobile REGEXP '^\\+9665[0-9]{8}$';
+
+ALTER TABLE users MODIFY mobile VARCHAR(15) NOT NULL;
@@ -0,0 +1,6 @@
+-- Add up migration script here
+-- Mobiles are stored in E.164 format, which takes up to 16 characters with the leading +
+ALTER TABLE users MODIFY mobile VARCHAR(16) NOT NULL;
+
+-- Saudi mobiles used to be stored as 9665XXXXXXXX
+UPDATE users SET mobile = CONCAT('+', mobile) WHERE mobile REGEXP '^9665[0-9]{8}$';
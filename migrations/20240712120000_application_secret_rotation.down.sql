@@ -0,0 +1,4 @@
+-- Add down migration script here
+ALTER TABLE applications
+DROP COLUMN previous_app_secret,
+DROP COLUMN previous_secret_expires_at;
@@ -0,0 +1,5 @@
+-- Add up migration script here
+-- The previous secret keeps working until previous_secret_expires_at after a rotation
+ALTER TABLE applications
+ADD COLUMN previous_app_secret VARCHAR(255) NULL DEFAULT NULL AFTER app_secret,
+ADD COLUMN previous_secret_expires_at TIMESTAMP NULL DEFAULT NULL AFTER previous_app_secret;
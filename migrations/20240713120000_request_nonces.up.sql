@@ -0,0 +1,11 @@
+-- Add up migration script here
+-- Nonces of signed requests, kept until their timestamp is outside the skew window
+CREATE TABLE IF NOT EXISTS request_nonces (
+  application_id CHAR(36) NOT NULL,
+  nonce VARCHAR(128) NOT NULL,
+  expires_at TIMESTAMP NOT NULL,
+  created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  PRIMARY KEY (application_id, nonce),
+  INDEX idx_request_nonces_expires_at (expires_at),
+  CONSTRAINT fk_request_nonces_application FOREIGN KEY (application_id) REFERENCES applications (id) ON DELETE CASCADE
+);
@@ -0,0 +1,64 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS roles (
+  id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+  name VARCHAR(50) NOT NULL UNIQUE,
+  description VARCHAR(255) NULL,
+  protected TINYINT (1) NOT NULL DEFAULT 0,
+  created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
+);
+
+-- Permissions are checked by name in the routes, new ones are added by migrations
+CREATE TABLE IF NOT EXISTS permissions (
+  id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+  name VARCHAR(100) NOT NULL UNIQUE,
+  description VARCHAR(255) NULL,
+  created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS role_permissions (
+  role_id INT NOT NULL,
+  permission_id INT NOT NULL,
+  PRIMARY KEY (role_id, permission_id),
+  CONSTRAINT fk_role_permissions_role FOREIGN KEY (role_id) REFERENCES roles (id) ON DELETE CASCADE,
+  CONSTRAINT fk_role_permissions_permission FOREIGN KEY (permission_id) REFERENCES permissions (id) ON DELETE CASCADE
+);
+
+-- The roles that used to be hard-coded in UserRole keep their ids
+INSERT INTO
+  roles (id, name, description, protected)
+VALUES
+  (1, 'admin', 'Full access to the admin endpoints', 1),
+  (2, 'moderator', 'Manages the contents', 1),
+  (3, 'user', 'Regular user', 1);
+
+INSERT INTO
+  permissions (name, description)
+VALUES
+  ('contents.view', 'List and view contents'),
+  ('contents.create', 'Create contents'),
+  ('contents.update', 'Update contents'),
+  ('contents.delete', 'Delete contents'),
+  ('roles.manage', 'Manage roles and their permissions');
+
+-- Admins get every permission, moderators can view and edit contents
+INSERT INTO
+  role_permissions (role_id, permission_id)
+SELECT
+  1,
+  id
+FROM
+  permissions;
+
+INSERT INTO
+  role_permissions (role_id, permission_id)
+SELECT
+  2,
+  id
+FROM
+  permissions
+WHERE
+  name IN ('contents.view', 'contents.update');
+
+ALTER TABLE users
+ADD CONSTRAINT fk_users_role FOREIGN KEY (role_id) REFERENCES roles (id);
@@ -0,0 +1,17 @@
+-- Add up migration script here
+-- Audit trail of impersonation: one row when a token is issued and one per request made with it
+CREATE TABLE IF NOT EXISTS impersonation_logs (
+  id BIGINT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+  impersonator_id CHAR(36) NOT NULL,
+  user_id CHAR(36) NOT NULL,
+  token_id CHAR(36) NOT NULL,
+  event VARCHAR(20) NOT NULL,
+  method VARCHAR(10) NOT NULL,
+  path VARCHAR(2048) NOT NULL,
+  ip_address VARCHAR(45) NULL,
+  created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  INDEX idx_impersonation_logs_user (user_id, created_at),
+  INDEX idx_impersonation_logs_impersonator (impersonator_id, created_at),
+  CONSTRAINT fk_impersonation_logs_impersonator FOREIGN KEY (impersonator_id) REFERENCES users (id) ON DELETE CASCADE,
+  CONSTRAINT fk_impersonation_logs_user FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
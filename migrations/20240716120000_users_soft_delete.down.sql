@@ -0,0 +1,8 @@
+-- Add down migration script here
+DELETE FROM permissions
+WHERE
+  name = 'contents.purge';
+
+ALTER TABLE users
+DROP INDEX idx_users_deleted_at,
+DROP COLUMN deleted_at;
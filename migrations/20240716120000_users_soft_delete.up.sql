@@ -0,0 +1,20 @@
+-- Add up migration script here
+ALTER TABLE users
+ADD COLUMN deleted_at TIMESTAMP NULL AFTER updated_at,
+ADD INDEX idx_users_deleted_at (deleted_at);
+
+-- Purging trashed contents for good is kept for admins
+INSERT INTO
+  permissions (name, description)
+VALUES
+  ('contents.purge', 'Permanently delete trashed contents');
+
+INSERT INTO
+  role_permissions (role_id, permission_id)
+SELECT
+  1,
+  id
+FROM
+  permissions
+WHERE
+  name = 'contents.purge';
@@ -0,0 +1,4 @@
+-- Add down migration script here
+DELETE FROM permissions
+WHERE
+  name = 'records.protect';
@@ -0,0 +1,16 @@
+-- Add up migration script here
+-- Setting or clearing the protected flag of users, contents and applications
+INSERT INTO
+  permissions (name, description)
+VALUES
+  ('records.protect', 'Protect and unprotect records');
+
+INSERT INTO
+  role_permissions (role_id, permission_id)
+SELECT
+  1,
+  id
+FROM
+  permissions
+WHERE
+  name = 'records.protect';
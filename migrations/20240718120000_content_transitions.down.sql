@@ -0,0 +1,8 @@
+-- Add down migration script here
+DELETE FROM permissions
+WHERE
+  name = 'contents.publish';
+
+DROP INDEX idx_contents_record_state ON contents;
+
+DROP TABLE IF EXISTS content_transitions;
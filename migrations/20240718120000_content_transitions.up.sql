@@ -0,0 +1,35 @@
+-- Add up migration script here
+-- record_state of contents: 0 draft, 1 published, 2 in_review, 3 archived
+CREATE TABLE IF NOT EXISTS content_transitions (
+  id BIGINT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+  content_id CHAR(36) NOT NULL,
+  from_state TINYINT NOT NULL,
+  to_state TINYINT NOT NULL,
+  user_id CHAR(36) NOT NULL,
+  note VARCHAR(255) NULL,
+  created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  INDEX idx_content_transitions_content (content_id, created_at),
+  CONSTRAINT fk_content_transitions_content FOREIGN KEY (content_id) REFERENCES contents (id) ON DELETE CASCADE,
+  CONSTRAINT fk_content_transitions_user FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_contents_record_state ON contents (record_state);
+
+-- Reviewing, publishing and archiving contents
+INSERT INTO
+  permissions (name, description)
+VALUES
+  ('contents.publish', 'Review, publish and archive contents');
+
+-- Admins and moderators review and publish
+INSERT INTO
+  role_permissions (role_id, permission_id)
+SELECT
+  r.id,
+  p.id
+FROM
+  roles r,
+  permissions p
+WHERE
+  r.id IN (1, 2)
+  AND p.name = 'contents.publish';
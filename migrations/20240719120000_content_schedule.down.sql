@@ -0,0 +1,10 @@
+-- Add down migration script here
+DROP INDEX idx_contents_unpublish_at ON contents;
+
+DROP INDEX idx_contents_publish_at ON contents;
+
+ALTER TABLE contents
+DROP FOREIGN KEY fk_contents_scheduled_by,
+DROP COLUMN scheduled_by,
+DROP COLUMN unpublish_at,
+DROP COLUMN publish_at;
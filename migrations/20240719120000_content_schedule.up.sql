@@ -0,0 +1,11 @@
+-- Add up migration script here
+-- The scheduler publishes and archives contents when these times are reached, then clears them
+ALTER TABLE contents
+ADD COLUMN publish_at TIMESTAMP NULL AFTER protected,
+ADD COLUMN unpublish_at TIMESTAMP NULL AFTER publish_at,
+ADD COLUMN scheduled_by CHAR(36) NULL AFTER unpublish_at,
+ADD CONSTRAINT fk_contents_scheduled_by FOREIGN KEY (scheduled_by) REFERENCES users (id) ON DELETE SET NULL;
+
+CREATE INDEX idx_contents_publish_at ON contents (publish_at);
+
+CREATE INDEX idx_contents_unpublish_at ON contents (unpublish_at);
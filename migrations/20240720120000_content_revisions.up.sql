@@ -0,0 +1,28 @@
+-- Add up migration script here
+-- Snapshot of the editable fields of a content, written on create, update and rollback
+CREATE TABLE IF NOT EXISTS content_revisions (
+  id BIGINT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+  content_id CHAR(36) NOT NULL,
+  revision INT NOT NULL,
+  title VARCHAR(255) NOT NULL,
+  summary TEXT DEFAULT NULL,
+  details TEXT DEFAULT NULL,
+  user_id CHAR(36) NOT NULL,
+  created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  UNIQUE KEY uq_content_revisions_revision (content_id, revision),
+  CONSTRAINT fk_content_revisions_content FOREIGN KEY (content_id) REFERENCES contents (id) ON DELETE CASCADE,
+  CONSTRAINT fk_content_revisions_user FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+-- Existing contents start from their current fields
+INSERT INTO
+  content_revisions (content_id, revision, title, summary, details, user_id)
+SELECT
+  id,
+  1,
+  title,
+  summary,
+  details,
+  created_by
+FROM
+  contents;
@@ -0,0 +1,5 @@
+-- Add down migration script here
+DROP INDEX uq_contents_slug ON contents;
+
+ALTER TABLE contents
+DROP COLUMN slug;
@@ -0,0 +1,37 @@
+-- Add up migration script here
+-- Published contents are looked up by their slug on the public API
+ALTER TABLE contents
+ADD COLUMN slug VARCHAR(255) NULL AFTER title;
+
+UPDATE contents
+SET
+  slug = LOWER(
+    TRIM(
+      BOTH '-'
+      FROM
+        REGEXP_REPLACE(title, '[^A-Za-z0-9]+', '-')
+    )
+  );
+
+-- Titles that only differ by punctuation keep the start of their id
+UPDATE contents c
+JOIN (
+  SELECT
+    slug
+  FROM
+    contents
+  GROUP BY
+    slug
+  HAVING
+    COUNT(*) > 1
+) duplicates ON duplicates.slug = c.slug
+SET
+  c.slug = CONCAT(c.slug, '-', LEFT(c.id, 8));
+
+UPDATE contents
+SET
+  slug = id
+WHERE
+  slug = '';
+
+CREATE UNIQUE INDEX uq_contents_slug ON contents (slug);
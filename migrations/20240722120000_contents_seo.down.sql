@@ -0,0 +1,8 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS content_slug_redirects;
+
+ALTER TABLE contents
+DROP COLUMN canonical_url,
+DROP COLUMN meta_description,
+DROP COLUMN meta_title,
+ADD UNIQUE INDEX title (title);
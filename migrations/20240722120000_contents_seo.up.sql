@@ -0,0 +1,16 @@
+-- Add up migration script here
+-- Contents are unique by their slug, titles may repeat
+ALTER TABLE contents
+DROP INDEX title,
+ADD COLUMN meta_title VARCHAR(255) NULL AFTER content_image,
+ADD COLUMN meta_description VARCHAR(500) NULL AFTER meta_title,
+ADD COLUMN canonical_url VARCHAR(2048) NULL AFTER meta_description;
+
+-- Previous slugs of a content, the public API redirects them to the current one
+CREATE TABLE IF NOT EXISTS content_slug_redirects (
+  old_slug VARCHAR(255) PRIMARY KEY NOT NULL,
+  content_id CHAR(36) NOT NULL,
+  created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  INDEX idx_content_slug_redirects_content (content_id),
+  CONSTRAINT fk_content_slug_redirects_content FOREIGN KEY (content_id) REFERENCES contents (id) ON DELETE CASCADE
+);
@@ -0,0 +1,6 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS content_image_variants;
+
+ALTER TABLE contents
+DROP COLUMN content_image_height,
+DROP COLUMN content_image_width;
@@ -0,0 +1,21 @@
+-- Add up migration script here
+-- Size of the uploaded image, known before its variants are processed
+ALTER TABLE contents
+ADD COLUMN content_image_width INT UNSIGNED NULL AFTER content_image,
+ADD COLUMN content_image_height INT UNSIGNED NULL AFTER content_image_width;
+
+-- Resized copies of the content image, made in the background after the upload
+CREATE TABLE IF NOT EXISTS content_image_variants (
+  id BIGINT AUTO_INCREMENT PRIMARY KEY,
+  content_id CHAR(36) NOT NULL,
+  source_key VARCHAR(512) NOT NULL,
+  size VARCHAR(16) NOT NULL,
+  format VARCHAR(8) NOT NULL,
+  storage_key VARCHAR(512) NOT NULL,
+  width INT UNSIGNED NOT NULL,
+  height INT UNSIGNED NOT NULL,
+  created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  UNIQUE INDEX uq_content_image_variants_storage_key (storage_key),
+  INDEX idx_content_image_variants_content (content_id),
+  CONSTRAINT fk_content_image_variants_content FOREIGN KEY (content_id) REFERENCES contents (id) ON DELETE CASCADE
+);
@@ -0,0 +1,12 @@
+-- Add down migration script here
+DELETE FROM permissions
+WHERE
+  name LIKE 'media.%';
+
+DROP TABLE IF EXISTS content_gallery;
+
+ALTER TABLE contents
+DROP FOREIGN KEY fk_contents_image_media,
+DROP COLUMN content_image_media_id;
+
+DROP TABLE IF EXISTS media;
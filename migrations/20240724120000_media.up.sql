@@ -0,0 +1,63 @@
+-- Add up migration script here
+-- Uploaded files, shared by the contents. A file is stored once, found again by its SHA-256
+CREATE TABLE IF NOT EXISTS media (
+  id CHAR(36) PRIMARY KEY NOT NULL,
+  storage_key VARCHAR(512) NOT NULL,
+  file_name VARCHAR(255) NOT NULL,
+  mime_type VARCHAR(64) NOT NULL,
+  size_bytes BIGINT UNSIGNED NOT NULL,
+  width INT UNSIGNED NOT NULL,
+  height INT UNSIGNED NOT NULL,
+  alt_text VARCHAR(255) NULL,
+  checksum CHAR(64) NOT NULL,
+  uploaded_by CHAR(36) NULL,
+  created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+  UNIQUE INDEX uq_media_checksum (checksum),
+  UNIQUE INDEX uq_media_storage_key (storage_key),
+  INDEX idx_media_created_at (created_at),
+  CONSTRAINT fk_media_uploaded_by FOREIGN KEY (uploaded_by) REFERENCES users (id) ON DELETE SET NULL
+);
+
+-- The image of a content is a media, media in use cannot be deleted
+ALTER TABLE contents
+ADD COLUMN content_image_media_id CHAR(36) NULL AFTER content_image_height,
+ADD CONSTRAINT fk_contents_image_media FOREIGN KEY (content_image_media_id) REFERENCES media (id) ON DELETE RESTRICT;
+
+-- Images of the gallery of a content, in order
+CREATE TABLE IF NOT EXISTS content_gallery (
+  content_id CHAR(36) NOT NULL,
+  media_id CHAR(36) NOT NULL,
+  position INT UNSIGNED NOT NULL,
+  PRIMARY KEY (content_id, media_id),
+  INDEX idx_content_gallery_media (media_id),
+  CONSTRAINT fk_content_gallery_content FOREIGN KEY (content_id) REFERENCES contents (id) ON DELETE CASCADE,
+  CONSTRAINT fk_content_gallery_media FOREIGN KEY (media_id) REFERENCES media (id) ON DELETE RESTRICT
+);
+
+INSERT INTO
+  permissions (name, description)
+VALUES
+  ('media.view', 'List and view media'),
+  ('media.create', 'Upload media'),
+  ('media.update', 'Update media'),
+  ('media.delete', 'Delete media');
+
+-- Admins get every permission, moderators manage media like contents
+INSERT INTO
+  role_permissions (role_id, permission_id)
+SELECT
+  r.id,
+  p.id
+FROM
+  roles r,
+  permissions p
+WHERE
+  (
+    r.id = 1
+    AND p.name LIKE 'media.%'
+  )
+  OR (
+    r.id = 2
+    AND p.name IN ('media.view', 'media.create', 'media.update')
+  );
@@ -0,0 +1,4 @@
+-- Add up migration script here
+-- Storage key of the square avatar, users without one get their initials
+ALTER TABLE users
+ADD COLUMN avatar VARCHAR(512) NULL AFTER gender;
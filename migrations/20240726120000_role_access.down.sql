@@ -0,0 +1,7 @@
+-- Add down migration script here
+DELETE FROM permissions
+WHERE
+  name IN ('users.manage', 'applications.manage', 'contents.manage_all');
+
+ALTER TABLE roles
+DROP COLUMN requires_two_factor;
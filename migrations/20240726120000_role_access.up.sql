@@ -0,0 +1,28 @@
+-- Add up migration script here
+-- Members of the flagged roles have to enable two-factor authentication before using the admin endpoints
+ALTER TABLE roles
+ADD COLUMN requires_two_factor TINYINT (1) NOT NULL DEFAULT 0 AFTER protected;
+
+UPDATE roles
+SET
+  requires_two_factor = 1
+WHERE
+  id = 1;
+
+-- The admin endpoints are granted by permission, not by role id
+INSERT INTO
+  permissions (name, description)
+VALUES
+  ('users.manage', 'Manage users and their sessions, users with it cannot be impersonated'),
+  ('applications.manage', 'Manage the API applications'),
+  ('contents.manage_all', 'Update, delete and restore the contents of other users');
+
+INSERT INTO
+  role_permissions (role_id, permission_id)
+SELECT
+  1,
+  id
+FROM
+  permissions
+WHERE
+  name IN ('users.manage', 'applications.manage', 'contents.manage_all');
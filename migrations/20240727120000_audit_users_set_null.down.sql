@@ -0,0 +1,38 @@
+-- Add down migration script here
+-- Rows of purged users cannot go back to NOT NULL columns
+DELETE FROM impersonation_logs
+WHERE
+  impersonator_id IS NULL
+  OR user_id IS NULL;
+
+DELETE FROM content_transitions
+WHERE
+  user_id IS NULL;
+
+DELETE FROM content_revisions
+WHERE
+  user_id IS NULL;
+
+ALTER TABLE impersonation_logs
+DROP FOREIGN KEY fk_impersonation_logs_impersonator,
+DROP FOREIGN KEY fk_impersonation_logs_user;
+
+ALTER TABLE impersonation_logs
+MODIFY impersonator_id CHAR(36) NOT NULL,
+MODIFY user_id CHAR(36) NOT NULL,
+ADD CONSTRAINT fk_impersonation_logs_impersonator FOREIGN KEY (impersonator_id) REFERENCES users (id) ON DELETE CASCADE,
+ADD CONSTRAINT fk_impersonation_logs_user FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE;
+
+ALTER TABLE content_transitions
+DROP FOREIGN KEY fk_content_transitions_user;
+
+ALTER TABLE content_transitions
+MODIFY user_id CHAR(36) NOT NULL,
+ADD CONSTRAINT fk_content_transitions_user FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE;
+
+ALTER TABLE content_revisions
+DROP FOREIGN KEY fk_content_revisions_user;
+
+ALTER TABLE content_revisions
+MODIFY user_id CHAR(36) NOT NULL,
+ADD CONSTRAINT fk_content_revisions_user FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE;
@@ -0,0 +1,25 @@
+-- Add up migration script here
+-- Audit trails outlive the users they mention, purging a user only clears the reference
+ALTER TABLE impersonation_logs
+DROP FOREIGN KEY fk_impersonation_logs_impersonator,
+DROP FOREIGN KEY fk_impersonation_logs_user;
+
+ALTER TABLE impersonation_logs
+MODIFY impersonator_id CHAR(36) NULL,
+MODIFY user_id CHAR(36) NULL,
+ADD CONSTRAINT fk_impersonation_logs_impersonator FOREIGN KEY (impersonator_id) REFERENCES users (id) ON DELETE SET NULL,
+ADD CONSTRAINT fk_impersonation_logs_user FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE SET NULL;
+
+ALTER TABLE content_transitions
+DROP FOREIGN KEY fk_content_transitions_user;
+
+ALTER TABLE content_transitions
+MODIFY user_id CHAR(36) NULL,
+ADD CONSTRAINT fk_content_transitions_user FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE SET NULL;
+
+ALTER TABLE content_revisions
+DROP FOREIGN KEY fk_content_revisions_user;
+
+ALTER TABLE content_revisions
+MODIFY user_id CHAR(36) NULL,
+ADD CONSTRAINT fk_content_revisions_user FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE SET NULL;
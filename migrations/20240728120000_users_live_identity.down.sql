@@ -0,0 +1,16 @@
+-- Add down migration script here
+-- Fails while a deleted user shares its name, mobile or email with another user, purge it first
+ALTER TABLE users
+DROP INDEX uq_users_live_name,
+DROP INDEX uq_users_live_mobile,
+DROP INDEX uq_users_live_email;
+
+ALTER TABLE users
+DROP COLUMN live_name,
+DROP COLUMN live_mobile,
+DROP COLUMN live_email,
+DROP INDEX idx_users_mobile,
+DROP INDEX idx_users_email,
+ADD UNIQUE INDEX name (name),
+ADD UNIQUE INDEX mobile (mobile),
+ADD UNIQUE INDEX email (email);
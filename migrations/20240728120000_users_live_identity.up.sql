@@ -0,0 +1,18 @@
+-- Add up migration script here
+-- Names, mobiles and emails are only unique among the users that are not deleted,
+-- so a deleted user does not keep the person from registering again.
+-- The generated columns are NULL for deleted users and hidden from SELECT *
+ALTER TABLE users
+DROP INDEX name,
+DROP INDEX mobile,
+DROP INDEX email,
+ADD INDEX idx_users_mobile (mobile),
+ADD INDEX idx_users_email (email),
+ADD COLUMN live_name VARCHAR(255) AS (IF(deleted_at IS NULL, name, NULL)) STORED INVISIBLE,
+ADD COLUMN live_mobile VARCHAR(16) AS (IF(deleted_at IS NULL, mobile, NULL)) STORED INVISIBLE,
+ADD COLUMN live_email VARCHAR(255) AS (IF(deleted_at IS NULL, email, NULL)) STORED INVISIBLE;
+
+ALTER TABLE users
+ADD UNIQUE INDEX uq_users_live_name (live_name),
+ADD UNIQUE INDEX uq_users_live_mobile (live_mobile),
+ADD UNIQUE INDEX uq_users_live_email (live_email);
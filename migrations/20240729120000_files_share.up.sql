@@ -0,0 +1,16 @@
+-- Add up migration script here
+-- Signed URLs hand out the private files, only admins issue them
+INSERT INTO
+  permissions (name, description)
+VALUES
+  ('files.share', 'Issue signed URLs of the private files');
+
+INSERT INTO
+  role_permissions (role_id, permission_id)
+SELECT
+  1,
+  id
+FROM
+  permissions
+WHERE
+  name = 'files.share';
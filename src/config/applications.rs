@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Applications {
+    pub secret_grace_minutes: i64, // How long the previous secret works after a rotation
+    pub signature_skew_seconds: i64, // Allowed clock difference for signed requests
+}
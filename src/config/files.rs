@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Files {
+    pub public_root: String,     // Directory served under /public
+    pub max_age_seconds: u32,    // How long browsers and proxies keep the public files
+    pub private_root: String,    // Directory only served through signed URLs
+    pub signing_key: String,     // Key of the signed URLs, none is accepted while it is empty
+    pub signed_url_seconds: i64, // How long a signed URL works
+}
@@ -0,0 +1,6 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Impersonation {
+    pub token_minutes: i64, // Lifetime of the token issued to act as a user
+}
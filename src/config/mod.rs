@@ -1,14 +1,32 @@
+use applications::Applications;
 use config::{Config as RustConfig, ConfigError, Environment, File};
 use dotenv::dotenv;
+use files::Files;
+use impersonation::Impersonation;
 use lazy_static::lazy_static;
+use otp::Otp;
+use phone::Phone;
+use registration::Registration;
+use schedule::Schedule;
 use serde::{Deserialize, Serialize};
 use sms::Sms;
 use smtp::Smtp;
 use std::env;
+use storage::Storage;
+use two_factor::TwoFactor;
 
 // Import the `sms` module from a separate file
+pub mod applications;
+pub mod files;
+pub mod impersonation;
+pub mod otp;
+pub mod phone;
+pub mod registration;
+pub mod schedule;
 pub mod sms;
 pub mod smtp;
+pub mod storage;
+pub mod two_factor;
 
 // Struct definitions for configuration
 
@@ -28,6 +46,8 @@ pub struct Database {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Jwt {
     pub secret: String,
+    pub access_token_minutes: i64,
+    pub refresh_token_days: i64,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -41,6 +61,15 @@ pub struct Config {
     pub cors: String,
     pub sms: Sms,
     pub smtp: Smtp,
+    pub otp: Otp,
+    pub phone: Phone,
+    pub registration: Registration,
+    pub two_factor: TwoFactor,
+    pub applications: Applications,
+    pub impersonation: Impersonation,
+    pub schedule: Schedule,
+    pub storage: Storage,
+    pub files: Files,
 }
 
 impl Config {
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Otp {
+    pub code_ttl_seconds: i64,
+    pub max_code_attempts: i32,
+    pub resend_cooldown_seconds: i64,
+    pub max_resends_per_mobile: i32,
+    pub max_resends_per_ip: i32,
+    pub max_failed_verifies_per_mobile: i32,
+    pub max_failed_verifies_per_ip: i32,
+    pub window_minutes: i64,
+    pub lockout_minutes: i64,
+}
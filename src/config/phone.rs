@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Phone {
+    pub default_region: String, // ISO 3166-1 alpha-2 region of numbers without a country code
+    pub allowed_countries: Vec<String>, // ISO 3166-1 alpha-2 regions accepted for mobiles
+}
@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Registration {
+    pub verification_hours: i64,
+    pub cleanup_interval_minutes: u64,
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Storage {
+    pub driver: String,          // Where uploads are kept: local or s3
+    pub local_root: String,      // Directory of the local driver
+    pub public_url: String,      // Prefix of the URL the stored files are reachable under
+    pub max_upload_bytes: usize, // Largest accepted upload
+    pub s3: S3,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S3 {
+    pub endpoint: String, // Any S3 compatible endpoint, e.g. a local MinIO
+    pub region: String,
+    pub bucket: String,
+    pub access_key: String,
+    pub secret_key: String,
+}
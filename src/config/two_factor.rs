@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TwoFactor {
+    pub issuer: String,
+    pub enforce_for_admins: bool,
+    pub recovery_codes: usize,
+}
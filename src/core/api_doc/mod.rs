@@ -1,26 +1,40 @@
 use utoipa::{
-    openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme},
+    openapi::security::{
+        ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityRequirement, SecurityScheme,
+    },
     Modify, OpenApi,
 };
 
 use crate::{
+    model::{application::ApplicationModelResponse, content_image_variant::ImageVariantResponse, content_revision::{ContentRevisionResponse, FieldDiffResponse}, content_transition::ContentTransitionResponse, impersonation_log::ImpersonationLogModel, media::{GalleryImageResponse, MediaModelResponse}, role::{PermissionModel, RoleModelResponse}},
     handlers::{ 
         admin::{
-            contents::{create_contents::__path_create_contents_handler, delete_content::__path_delete_contents_handler, get_content_by_id::__path_get_content_by_id_handler, get_contents::__path_get_contents_handler, update_contents::__path_update_contents_handler}, 
-            user::{create_user::__path_create_user_handler, delete_user::__path_delete_user_handler, get_user_by_id::__path_get_user_by_id_handler, get_users::__path_get_users_handler, update_user::__path_update_user_handler}
+            applications::{create_application::__path_create_application_handler, delete_application::__path_delete_application_handler, get_application_by_id::__path_get_application_by_id_handler, get_applications::__path_get_applications_handler, rotate_application_secret::__path_rotate_application_secret_handler, update_application::__path_update_application_handler},
+            roles::{create_role::__path_create_role_handler, delete_role::__path_delete_role_handler, get_permissions::__path_get_permissions_handler, get_role_by_id::__path_get_role_by_id_handler, get_roles::__path_get_roles_handler, update_role::__path_update_role_handler},
+            contents::{create_contents::__path_create_contents_handler, delete_content::__path_delete_contents_handler, get_content_by_id::__path_get_content_by_id_handler, get_contents::__path_get_contents_handler, update_contents::__path_update_contents_handler, restore_content::__path_restore_content_handler, purge_content::__path_purge_content_handler, transition_content::__path_transition_content_handler, get_content_transitions::__path_get_content_transitions_handler, get_content_revisions::__path_get_content_revisions_handler, get_content_revision_diff::__path_get_content_revision_diff_handler, rollback_content::__path_rollback_content_handler, upload_content_image::__path_upload_content_image_handler}, 
+            files::sign_file::__path_sign_file_handler,
+            media::{delete_media::__path_delete_media_handler, get_media::__path_get_media_handler, get_media_by_id::__path_get_media_by_id_handler, update_media::__path_update_media_handler, upload_media::__path_upload_media_handler},
+            user::{create_user::__path_create_user_handler, delete_user::__path_delete_user_handler, get_user_by_id::__path_get_user_by_id_handler, get_users::__path_get_users_handler, revoke_user_sessions::__path_revoke_user_sessions_handler, update_user::__path_update_user_handler, impersonate_user::__path_impersonate_user_handler, get_impersonation_logs::__path_get_impersonation_logs_handler, restore_user::__path_restore_user_handler, purge_user::__path_purge_user_handler}
         },
         auth::{
             login::{LoginUserRequest, __path_login_user_handler},
             register::{RegisterUserRequest, __path_register_user_handler},
             verify::{VerifyOtpRequest, __path_verify_otp_handler},
-            logout::__path_logout_user_handler,
+            logout::{__path_logout_all_user_handler, __path_logout_user_handler},
+            refresh::{RefreshTokenRequest, __path_refresh_token_handler},
+            two_factor::{ConfirmTwoFactorRequest, DisableTwoFactorRequest, __path_confirm_two_factor_handler, __path_disable_two_factor_handler, __path_enroll_two_factor_handler},
         },
-        health_checker::{__path_health_checker_auth_handler, __path_health_checker_handler},
+        health_checker::{__path_health_checker_auth_handler, __path_health_checker_handler, __path_health_checker_signed_handler},
+        files::{get_public_file::__path_get_public_file_handler, get_signed_file::__path_get_signed_file_handler},
         project::profile::{
             get_profile::__path_profile_handler, update_profile::__path_update_profile_handler,
+            upload_avatar::__path_upload_avatar_handler, delete_avatar::__path_delete_avatar_handler,
+        },
+        project::contents::{
+            get_contents::__path_get_public_contents_handler, get_content_by_slug::__path_get_public_content_by_slug_handler,
         },
     },
-    schema::{admin::{content::{ContentsFilterOptions, CreateContentSchema, UpdateContentSchema}, user::{CreateUserSchema, UpdateUserSchema, UsersFilterOptions}}, project::profile::update_profile::UpdateProfileSchema, response::{api_response::ApiResponse, api_response_collection::ApiResponseCollection, api_response_error::{ApiResponseError, ValidationErrorDetail}, api_response_object::ApiResponseObject, Pagination}},
+    schema::{admin::{application::{ApplicationsFilterOptions, CreateApplicationSchema, UpdateApplicationSchema}, content::{ContentsFilterOptions, CreateContentSchema, TransitionContentSchema, RevisionDiffOptions, UpdateContentSchema, UploadImageSchema}, file::SignFileSchema, media::{MediaFilterOptions, UpdateMediaSchema, UploadMediaSchema}, role::{CreateRoleSchema, UpdateRoleSchema}, user::{CreateUserSchema, ImpersonationLogsFilterOptions, UpdateUserSchema, UsersFilterOptions}}, project::{contents::PublicContentsFilterOptions, profile::{update_profile::UpdateProfileSchema, upload_avatar::UploadAvatarSchema}}, response::{project::contents::PublicContentResponse, api_response::ApiResponse, api_response_collection::ApiResponseCollection, api_response_error::{ApiResponseError, ValidationErrorDetail}, api_response_object::ApiResponseObject, Pagination}},
 };
 
 #[derive(OpenApi)]
@@ -29,42 +43,103 @@ use crate::{
         // Health Checker
         health_checker_handler,
         health_checker_auth_handler,
+        health_checker_signed_handler,
+        // Files
+        get_public_file_handler,
+        get_signed_file_handler,
         // Profile
         profile_handler,
         update_profile_handler,
+        upload_avatar_handler,
+        delete_avatar_handler,
+        // Contents
+        get_public_contents_handler,
+        get_public_content_by_slug_handler,
         //Auth
         login_user_handler,
         logout_user_handler,
+        logout_all_user_handler,
         verify_otp_handler,
+        refresh_token_handler,
         register_user_handler,
+        enroll_two_factor_handler,
+        confirm_two_factor_handler,
+        disable_two_factor_handler,
         // Admin-Users
         get_users_handler,
         get_user_by_id_handler,
         create_user_handler,
         update_user_handler,
         delete_user_handler,
+        restore_user_handler,
+        purge_user_handler,
+        revoke_user_sessions_handler,
+        impersonate_user_handler,
+        get_impersonation_logs_handler,
         // Admin Contents
         get_contents_handler,
         get_content_by_id_handler,
         create_contents_handler,
         update_contents_handler,
         delete_contents_handler,
+        restore_content_handler,
+        purge_content_handler,
+        transition_content_handler,
+        get_content_transitions_handler,
+        get_content_revisions_handler,
+        get_content_revision_diff_handler,
+        rollback_content_handler,
+        upload_content_image_handler,
+        // Admin Media
+        get_media_handler,
+        get_media_by_id_handler,
+        upload_media_handler,
+        update_media_handler,
+        delete_media_handler,
+        // Admin Files
+        sign_file_handler,
+        // Admin Applications
+        get_applications_handler,
+        get_application_by_id_handler,
+        create_application_handler,
+        update_application_handler,
+        delete_application_handler,
+        rotate_application_secret_handler,
+        // Admin Roles
+        get_roles_handler,
+        get_role_by_id_handler,
+        create_role_handler,
+        update_role_handler,
+        delete_role_handler,
+        get_permissions_handler,
     ),
     components(
         schemas(
             ApiResponse, ApiResponseCollection, ApiResponseObject, ApiResponseError, Pagination, ValidationErrorDetail,
-            UpdateProfileSchema,
-            CreateContentSchema, UpdateContentSchema, ContentsFilterOptions,
-            LoginUserRequest, VerifyOtpRequest, RegisterUserRequest,
-            CreateUserSchema, UpdateUserSchema, UsersFilterOptions
+            UpdateProfileSchema, UploadAvatarSchema,
+            PublicContentsFilterOptions, PublicContentResponse,
+            CreateContentSchema, UpdateContentSchema, ContentsFilterOptions, TransitionContentSchema, ContentTransitionResponse, RevisionDiffOptions, ContentRevisionResponse, FieldDiffResponse, UploadImageSchema, ImageVariantResponse, GalleryImageResponse,
+            UploadMediaSchema, UpdateMediaSchema, MediaFilterOptions, MediaModelResponse,
+            SignFileSchema,
+            LoginUserRequest, VerifyOtpRequest, RefreshTokenRequest, RegisterUserRequest,
+            ConfirmTwoFactorRequest, DisableTwoFactorRequest,
+            CreateUserSchema, UpdateUserSchema, UsersFilterOptions, ImpersonationLogsFilterOptions, ImpersonationLogModel,
+            CreateApplicationSchema, UpdateApplicationSchema, ApplicationsFilterOptions, ApplicationModelResponse,
+            CreateRoleSchema, UpdateRoleSchema, RoleModelResponse, PermissionModel
         )
     ),
     tags(
         (name = "Health Checker Endpoint", description = "Health Checker Endpoint"),
-        (name = "Auth Endpoint", description = "Authenticated endpoints: Login, VerifyOTP, Register"),
-        (name = "Profile Endpoint", description = "Get Profile and Update Profile"),
-        (name = "Admin: Users Endpoint", description = "Admin User management: Create User, Get Users, Update User, Delete User, Get User By ID"),
-        (name = "Admin: Contents Endpoint", description = "Admin Content management: Create Contetns, Get Contents, Update Contents, Delete Contents, Get Content By ID"),
+        (name = "Files Endpoint", description = "Public files and private files through signed URLs"),
+        (name = "Auth Endpoint", description = "Authenticated endpoints: Login, VerifyOTP, Refresh Token, Register, Logout, Logout Everywhere, Two-Factor Authentication"),
+        (name = "Profile Endpoint", description = "Get Profile, Update Profile, Upload Avatar and Delete Avatar"),
+        (name = "Contents Endpoint", description = "Published contents: Get Contents, Get Content By Slug"),
+        (name = "Admin: Users Endpoint", description = "Admin User management: Create User, Get Users, Update User, Delete User, Get User By ID, Revoke User Sessions, Impersonate User, Get Impersonation Logs"),
+        (name = "Admin: Contents Endpoint", description = "Admin Content management: Create Contetns, Get Contents, Update Contents, Delete Contents, Get Content By ID, Content Workflow Transitions, Content Revisions"),
+        (name = "Admin: Media Endpoint", description = "Admin Media library: Upload Media, Get Media, Update Media, Delete Media, Get Media By ID"),
+        (name = "Admin: Files Endpoint", description = "Admin Files: Sign File"),
+        (name = "Admin: Applications Endpoint", description = "Admin Application management: Create Application, Get Applications, Update Application, Delete Application, Get Application By ID, Rotate Application Secret"),
+        (name = "Admin: Roles Endpoint", description = "Admin Role management: Create Role, Get Roles, Update Role, Delete Role, Get Role By ID, Get Permissions"),
         
     ),
     modifiers(&SecurityAddon)
@@ -84,6 +159,27 @@ impl Modify for SecurityAddon {
                     .bearer_format("JWT")
                     .build(),
             ),
-        )
+        );
+        components.add_security_scheme(
+            "app_key",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("X-App-Key"))),
+        );
+
+        // Every /api endpoint also requires the key of the calling application
+        for (path, item) in openapi.paths.paths.iter_mut() {
+            if !path.starts_with("/api/") {
+                continue;
+            }
+            for operation in item.operations.values_mut() {
+                let security = operation.security.get_or_insert_with(Vec::new);
+                if security.is_empty() {
+                    security.push(SecurityRequirement::new("app_key", Vec::<String>::new()));
+                } else {
+                    for requirement in security.iter_mut() {
+                        *requirement = requirement.clone().add("app_key", Vec::<String>::new());
+                    }
+                }
+            }
+        }
     }
 }
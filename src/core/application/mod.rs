@@ -0,0 +1,152 @@
+use chrono::{DateTime, Utc};
+use rand::{distributions::Alphanumeric, Rng, RngCore};
+use sqlx::MySqlPool;
+
+use crate::{
+    model::application::ApplicationModel, schema::response::api_response_error::ApiResponseError,
+};
+
+const APP_KEY_LENGTH: usize = 42;
+const APP_SECRET_BYTES: usize = 32;
+
+// Public key sent by the application with every request
+pub fn generate_app_key() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(APP_KEY_LENGTH)
+        .map(|c| char::from(c).to_ascii_lowercase())
+        .collect()
+}
+
+// Shared secret used by the application to sign its requests
+pub fn generate_app_secret() -> String {
+    let mut secret = [0u8; APP_SECRET_BYTES];
+    rand::thread_rng().fill_bytes(&mut secret);
+    hex::encode(secret)
+}
+
+// Secrets accepted for the application, the previous one only during the rotation grace period
+pub fn valid_secrets(application: &ApplicationModel, now: DateTime<Utc>) -> Vec<&str> {
+    let mut secrets = vec![application.app_secret.as_str()];
+    if let (Some(previous), Some(expires_at)) = (
+        application.previous_app_secret.as_deref(),
+        application.previous_secret_expires_at,
+    ) {
+        if expires_at > now {
+            secrets.push(previous);
+        }
+    }
+    secrets
+}
+
+// Find an application that has not been deleted
+pub async fn find_application(
+    pool: &MySqlPool,
+    application_id: &str,
+) -> Result<ApplicationModel, ApiResponseError> {
+    sqlx::query_as!(
+        ApplicationModel,
+        "SELECT * FROM applications WHERE id = ? AND deleted_at IS NULL",
+        application_id
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?
+    .ok_or_else(|| {
+        ApiResponseError::new(
+            404,
+            format!("Application with ID: {} not found", application_id),
+            None,
+        )
+    })
+}
+
+// Find the application of an app key, deleted applications are treated as unknown keys
+pub async fn find_active_application_by_key(
+    pool: &MySqlPool,
+    app_key: &str,
+) -> Result<ApplicationModel, ApiResponseError> {
+    let application = sqlx::query_as!(
+        ApplicationModel,
+        "SELECT * FROM applications WHERE app_key = ? AND deleted_at IS NULL",
+        app_key
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?
+    .ok_or_else(|| ApiResponseError::new(401, "Invalid app key".to_string(), None))?;
+
+    if application.record_state != 1 {
+        return Err(ApiResponseError::new(
+            403,
+            "Application is disabled".to_string(),
+            None,
+        ));
+    }
+
+    Ok(application)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn application(
+        previous_app_secret: Option<&str>,
+        previous_secret_expires_at: Option<DateTime<Utc>>,
+    ) -> ApplicationModel {
+        ApplicationModel {
+            id: uuid::Uuid::new_v4().to_string(),
+            app_name: "test".to_string(),
+            app_version: "1".to_string(),
+            app_key: generate_app_key(),
+            app_secret: "current".to_string(),
+            previous_app_secret: previous_app_secret.map(str::to_string),
+            previous_secret_expires_at,
+            app_requests: 0,
+            record_state: 1,
+            protected: 0,
+            created_by: uuid::Uuid::new_v4().to_string(),
+            created_at: None,
+            updated_at: None,
+            deleted_at: None,
+        }
+    }
+
+    #[test]
+    fn test_generate_app_credentials() {
+        let key = generate_app_key();
+        let secret = generate_app_secret();
+
+        assert_eq!(key.len(), APP_KEY_LENGTH);
+        assert!(key.chars().all(|c| !c.is_ascii_uppercase()));
+        assert_eq!(secret.len(), APP_SECRET_BYTES * 2);
+        assert_ne!(key, generate_app_key());
+        assert_ne!(secret, generate_app_secret());
+    }
+
+    #[test]
+    fn test_valid_secrets_during_grace_period() {
+        let now = Utc::now();
+
+        assert_eq!(
+            valid_secrets(&application(None, None), now),
+            vec!["current"]
+        );
+        assert_eq!(
+            valid_secrets(
+                &application(Some("previous"), Some(now + Duration::minutes(5))),
+                now
+            ),
+            vec!["current", "previous"]
+        );
+        assert_eq!(
+            valid_secrets(
+                &application(Some("previous"), Some(now - Duration::minutes(5))),
+                now
+            ),
+            vec!["current"]
+        );
+    }
+}
@@ -0,0 +1,274 @@
+use std::collections::HashMap;
+
+use image::{codecs::jpeg::JpegEncoder, imageops::FilterType, Rgb, RgbImage};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    core::{image_variant::image_reader, storage::file_url},
+    model::user::UserModel,
+    schema::response::api_response_error::{ApiResponseError, ValidationErrorDetail},
+};
+
+// Avatars are stored as squares of this side
+pub const AVATAR_SIZE: u32 = 256;
+
+const JPEG_QUALITY: u8 = 85;
+
+// Backgrounds of the initials, picked by the user id so a user keeps the same one
+const INITIALS_COLORS: &[&str] = &[
+    "#1abc9c", "#2ecc71", "#3498db", "#9b59b6", "#34495e", "#e67e22", "#e74c3c", "#7f8c8d",
+];
+
+// Square part of the image to keep, in pixels of the uploaded image
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crop {
+    pub x: u32,
+    pub y: u32,
+    pub size: u32,
+}
+
+fn validation_error(field: &str, error: String) -> ApiResponseError {
+    ApiResponseError::new(
+        400,
+        "Validation Error".to_string(),
+        Some(vec![ValidationErrorDetail {
+            field: field.to_string(),
+            error,
+        }]),
+    )
+}
+
+// The crop sent with the upload, the centered square is kept when none is sent
+pub fn parse_crop(
+    fields: &HashMap<String, String>,
+    width: u32,
+    height: u32,
+) -> Result<Crop, ApiResponseError> {
+    if !["x", "y", "size"]
+        .iter()
+        .any(|field| fields.contains_key(*field))
+    {
+        let size = width.min(height);
+        return Ok(Crop {
+            x: (width - size) / 2,
+            y: (height - size) / 2,
+            size,
+        });
+    }
+
+    let field = |name: &str| -> Result<u32, ApiResponseError> {
+        let value = fields
+            .get(name)
+            .ok_or_else(|| validation_error(name, "x, y and size are sent together".to_string()))?;
+        value
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| validation_error(name, format!("{} must be a number of pixels", name)))
+    };
+    let crop = Crop {
+        x: field("x")?,
+        y: field("y")?,
+        size: field("size")?,
+    };
+
+    if crop.size == 0 {
+        return Err(validation_error(
+            "size",
+            "Size must be greater than 0".to_string(),
+        ));
+    }
+    if crop.x as u64 + crop.size as u64 > width as u64
+        || crop.y as u64 + crop.size as u64 > height as u64
+    {
+        return Err(validation_error(
+            "size",
+            format!("Crop must fit in the {}x{} image", width, height),
+        ));
+    }
+    Ok(crop)
+}
+
+// Crop and resize to the avatar size, as a JPEG. CPU bound, run it on a blocking thread.
+pub fn render_avatar(bytes: &[u8], crop: Crop) -> Result<Vec<u8>, String> {
+    let image = image_reader(bytes)?
+        .decode()
+        .map_err(|e| format!("Failed to decode the image: {}", e))?
+        .crop_imm(crop.x, crop.y, crop.size, crop.size)
+        .resize_exact(AVATAR_SIZE, AVATAR_SIZE, FilterType::Lanczos3)
+        .to_rgba8();
+
+    // JPEG has no transparency, transparent parts become white
+    let flattened = RgbImage::from_fn(AVATAR_SIZE, AVATAR_SIZE, |x, y| {
+        let [r, g, b, a] = image.get_pixel(x, y).0;
+        let blend =
+            |channel: u8| ((channel as u32 * a as u32 + 255 * (255 - a as u32)) / 255) as u8;
+        Rgb([blend(r), blend(g), blend(b)])
+    });
+
+    let mut avatar = Vec::new();
+    flattened
+        .write_with_encoder(JpegEncoder::new_with_quality(&mut avatar, JPEG_QUALITY))
+        .map_err(|e| format!("Failed to encode the avatar: {}", e))?;
+    Ok(avatar)
+}
+
+// First letter of the first and last words of the name
+pub fn initials(name: &str) -> String {
+    let words: Vec<&str> = name
+        .split_whitespace()
+        .filter(|word| word.chars().next().is_some_and(char::is_alphanumeric))
+        .collect();
+    let initials: String = match words.as_slice() {
+        [] => return "?".to_string(),
+        [word] => word.chars().take(1).collect(),
+        [first, .., last] => first.chars().take(1).chain(last.chars().take(1)).collect(),
+    };
+    initials.to_uppercase()
+}
+
+// Every byte outside the unreserved characters is escaped, names may hold any letter
+fn percent_encode(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-_.~".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+// Initials on a colored square, as a data URL so no request is made for it
+pub fn initials_avatar(user_id: &str, name: &str) -> String {
+    let color =
+        INITIALS_COLORS[Sha256::digest(user_id.as_bytes())[0] as usize % INITIALS_COLORS.len()];
+    let svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{size}\" height=\"{size}\" viewBox=\"0 0 {size} {size}\"><rect width=\"100%\" height=\"100%\" fill=\"{color}\"/><text x=\"50%\" y=\"50%\" dy=\".35em\" fill=\"#ffffff\" font-family=\"Arial, sans-serif\" font-size=\"{font_size}\" text-anchor=\"middle\">{initials}</text></svg>",
+        size = AVATAR_SIZE,
+        color = color,
+        font_size = AVATAR_SIZE * 2 / 5,
+        initials = initials(name)
+    );
+    format!("data:image/svg+xml,{}", percent_encode(&svg))
+}
+
+// URL of the uploaded avatar, or of the initials of users without one
+pub fn avatar_url(user: &UserModel) -> String {
+    match user.avatar {
+        Some(ref avatar) => file_url(avatar),
+        None => initials_avatar(&user.id, &user.name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageFormat, RgbaImage};
+    use std::io::Cursor;
+
+    fn fields(values: &[(&str, &str)]) -> HashMap<String, String> {
+        values
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_crop() {
+        // The centered square by default
+        assert_eq!(
+            parse_crop(&HashMap::new(), 800, 600).unwrap(),
+            Crop {
+                x: 100,
+                y: 0,
+                size: 600
+            }
+        );
+        assert_eq!(
+            parse_crop(
+                &fields(&[("x", "10"), ("y", "20"), ("size", "300")]),
+                800,
+                600
+            )
+            .unwrap(),
+            Crop {
+                x: 10,
+                y: 20,
+                size: 300
+            }
+        );
+
+        let err = parse_crop(&fields(&[("x", "10")]), 800, 600).unwrap_err();
+        assert_eq!(err.validation_errors.unwrap()[0].field, "y");
+        assert!(parse_crop(
+            &fields(&[("x", "-1"), ("y", "0"), ("size", "10")]),
+            800,
+            600
+        )
+        .is_err());
+        assert!(parse_crop(&fields(&[("x", "0"), ("y", "0"), ("size", "0")]), 800, 600).is_err());
+        assert!(parse_crop(
+            &fields(&[("x", "500"), ("y", "0"), ("size", "400")]),
+            800,
+            600
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_render_avatar() {
+        // Left half red, right half transparent
+        let image = RgbaImage::from_fn(400, 200, |x, _| {
+            if x < 200 {
+                image::Rgba([255, 0, 0, 255])
+            } else {
+                image::Rgba([0, 0, 0, 0])
+            }
+        });
+        let mut png = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+            .unwrap();
+
+        let crop = Crop {
+            x: 100,
+            y: 0,
+            size: 200,
+        };
+        let avatar = image::load_from_memory(&render_avatar(&png, crop).unwrap())
+            .unwrap()
+            .to_rgb8();
+        assert_eq!(avatar.dimensions(), (AVATAR_SIZE, AVATAR_SIZE));
+
+        let [r, g, b] = avatar.get_pixel(10, 128).0;
+        assert!(r > 230 && g < 30 && b < 30);
+        let [r, g, b] = avatar.get_pixel(AVATAR_SIZE - 10, 128).0;
+        assert!(r > 230 && g > 230 && b > 230);
+    }
+
+    #[test]
+    fn test_initials() {
+        assert_eq!(initials("Ahmed Anbar"), "AA");
+        assert_eq!(initials("  jane  van der berg "), "JB");
+        assert_eq!(initials("admin"), "A");
+        assert_eq!(initials("أحمد عنبر"), "أع");
+        assert_eq!(initials("- --"), "?");
+        assert_eq!(initials(""), "?");
+    }
+
+    #[test]
+    fn test_initials_avatar() {
+        let url = initials_avatar("a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e2b", "Ahmed <b> Anbar");
+        assert!(url.starts_with("data:image/svg+xml,%3Csvg"));
+        // Nothing in the name reaches the markup unescaped
+        assert!(!url.contains('<') && !url.contains('#') && !url.contains(' '));
+        assert!(url.contains("%3EAA%3C%2Ftext%3E"));
+
+        // The same user always gets the same color
+        assert_eq!(
+            url,
+            initials_avatar("a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e2b", "Ahmed <b> Anbar")
+        );
+    }
+}
@@ -1 +0,0 @@
-pub const ADMIN_ROLE: i32 = 1;
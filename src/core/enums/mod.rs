@@ -26,3 +26,61 @@ impl UserRole {
         }
     }
 }
+
+// Workflow state of a content, stored in `contents.record_state`.
+// Published keeps the value 1 of the contents that were active before the workflow.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentState {
+    Draft,
+    InReview,
+    Published,
+    Archived,
+}
+
+impl ContentState {
+    pub fn to_str(self) -> &'static str {
+        match self {
+            ContentState::Draft => "draft",
+            ContentState::InReview => "in_review",
+            ContentState::Published => "published",
+            ContentState::Archived => "archived",
+        }
+    }
+
+    pub fn to_i8(self) -> i8 {
+        match self {
+            ContentState::Draft => 0,
+            ContentState::Published => 1,
+            ContentState::InReview => 2,
+            ContentState::Archived => 3,
+        }
+    }
+
+    pub fn from_i8(value: i8) -> Option<Self> {
+        match value {
+            0 => Some(ContentState::Draft),
+            1 => Some(ContentState::Published),
+            2 => Some(ContentState::InReview),
+            3 => Some(ContentState::Archived),
+            _ => None,
+        }
+    }
+
+    // Name of a stored state, "unknown" for values outside the workflow
+    pub fn name_of(value: i8) -> &'static str {
+        Self::from_i8(value)
+            .map(|state| state.to_str())
+            .unwrap_or("unknown")
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "draft" => Some(ContentState::Draft),
+            "in_review" => Some(ContentState::InReview),
+            "published" => Some(ContentState::Published),
+            "archived" => Some(ContentState::Archived),
+            _ => None,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::core::app_state::AppState;
+use crate::core::{app_state::AppState, slug::content_slug};
 use actix_web::{get, web, HttpResponse, Responder};
 use fake::{faker::lorem::en::Paragraph, Fake};
 use rand::Rng;
@@ -30,6 +30,7 @@ pub async fn create_fake_contents(
         };
 
         let content_id = Uuid::new_v4().to_string();
+        let slug = content_slug(&title, &content_id);
         let summary: Option<String> = if rand::thread_rng().gen_range(0..2) == 1 {
             Some(Paragraph(1..5).fake())
         } else {
@@ -43,10 +44,11 @@ pub async fn create_fake_contents(
         let content_type = "page";
 
         let insert_result = sqlx::query(
-            "INSERT INTO contents (id, title, content_type, summary, details, created_by) VALUES (?, ?, ?, ?, ?, ?)",
+            "INSERT INTO contents (id, title, slug, content_type, summary, details, created_by) VALUES (?, ?, ?, ?, ?, ?, ?)",
         )
         .bind(&content_id)
         .bind(&title)
+        .bind(&slug)
         .bind(&content_type)
         .bind(&summary)
         .bind(&details)
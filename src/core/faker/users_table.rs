@@ -32,7 +32,7 @@ pub async fn create_fake_users(
         };
 
         let mobile: String = loop {
-            let mobile = format!("9665{}", rand::thread_rng().gen_range(10000000..99999999));
+            let mobile = format!("+96650{}", rand::thread_rng().gen_range(1000000..9999999));
             if !unique_mobiles.contains(&mobile) {
                 let row: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM users WHERE mobile = ?")
                     .bind(&mobile)
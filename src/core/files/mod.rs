@@ -0,0 +1,311 @@
+use std::path::Path;
+
+use actix_files::NamedFile;
+use actix_web::{
+    http::header::{self, ContentEncoding, HeaderValue},
+    HttpRequest, HttpResponse,
+};
+use chrono::{DateTime, Duration, Utc};
+
+use crate::{
+    config::CONFIG,
+    core::{
+        signature::{sign_request, verify_signature},
+        storage::validate_key,
+    },
+    schema::response::api_response_error::ApiResponseError,
+};
+
+// Compressed copies looked for next to a file, in order of preference
+const PRECOMPRESSED: &[(&str, &str, ContentEncoding)] = &[
+    ("br", "br", ContentEncoding::Brotli),
+    ("gzip", "gz", ContentEncoding::Gzip),
+];
+
+fn not_found() -> ApiResponseError {
+    ApiResponseError::new(404, "File Not Found".to_string(), None)
+}
+
+// Whether the client takes the coding, a q of 0 refuses it
+fn accepts_encoding(req: &HttpRequest, coding: &str) -> bool {
+    let Some(accept) = req
+        .headers()
+        .get(header::ACCEPT_ENCODING)
+        .and_then(|value| value.to_str().ok())
+    else {
+        return false;
+    };
+
+    let mut wildcard = false;
+    for item in accept.split(',') {
+        let mut parts = item.split(';');
+        let name = parts.next().unwrap_or_default().trim();
+        let accepted = parts
+            .filter_map(|param| param.trim().strip_prefix("q="))
+            .all(|q| q.trim().parse::<f32>().is_ok_and(|q| q > 0.0));
+
+        if name.eq_ignore_ascii_case(coding) {
+            return accepted;
+        }
+        if name == "*" {
+            wildcard = accepted;
+        }
+    }
+    wildcard
+}
+
+// Serve `path` from `root` with a strong ETag, Last-Modified and range requests.
+// A `.br` or `.gz` copy next to the file is sent instead when the client takes it,
+// ranges always get the file itself since they are offsets into it.
+pub async fn serve_file(
+    req: &HttpRequest,
+    root: &str,
+    path: &str,
+    cache_control: &str,
+) -> Result<HttpResponse, ApiResponseError> {
+    validate_key(path).map_err(|_| not_found())?;
+
+    let file_path = Path::new(root).join(path);
+    match tokio::fs::metadata(&file_path).await {
+        Ok(metadata) if metadata.is_file() => {}
+        _ => return Err(not_found()),
+    }
+    let mut file = NamedFile::open_async(&file_path)
+        .await
+        .map_err(|_| not_found())?;
+
+    if !req.headers().contains_key(header::RANGE) {
+        for (coding, extension, encoding) in PRECOMPRESSED {
+            if !accepts_encoding(req, coding) {
+                continue;
+            }
+            let compressed_path = file_path.with_file_name(format!(
+                "{}.{}",
+                file_path.file_name().unwrap_or_default().to_string_lossy(),
+                extension
+            ));
+            if let Ok(compressed) = NamedFile::open_async(&compressed_path).await {
+                // The copy is described as the file it stands for
+                file = compressed
+                    .set_content_type(file.content_type().clone())
+                    .set_content_disposition(file.content_disposition().clone())
+                    .set_content_encoding(*encoding);
+                break;
+            }
+        }
+    }
+
+    let mut response = file
+        .use_etag(true)
+        .use_last_modified(true)
+        .into_response(req);
+    let headers = response.headers_mut();
+    if let Ok(value) = HeaderValue::from_str(cache_control) {
+        headers.insert(header::CACHE_CONTROL, value);
+    }
+    headers.append(header::VARY, HeaderValue::from_static("accept-encoding"));
+    Ok(response)
+}
+
+fn canonical_file_request(path: &str, expires: i64) -> String {
+    format!("GET\n{}\n{}", path, expires)
+}
+
+// URL of a private file that works until `expires`, for handing out files that are
+// not public such as exports or documents of a user
+pub fn signed_url(path: &str, now: DateTime<Utc>) -> String {
+    let expires = (now + Duration::seconds(CONFIG.files.signed_url_seconds)).timestamp();
+    let signature = sign_request(
+        &CONFIG.files.signing_key,
+        &canonical_file_request(path, expires),
+    );
+    format!(
+        "/files/{}?expires={}&signature={}",
+        path, expires, signature
+    )
+}
+
+// Check a signed URL, returns the seconds it still works for
+pub fn verify_signed_url(
+    path: &str,
+    expires: i64,
+    signature: &str,
+    now: DateTime<Utc>,
+) -> Result<i64, ApiResponseError> {
+    let key = CONFIG.files.signing_key.as_str();
+    if key.is_empty()
+        || !verify_signature(&[key], &canonical_file_request(path, expires), signature)
+    {
+        return Err(ApiResponseError::new(
+            403,
+            "Invalid Signature".to_string(),
+            None,
+        ));
+    }
+    if expires <= now.timestamp() {
+        return Err(ApiResponseError::new(
+            403,
+            "Signed URL Expired".to_string(),
+            None,
+        ));
+    }
+    Ok(expires - now.timestamp())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{body::to_bytes, http::StatusCode, test::TestRequest};
+
+    // A root with `app.css` and its brotli copy
+    fn create_root() -> std::path::PathBuf {
+        let root = std::env::temp_dir().join(format!("files-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(root.join("css")).unwrap();
+        std::fs::write(root.join("css/app.css"), "body { color: red; }").unwrap();
+        std::fs::write(root.join("css/app.css.br"), "compressed").unwrap();
+        root
+    }
+
+    #[actix_web::test]
+    async fn test_serve_file() {
+        let root = create_root();
+        let root_str = root.to_str().unwrap();
+
+        let req = TestRequest::get().to_http_request();
+        let response = serve_file(&req, root_str, "css/app.css", "public, max-age=60")
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(header::CACHE_CONTROL).unwrap(),
+            "public, max-age=60"
+        );
+        assert_eq!(
+            response.headers().get(header::VARY).unwrap(),
+            "accept-encoding"
+        );
+        assert!(response.headers().get(header::CONTENT_ENCODING).is_none());
+        let etag = response.headers().get(header::ETAG).unwrap().clone();
+        // Strong ETags, weak ones start with W/
+        assert!(etag.to_str().unwrap().starts_with('"'));
+        assert!(response.headers().contains_key(header::LAST_MODIFIED));
+        assert_eq!(
+            to_bytes(response.into_body()).await.unwrap(),
+            "body { color: red; }"
+        );
+
+        // A matching ETag is answered without the body
+        let req = TestRequest::get()
+            .insert_header((header::IF_NONE_MATCH, etag))
+            .to_http_request();
+        let response = serve_file(&req, root_str, "css/app.css", "public, max-age=60")
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+
+        // Ranges of the file
+        let req = TestRequest::get()
+            .insert_header((header::RANGE, "bytes=0-3"))
+            .insert_header((header::ACCEPT_ENCODING, "br"))
+            .to_http_request();
+        let response = serve_file(&req, root_str, "css/app.css", "public, max-age=60")
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(to_bytes(response.into_body()).await.unwrap(), "body");
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_serve_precompressed_file() {
+        let root = create_root();
+        let root_str = root.to_str().unwrap();
+
+        let req = TestRequest::get()
+            .insert_header((header::ACCEPT_ENCODING, "gzip, deflate, br"))
+            .to_http_request();
+        let response = serve_file(&req, root_str, "css/app.css", "no-cache")
+            .await
+            .unwrap();
+        assert_eq!(
+            response.headers().get(header::CONTENT_ENCODING).unwrap(),
+            "br"
+        );
+        assert!(response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .starts_with("text/css"));
+        assert_eq!(to_bytes(response.into_body()).await.unwrap(), "compressed");
+
+        // No gzip copy, and brotli refused
+        let req = TestRequest::get()
+            .insert_header((header::ACCEPT_ENCODING, "gzip, br;q=0"))
+            .to_http_request();
+        let response = serve_file(&req, root_str, "css/app.css", "no-cache")
+            .await
+            .unwrap();
+        assert!(response.headers().get(header::CONTENT_ENCODING).is_none());
+        assert_eq!(
+            to_bytes(response.into_body()).await.unwrap(),
+            "body { color: red; }"
+        );
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_serve_file_not_found() {
+        let root = create_root();
+        let root_str = root.to_str().unwrap();
+        let req = TestRequest::get().to_http_request();
+
+        for path in [
+            "css/missing.css",
+            "css",
+            "../secret",
+            "css/../css/app.css",
+            "",
+        ] {
+            let err = serve_file(&req, root_str, path, "no-cache")
+                .await
+                .unwrap_err();
+            assert_eq!(err.status, 404, "{}", path);
+        }
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_signed_url() {
+        let now = Utc::now();
+        let url = signed_url("exports/report.csv", now);
+        let (path, query) = url.split_once('?').unwrap();
+        assert_eq!(path, "/files/exports/report.csv");
+
+        let params: std::collections::HashMap<&str, &str> = query
+            .split('&')
+            .filter_map(|param| param.split_once('='))
+            .collect();
+        let expires: i64 = params["expires"].parse().unwrap();
+        let signature = params["signature"];
+
+        assert_eq!(
+            verify_signed_url("exports/report.csv", expires, signature, now).unwrap(),
+            CONFIG.files.signed_url_seconds
+        );
+        // Another file, a later expiry or a past one are refused
+        assert!(verify_signed_url("exports/other.csv", expires, signature, now).is_err());
+        assert!(verify_signed_url("exports/report.csv", expires + 60, signature, now).is_err());
+        assert!(verify_signed_url(
+            "exports/report.csv",
+            expires,
+            signature,
+            now + Duration::seconds(CONFIG.files.signed_url_seconds)
+        )
+        .is_err());
+    }
+}
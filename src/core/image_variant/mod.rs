@@ -0,0 +1,377 @@
+use std::io::Cursor;
+
+use image::{
+    codecs::{jpeg::JpegEncoder, webp::WebPEncoder},
+    imageops::FilterType,
+    DynamicImage, ImageReader,
+};
+use log::warn;
+use sqlx::MySqlPool;
+
+use crate::{
+    core::storage::Storage, model::content_image_variant::ContentImageVariantModel,
+    schema::response::api_response_error::ApiResponseError,
+};
+
+pub mod variant_queue;
+
+const JPEG_QUALITY: u8 = 82;
+
+// The image is resized to fit in the box, keeping its aspect ratio
+pub struct VariantSize {
+    pub name: &'static str,
+    pub max_width: u32,
+    pub max_height: u32,
+}
+
+pub const VARIANT_SIZES: &[VariantSize] = &[
+    VariantSize {
+        name: "thumbnail",
+        max_width: 150,
+        max_height: 150,
+    },
+    VariantSize {
+        name: "medium",
+        max_width: 640,
+        max_height: 640,
+    },
+    VariantSize {
+        name: "large",
+        max_width: 1280,
+        max_height: 1280,
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VariantFormat {
+    Webp,
+    Jpeg,
+}
+
+pub const VARIANT_FORMATS: &[VariantFormat] = &[VariantFormat::Webp, VariantFormat::Jpeg];
+
+impl VariantFormat {
+    pub fn name(&self) -> &'static str {
+        match self {
+            VariantFormat::Webp => "webp",
+            VariantFormat::Jpeg => "jpeg",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            VariantFormat::Webp => "webp",
+            VariantFormat::Jpeg => "jpg",
+        }
+    }
+
+    pub fn mime(&self) -> &'static str {
+        match self {
+            VariantFormat::Webp => "image/webp",
+            VariantFormat::Jpeg => "image/jpeg",
+        }
+    }
+}
+
+pub struct ImageVariant {
+    pub size: &'static str,
+    pub format: VariantFormat,
+    pub width: u32,
+    pub height: u32,
+    pub bytes: Vec<u8>,
+}
+
+pub fn image_reader(bytes: &[u8]) -> Result<ImageReader<Cursor<&[u8]>>, String> {
+    ImageReader::new(Cursor::new(bytes))
+        .with_guessed_format()
+        .map_err(|e| format!("Failed to read the image: {}", e))
+}
+
+// Width and height read from the header of the image, without decoding it
+pub fn image_dimensions(bytes: &[u8]) -> Result<(u32, u32), String> {
+    image_reader(bytes)?
+        .into_dimensions()
+        .map_err(|e| format!("Failed to read the image: {}", e))
+}
+
+// Key of a variant under its content, `contents/{id}/{name}-thumbnail.webp`. Media are
+// shared by contents, each content has its own variants.
+pub fn variant_key(
+    content_id: &str,
+    source_key: &str,
+    size: &str,
+    format: VariantFormat,
+) -> String {
+    let file_name = source_key
+        .rsplit_once('/')
+        .map_or(source_key, |(_, file_name)| file_name);
+    let stem = file_name
+        .rsplit_once('.')
+        .map_or(file_name, |(stem, _)| stem);
+    format!(
+        "contents/{}/{}-{}.{}",
+        content_id,
+        stem,
+        size,
+        format.extension()
+    )
+}
+
+fn encode(image: &DynamicImage, format: VariantFormat) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::new();
+    match format {
+        // JPEG has no transparency
+        VariantFormat::Jpeg => image
+            .to_rgb8()
+            .write_with_encoder(JpegEncoder::new_with_quality(&mut bytes, JPEG_QUALITY)),
+        // The WebP encoder of the image crate only writes lossless images
+        VariantFormat::Webp => image
+            .to_rgba8()
+            .write_with_encoder(WebPEncoder::new_lossless(&mut bytes)),
+    }
+    .map_err(|e| format!("Failed to encode the {} variant: {}", format.name(), e))?;
+    Ok(bytes)
+}
+
+// Every size in every format. CPU bound, run it on a blocking thread.
+pub fn render_variants(bytes: &[u8]) -> Result<Vec<ImageVariant>, String> {
+    let image = image_reader(bytes)?
+        .decode()
+        .map_err(|e| format!("Failed to decode the image: {}", e))?;
+
+    let mut variants = Vec::new();
+    for size in VARIANT_SIZES {
+        // Smaller images are kept at their size, never upscaled
+        let resized = if image.width() <= size.max_width && image.height() <= size.max_height {
+            image.clone()
+        } else {
+            image.resize(size.max_width, size.max_height, FilterType::Lanczos3)
+        };
+        for format in VARIANT_FORMATS {
+            variants.push(ImageVariant {
+                size: size.name,
+                format: *format,
+                width: resized.width(),
+                height: resized.height(),
+                bytes: encode(&resized, *format)?,
+            });
+        }
+    }
+    Ok(variants)
+}
+
+async fn delete_files(storage: &dyn Storage, keys: &[String]) {
+    for key in keys {
+        if let Err(e) = storage.delete(key).await {
+            warn!("Failed to delete the image variant {}: {}", key, e);
+        }
+    }
+}
+
+// Make and store the variants of the image `source_key` of the content, returns how many were
+// recorded. The variants of the previous images are removed once the new ones are recorded.
+// Nothing is recorded once the content has another image, the files made for the replaced
+// one are deleted.
+pub async fn process_image_variants(
+    pool: &MySqlPool,
+    storage: &dyn Storage,
+    content_id: &str,
+    source_key: &str,
+) -> Result<usize, String> {
+    let bytes = storage.get(source_key).await?;
+    let variants = tokio::task::spawn_blocking(move || render_variants(&bytes))
+        .await
+        .map_err(|e| format!("Image processing stopped: {}", e))??;
+
+    let mut stored = Vec::new();
+    let mut recorded = Vec::new();
+    for variant in variants {
+        let key = variant_key(content_id, source_key, variant.size, variant.format);
+        if let Err(e) = storage
+            .put(&key, variant.bytes, variant.format.mime())
+            .await
+        {
+            delete_files(storage, &stored).await;
+            return Err(e);
+        }
+        stored.push(key.clone());
+        recorded.push((
+            variant.size,
+            variant.format,
+            key,
+            variant.width,
+            variant.height,
+        ));
+    }
+
+    match record_variants(pool, content_id, source_key, &recorded).await {
+        Ok(true) => {
+            if let Err(e) = remove_image_variants(pool, storage, content_id, source_key).await {
+                warn!(
+                    "Failed to remove the image variants of content {}: {}",
+                    content_id, e
+                );
+            }
+            Ok(recorded.len())
+        }
+        Ok(false) => {
+            delete_files(storage, &stored).await;
+            Ok(0)
+        }
+        Err(e) => {
+            delete_files(storage, &stored).await;
+            Err(format!("Failed to record the image variants: {}", e))
+        }
+    }
+}
+
+// All or none of the variants are recorded, and only while `source_key` is the image of
+// the content
+async fn record_variants(
+    pool: &MySqlPool,
+    content_id: &str,
+    source_key: &str,
+    variants: &[(&str, VariantFormat, String, u32, u32)],
+) -> Result<bool, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    for (size, format, key, width, height) in variants {
+        let insert_result = sqlx::query(
+            "INSERT INTO content_image_variants (content_id, source_key, size, format, storage_key, width, height) SELECT id, content_image, ?, ?, ?, ?, ? FROM contents WHERE id = ? AND content_image = ?",
+        )
+        .bind(size)
+        .bind(format.name())
+        .bind(key)
+        .bind(width)
+        .bind(height)
+        .bind(content_id)
+        .bind(source_key)
+        .execute(&mut *tx)
+        .await?;
+
+        if insert_result.rows_affected() == 0 {
+            return Ok(false);
+        }
+    }
+    tx.commit().await?;
+    Ok(true)
+}
+
+// Delete the variants made for the previous images of the content, kept when their file
+// cannot be deleted so the next replacement retries them
+pub async fn remove_image_variants(
+    pool: &MySqlPool,
+    storage: &dyn Storage,
+    content_id: &str,
+    current_source: &str,
+) -> Result<usize, String> {
+    let orphans: Vec<(i64, String)> = sqlx::query_as(
+        "SELECT id, storage_key FROM content_image_variants WHERE content_id = ? AND source_key <> ?",
+    )
+    .bind(content_id)
+    .bind(current_source)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch the image variants: {}", e))?;
+
+    let mut removed = 0;
+    for (id, key) in orphans {
+        if let Err(e) = storage.delete(&key).await {
+            warn!("Failed to delete the image variant {}: {}", key, e);
+            continue;
+        }
+        sqlx::query("DELETE FROM content_image_variants WHERE id = ?")
+            .bind(id)
+            .execute(pool)
+            .await
+            .map_err(|e| format!("Failed to delete the image variant {}: {}", key, e))?;
+        removed += 1;
+    }
+    Ok(removed)
+}
+
+// Variants of the current image of each content
+pub async fn content_image_variants(
+    pool: &MySqlPool,
+    content_ids: &[&str],
+) -> Result<Vec<ContentImageVariantModel>, ApiResponseError> {
+    if content_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let query = format!(
+        "SELECT v.* FROM content_image_variants v JOIN contents c ON c.id = v.content_id AND c.content_image = v.source_key WHERE v.content_id IN ({}) ORDER BY v.id",
+        vec!["?"; content_ids.len()].join(", ")
+    );
+    let mut variants_query = sqlx::query_as::<_, ContentImageVariantModel>(&query);
+    for content_id in content_ids {
+        variants_query = variants_query.bind(content_id);
+    }
+
+    variants_query
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageFormat, Rgb, RgbImage};
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        RgbImage::from_pixel(width, height, Rgb([200, 80, 40]))
+            .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+            .unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_image_dimensions() {
+        assert_eq!(image_dimensions(&png(800, 600)).unwrap(), (800, 600));
+        assert!(image_dimensions(b"\x89PNG\r\n\x1a\n").is_err());
+    }
+
+    #[test]
+    fn test_variant_key() {
+        assert_eq!(
+            variant_key(
+                "1",
+                "contents/1/a-photo.png",
+                "thumbnail",
+                VariantFormat::Webp
+            ),
+            "contents/1/a-photo-thumbnail.webp"
+        );
+        assert_eq!(
+            variant_key("2", "media/a-photo.png", "large", VariantFormat::Jpeg),
+            "contents/2/a-photo-large.jpg"
+        );
+    }
+
+    #[test]
+    fn test_render_variants() {
+        let variants = render_variants(&png(800, 600)).unwrap();
+        assert_eq!(variants.len(), VARIANT_SIZES.len() * VARIANT_FORMATS.len());
+
+        let dimensions = |size: &str| {
+            let variant = variants
+                .iter()
+                .find(|variant| variant.size == size)
+                .unwrap();
+            (variant.width, variant.height)
+        };
+        // The aspect ratio is kept and the image is not upscaled
+        assert_eq!(dimensions("thumbnail"), (150, 113));
+        assert_eq!(dimensions("medium"), (640, 480));
+        assert_eq!(dimensions("large"), (800, 600));
+
+        for variant in &variants {
+            let format = image::guess_format(&variant.bytes).unwrap();
+            match variant.format {
+                VariantFormat::Webp => assert_eq!(format, ImageFormat::WebP),
+                VariantFormat::Jpeg => assert_eq!(format, ImageFormat::Jpeg),
+            }
+        }
+    }
+}
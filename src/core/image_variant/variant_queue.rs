@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use log::{error, info, warn};
+use sqlx::MySqlPool;
+use tokio::sync::mpsc::{self, Receiver, Sender};
+
+use super::process_image_variants;
+use crate::core::storage::Storage;
+
+#[derive(Debug)]
+pub struct VariantJob {
+    pub content_id: String,
+    // Key of the stored image
+    pub source_key: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct VariantQueue {
+    pub sender: Sender<VariantJob>,
+}
+
+impl VariantQueue {
+    pub fn new() -> (Self, Receiver<VariantJob>) {
+        let (sender, receiver) = mpsc::channel(100); // Buffer size of 100
+        (Self { sender }, receiver)
+    }
+
+    // Queue the variants of the image of a content, a full queue is waited for
+    pub async fn queue(&self, content_id: &str, source_key: &str) {
+        let job = VariantJob {
+            content_id: content_id.to_string(),
+            source_key: source_key.to_string(),
+        };
+        if let Err(e) = self.sender.send(job).await {
+            warn!(
+                "Failed to queue the image variants of {}: {}",
+                source_key, e
+            );
+        }
+    }
+
+    pub async fn process_queue(
+        mut receiver: Receiver<VariantJob>,
+        pool: MySqlPool,
+        storage: Arc<dyn Storage>,
+    ) {
+        while let Some(job) = receiver.recv().await {
+            match process_image_variants(&pool, storage.as_ref(), &job.content_id, &job.source_key)
+                .await
+            {
+                Ok(count) => info!(
+                    "Made {} image variants of {} for content {}",
+                    count, job.source_key, job.content_id
+                ),
+                Err(e) => error!(
+                    "Failed to make the image variants of {} for content {}: {}",
+                    job.source_key, job.content_id, e
+                ),
+            }
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use actix_web::http::Method;
+use chrono::Duration;
+use sqlx::MySqlPool;
+
+use crate::{
+    config::CONFIG,
+    core::{permission::has_permission, policy::forbidden},
+    model::user::UserModel,
+    schema::response::api_response_error::ApiResponseError,
+};
+
+const MAX_PATH_LENGTH: usize = 2048;
+
+// Users whose role manages the users cannot be impersonated
+pub const MANAGE_USERS_PERMISSION: &str = "users.manage";
+
+// Events of the impersonation audit trail
+pub const EVENT_ISSUED: &str = "issued";
+pub const EVENT_REQUEST: &str = "request";
+
+// One row of the impersonation audit trail
+pub struct ImpersonationEvent<'a> {
+    pub impersonator_id: &'a str,
+    pub user_id: &'a str,
+    pub token_id: &'a str,
+    pub event: &'a str,
+    pub method: &'a str,
+    pub path: &'a str,
+    pub ip_address: Option<String>,
+}
+
+pub fn impersonation_token_duration() -> Duration {
+    Duration::minutes(CONFIG.impersonation.token_minutes)
+}
+
+// Protected users and the users managing the other users cannot be impersonated,
+// so the token never grants more than support needs
+pub async fn authorize_impersonation(
+    pool: &MySqlPool,
+    impersonator_id: &str,
+    target: &UserModel,
+) -> Result<(), ApiResponseError> {
+    let manages_users = has_permission(pool, &target.id, MANAGE_USERS_PERMISSION)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal server error: {}", e), None))?;
+    check_impersonation_target(impersonator_id, target, manages_users)
+}
+
+// The rules of `authorize_impersonation`, once the permission of the target is known
+fn check_impersonation_target(
+    impersonator_id: &str,
+    target: &UserModel,
+    manages_users: bool,
+) -> Result<(), ApiResponseError> {
+    if target.id == impersonator_id {
+        return Err(ApiResponseError::new(
+            400,
+            "You cannot impersonate yourself".to_string(),
+            None,
+        ));
+    }
+    if target.protected == 1 || manages_users {
+        return Err(forbidden("impersonate", "user"));
+    }
+    Ok(())
+}
+
+// Impersonation tokens only read as the user, so support cannot change the profile,
+// the sessions or the two-factor settings of the user
+pub fn check_impersonation_method(method: &Method) -> Result<(), ApiResponseError> {
+    if method != Method::GET && method != Method::HEAD {
+        return Err(ApiResponseError::new(
+            403,
+            "Impersonation tokens can only read".to_string(),
+            None,
+        ));
+    }
+    Ok(())
+}
+
+pub async fn record_impersonation(
+    pool: &MySqlPool,
+    event: &ImpersonationEvent<'_>,
+) -> Result<(), sqlx::Error> {
+    let path: String = event.path.chars().take(MAX_PATH_LENGTH).collect();
+
+    sqlx::query(
+        "INSERT INTO impersonation_logs (impersonator_id, user_id, token_id, event, method, path, ip_address) VALUES (?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(event.impersonator_id)
+    .bind(event.user_id)
+    .bind(event.token_id)
+    .bind(event.event)
+    .bind(event.method)
+    .bind(path)
+    .bind(&event.ip_address)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADMIN_ID: &str = "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e2b";
+
+    fn user(role_id: i32, protected: i8) -> UserModel {
+        UserModel {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: "test".to_string(),
+            mobile: "+966501234567".to_string(),
+            email: None,
+            gender: None,
+            avatar: None,
+            role_id,
+            active: 1,
+            protected,
+            verification_expires_at: None,
+            created_at: None,
+            updated_at: None,
+            deleted_at: None,
+        }
+    }
+
+    #[test]
+    fn test_check_impersonation_method() {
+        assert!(check_impersonation_method(&Method::GET).is_ok());
+        assert!(check_impersonation_method(&Method::HEAD).is_ok());
+        for method in [Method::POST, Method::PUT, Method::PATCH, Method::DELETE] {
+            assert_eq!(check_impersonation_method(&method).unwrap_err().status, 403);
+        }
+    }
+
+    #[test]
+    fn test_check_impersonation_target() {
+        assert!(check_impersonation_target(ADMIN_ID, &user(3, 0), false).is_ok());
+        assert!(check_impersonation_target(ADMIN_ID, &user(2, 0), false).is_ok());
+
+        assert_eq!(
+            check_impersonation_target(ADMIN_ID, &user(3, 1), false)
+                .unwrap_err()
+                .status,
+            403
+        );
+        // Any role managing the users, not only the admin one
+        assert_eq!(
+            check_impersonation_target(ADMIN_ID, &user(4, 0), true)
+                .unwrap_err()
+                .status,
+            403
+        );
+
+        let mut admin = user(1, 0);
+        admin.id = ADMIN_ID.to_string();
+        assert_eq!(
+            check_impersonation_target(ADMIN_ID, &admin, true)
+                .unwrap_err()
+                .status,
+            400
+        );
+    }
+}
@@ -0,0 +1,274 @@
+use sha2::{Digest, Sha256};
+use sqlx::{MySqlConnection, MySqlPool};
+
+use crate::{
+    core::{image_variant::image_dimensions, storage::Storage, upload::UploadedImage},
+    model::media::{GalleryMediaModel, MediaModel},
+    schema::response::api_response_error::{ApiResponseError, ValidationErrorDetail},
+};
+
+pub const MAX_ALT_TEXT_LENGTH: usize = 255;
+
+// Largest gallery of a content
+pub const MAX_GALLERY_SIZE: usize = 50;
+
+fn internal_error(e: impl std::fmt::Display) -> ApiResponseError {
+    ApiResponseError::new(500, format!("Internal Server Error: {}", e), None)
+}
+
+fn validation_error(field: &str, error: String) -> ApiResponseError {
+    ApiResponseError::new(
+        400,
+        "Validation Error".to_string(),
+        Some(vec![ValidationErrorDetail {
+            field: field.to_string(),
+            error,
+        }]),
+    )
+}
+
+pub fn validate_alt_text(alt_text: Option<&str>) -> Result<Option<String>, ApiResponseError> {
+    let alt_text = alt_text
+        .map(str::trim)
+        .filter(|alt_text| !alt_text.is_empty());
+    if alt_text.is_some_and(|alt_text| alt_text.chars().count() > MAX_ALT_TEXT_LENGTH) {
+        return Err(validation_error(
+            "alt_text",
+            format!(
+                "Alt text must be at most {} characters",
+                MAX_ALT_TEXT_LENGTH
+            ),
+        ));
+    }
+    Ok(alt_text.map(str::to_string))
+}
+
+pub fn checksum(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+pub async fn find_media(pool: &MySqlPool, media_id: &str) -> Result<MediaModel, ApiResponseError> {
+    sqlx::query_as!(MediaModel, "SELECT * FROM media WHERE id = ?", media_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(internal_error)?
+        .ok_or_else(|| ApiResponseError::new(404, "Media not found".to_string(), None))
+}
+
+async fn find_media_by_checksum(
+    pool: &MySqlPool,
+    checksum: &str,
+) -> Result<Option<MediaModel>, ApiResponseError> {
+    sqlx::query_as!(
+        MediaModel,
+        "SELECT * FROM media WHERE checksum = ?",
+        checksum
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(internal_error)
+}
+
+// Add the image to the library, returns the media and whether it is new. An image that is
+// already in the library is not stored twice, the existing media is returned instead.
+pub async fn store_media(
+    pool: &MySqlPool,
+    storage: &dyn Storage,
+    image: UploadedImage,
+    alt_text: Option<&str>,
+    user_id: &str,
+) -> Result<(MediaModel, bool), ApiResponseError> {
+    let checksum = checksum(&image.bytes);
+    if let Some(media) = find_media_by_checksum(pool, &checksum).await? {
+        return Ok((media, false));
+    }
+
+    let (width, height) = image_dimensions(&image.bytes)
+        .map_err(|_| ApiResponseError::new(400, "Image could not be read".to_string(), None))?;
+    let media_id = uuid::Uuid::new_v4().to_string();
+    let file_name = format!("{}.{}", image.name, image.image_type.extension());
+    let key = format!("media/{}", image.file_name());
+    let size_bytes = image.bytes.len() as u64;
+
+    storage
+        .put(&key, image.bytes, image.image_type.mime())
+        .await
+        .map_err(internal_error)?;
+
+    let insert_result = sqlx::query(
+        "INSERT INTO media (id, storage_key, file_name, mime_type, size_bytes, width, height, alt_text, checksum, uploaded_by) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(&media_id)
+    .bind(&key)
+    .bind(&file_name)
+    .bind(image.image_type.mime())
+    .bind(size_bytes)
+    .bind(width)
+    .bind(height)
+    .bind(alt_text)
+    .bind(&checksum)
+    .bind(user_id)
+    .execute(pool)
+    .await;
+
+    if let Err(e) = insert_result {
+        if let Err(delete_error) = storage.delete(&key).await {
+            log::warn!(
+                "Failed to delete the unused media {}: {}",
+                key,
+                delete_error
+            );
+        }
+        // The same image was uploaded at the same time
+        if e.to_string().contains("Duplicate entry") {
+            if let Some(media) = find_media_by_checksum(pool, &checksum).await? {
+                return Ok((media, false));
+            }
+        }
+        return Err(internal_error(e));
+    }
+
+    Ok((find_media(pool, &media_id).await?, true))
+}
+
+// How many contents use the media, as their image or in their gallery
+pub async fn media_references(pool: &MySqlPool, media_id: &str) -> Result<i64, ApiResponseError> {
+    sqlx::query_scalar(
+        "SELECT (SELECT COUNT(*) FROM contents WHERE content_image_media_id = ?) + (SELECT COUNT(*) FROM content_gallery WHERE media_id = ?)",
+    )
+    .bind(media_id)
+    .bind(media_id)
+    .fetch_one(pool)
+    .await
+    .map_err(internal_error)
+}
+
+// The media set as the image of a content, sent in `field`
+pub async fn find_content_media(
+    pool: &MySqlPool,
+    field: &str,
+    media_id: &str,
+) -> Result<MediaModel, ApiResponseError> {
+    find_media(pool, media_id).await.map_err(|e| {
+        if e.status == 404 {
+            validation_error(field, format!("Media {} not found", media_id))
+        } else {
+            e
+        }
+    })
+}
+
+// Media ids of a gallery without the repeated ones, every media must exist
+pub async fn validate_gallery(
+    pool: &MySqlPool,
+    media_ids: &[String],
+) -> Result<Vec<String>, ApiResponseError> {
+    let mut gallery: Vec<String> = Vec::new();
+    for media_id in media_ids {
+        if !gallery.contains(media_id) {
+            gallery.push(media_id.to_owned());
+        }
+    }
+    if gallery.len() > MAX_GALLERY_SIZE {
+        return Err(validation_error(
+            "gallery",
+            format!("Gallery must have at most {} images", MAX_GALLERY_SIZE),
+        ));
+    }
+
+    for media_id in &gallery {
+        find_content_media(pool, "gallery", media_id).await?;
+    }
+    Ok(gallery)
+}
+
+// Run inside the transaction that changes the content
+pub async fn set_content_image_media(
+    conn: &mut MySqlConnection,
+    content_id: &str,
+    media: &MediaModel,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "UPDATE contents SET content_image = ?, content_image_width = ?, content_image_height = ?, content_image_media_id = ? WHERE id = ?",
+    )
+    .bind(&media.storage_key)
+    .bind(media.width)
+    .bind(media.height)
+    .bind(&media.id)
+    .bind(content_id)
+    .execute(conn)
+    .await?;
+    Ok(())
+}
+
+// Run inside the transaction that changes the content, the gallery is replaced
+pub async fn set_content_gallery(
+    conn: &mut MySqlConnection,
+    content_id: &str,
+    media_ids: &[String],
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM content_gallery WHERE content_id = ?")
+        .bind(content_id)
+        .execute(&mut *conn)
+        .await?;
+
+    for (position, media_id) in media_ids.iter().enumerate() {
+        sqlx::query(
+            "INSERT INTO content_gallery (content_id, media_id, position) VALUES (?, ?, ?)",
+        )
+        .bind(content_id)
+        .bind(media_id)
+        .bind(position as u32)
+        .execute(&mut *conn)
+        .await?;
+    }
+    Ok(())
+}
+
+// Galleries of the contents, in order
+pub async fn content_galleries(
+    pool: &MySqlPool,
+    content_ids: &[&str],
+) -> Result<Vec<GalleryMediaModel>, ApiResponseError> {
+    if content_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let query = format!(
+        "SELECT g.content_id, m.* FROM content_gallery g INNER JOIN media m ON m.id = g.media_id WHERE g.content_id IN ({}) ORDER BY g.content_id, g.position",
+        vec!["?"; content_ids.len()].join(", ")
+    );
+    let mut gallery_query = sqlx::query_as::<_, GalleryMediaModel>(&query);
+    for content_id in content_ids {
+        gallery_query = gallery_query.bind(content_id);
+    }
+
+    gallery_query.fetch_all(pool).await.map_err(internal_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_alt_text() {
+        assert_eq!(validate_alt_text(None).unwrap(), None);
+        assert_eq!(validate_alt_text(Some("  ")).unwrap(), None);
+        assert_eq!(
+            validate_alt_text(Some(" A red square ")).unwrap(),
+            Some("A red square".to_string())
+        );
+        let err = validate_alt_text(Some(&"a".repeat(MAX_ALT_TEXT_LENGTH + 1))).unwrap_err();
+        assert_eq!(err.validation_errors.unwrap()[0].field, "alt_text");
+    }
+
+    #[test]
+    fn test_checksum() {
+        // SHA-256 of an empty file
+        assert_eq!(
+            checksum(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_ne!(checksum(b"a"), checksum(b"b"));
+    }
+}
@@ -1,8 +1,29 @@
 pub mod api_doc;
 pub mod app_state;
-pub mod constants;
+pub mod application;
+pub mod avatar;
 pub mod enums;
 pub mod faker;
+pub mod files;
+pub mod image_variant;
+pub mod impersonation;
 pub mod mail;
+pub mod media;
+pub mod otp;
+pub mod otp_limit;
+pub mod permission;
+pub mod phone;
+pub mod policy;
+pub mod registration;
+pub mod revision;
+pub mod schedule;
+pub mod session;
+pub mod signature;
+pub mod slug;
 pub mod sms;
+pub mod soft_delete;
+pub mod storage;
+pub mod two_factor;
+pub mod upload;
 pub mod utils;
+pub mod workflow;
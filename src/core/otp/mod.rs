@@ -0,0 +1,188 @@
+use chrono::{Duration, Utc};
+use hmac::{Hmac, Mac};
+use log::error;
+use rand::RngCore;
+use sha2::Sha256;
+use sqlx::MySqlPool;
+
+use crate::{
+    config::CONFIG,
+    core::{
+        mail::email_queue::{EmailJob, EmailQueue},
+        sms::sms_queue::{SmsJob, SmsQueue},
+    },
+    model::user_otp::UserOtpModel,
+    schema::response::api_response_error::ApiResponseError,
+};
+
+type HmacSha256 = Hmac<Sha256>;
+
+const SALT_BYTES: usize = 16;
+
+// Result of checking a submitted OTP against the stored one
+#[derive(Debug, PartialEq)]
+pub enum OtpCheck {
+    Valid,
+    Invalid,
+    Expired,
+    Missing, // No OTP requested, already used or dropped after too many attempts
+}
+
+pub fn generate_salt() -> String {
+    let mut salt = [0u8; SALT_BYTES];
+    rand::thread_rng().fill_bytes(&mut salt);
+    hex::encode(salt)
+}
+
+// The hash is keyed with the server secret, so a leaked table
+// cannot be brute forced without the configuration as well
+fn otp_mac(code: &str, salt: &str) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(CONFIG.jwt.secret.as_bytes())
+        .expect("HMAC can take a key of any size");
+    mac.update(salt.as_bytes());
+    mac.update(b":");
+    mac.update(code.as_bytes());
+    mac
+}
+
+pub fn hash_otp(code: &str, salt: &str) -> String {
+    hex::encode(otp_mac(code, salt).finalize().into_bytes())
+}
+
+// Constant-time comparison of the submitted code with the stored hash
+pub fn verify_otp_hash(code: &str, salt: &str, code_hash: &str) -> bool {
+    match hex::decode(code_hash) {
+        Ok(expected) => otp_mac(code, salt).verify_slice(&expected).is_ok(),
+        Err(_) => false,
+    }
+}
+
+// Store a new OTP for the user, replacing any previous one
+pub async fn store_otp(pool: &MySqlPool, user_id: &str, code: &str) -> Result<(), sqlx::Error> {
+    let salt = generate_salt();
+    let expires_at = Utc::now() + Duration::seconds(CONFIG.otp.code_ttl_seconds);
+
+    sqlx::query(
+        "INSERT INTO user_otps (user_id, code_hash, salt, attempts, expires_at) VALUES (?, ?, ?, 0, ?) ON DUPLICATE KEY UPDATE code_hash = VALUES(code_hash), salt = VALUES(salt), attempts = 0, expires_at = VALUES(expires_at), created_at = CURRENT_TIMESTAMP",
+    )
+    .bind(user_id)
+    .bind(hash_otp(code, &salt))
+    .bind(&salt)
+    .bind(expires_at)
+    .execute(pool)
+    .await
+    .map(|_| ())
+}
+
+// Check a submitted OTP, a wrong code counts against the OTP and
+// the OTP is dropped once it has used all its attempts
+pub async fn check_otp(
+    pool: &MySqlPool,
+    user_id: &str,
+    code: &str,
+) -> Result<OtpCheck, sqlx::Error> {
+    let stored_otp = sqlx::query_as!(
+        UserOtpModel,
+        "SELECT * FROM user_otps WHERE user_id = ?",
+        user_id
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    let Some(stored_otp) = stored_otp else {
+        return Ok(OtpCheck::Missing);
+    };
+
+    if stored_otp.expires_at < Utc::now() {
+        delete_otp(pool, user_id).await?;
+        return Ok(OtpCheck::Expired);
+    }
+
+    if verify_otp_hash(code, &stored_otp.salt, &stored_otp.code_hash) {
+        return Ok(OtpCheck::Valid);
+    }
+
+    if stored_otp.attempts + 1 >= CONFIG.otp.max_code_attempts {
+        delete_otp(pool, user_id).await?;
+    } else {
+        sqlx::query("UPDATE user_otps SET attempts = attempts + 1 WHERE user_id = ?")
+            .bind(user_id)
+            .execute(pool)
+            .await?;
+    }
+
+    Ok(OtpCheck::Invalid)
+}
+
+pub async fn delete_otp(pool: &MySqlPool, user_id: &str) -> Result<u64, sqlx::Error> {
+    sqlx::query("DELETE FROM user_otps WHERE user_id = ?")
+        .bind(user_id)
+        .execute(pool)
+        .await
+        .map(|result| result.rows_affected())
+}
+
+// Queue the OTP by SMS, and by email when the user has one.
+// Outside of production the fixed OTP is used and nothing is sent.
+pub async fn send_otp(
+    sms_queue: &SmsQueue,
+    email_queue: &EmailQueue,
+    mobile: &str,
+    email: Option<&str>,
+    subject: &str,
+    otp: &str,
+) -> Result<(), ApiResponseError> {
+    if !CONFIG.env.eq_ignore_ascii_case("production") {
+        return Ok(());
+    }
+
+    let job = SmsJob {
+        to: mobile.to_string(),
+        body: format!("{}: {}", subject, otp),
+    };
+    if let Err(e) = sms_queue.sender.send(job).await {
+        error!("Failed to queue SMS to: {}. Error: {:?}", mobile, e);
+        return Err(ApiResponseError::new(500, "Server Error".to_string(), None));
+    }
+
+    if let Some(email) = email {
+        let job = EmailJob {
+            subject: subject.to_string(),
+            to: email.to_string(),
+            body: format!("{}: {}", subject, otp),
+        };
+        if let Err(e) = email_queue.sender.send(job).await {
+            error!("Failed to queue Email: {:?}", e);
+            return Err(ApiResponseError::new(500, "Server Error".to_string(), None));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_otp_is_salted() {
+        let salt = generate_salt();
+        let hash = hash_otp("123456", &salt);
+
+        assert_eq!(salt.len(), SALT_BYTES * 2);
+        assert_eq!(hash, hash_otp("123456", &salt));
+        assert_ne!(hash, hash_otp("123456", &generate_salt()));
+        assert!(!hash.contains("123456"));
+    }
+
+    #[test]
+    fn test_verify_otp_hash() {
+        let salt = generate_salt();
+        let hash = hash_otp("123456", &salt);
+
+        assert!(verify_otp_hash("123456", &salt, &hash));
+        assert!(!verify_otp_hash("123457", &salt, &hash));
+        assert!(!verify_otp_hash("123456", &generate_salt(), &hash));
+        assert!(!verify_otp_hash("123456", &salt, "not-hex"));
+    }
+}
@@ -0,0 +1,243 @@
+use chrono::{DateTime, Duration, Utc};
+use sqlx::MySqlPool;
+
+use crate::{
+    config::CONFIG, model::otp_rate_limit::OtpRateLimitModel,
+    schema::response::api_response_error::ApiResponseError,
+};
+
+// Actions counted by the limiter
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OtpAction {
+    Resend,       // An OTP was generated and sent (login)
+    FailedVerify, // A wrong OTP or second factor was submitted
+}
+
+impl OtpAction {
+    fn to_str(self) -> &'static str {
+        match self {
+            OtpAction::Resend => "resend",
+            OtpAction::FailedVerify => "verify",
+        }
+    }
+
+    fn max_attempts(self, subject_type: SubjectType) -> i32 {
+        match (self, subject_type) {
+            (OtpAction::Resend, SubjectType::Mobile) => CONFIG.otp.max_resends_per_mobile,
+            (OtpAction::Resend, SubjectType::Ip) => CONFIG.otp.max_resends_per_ip,
+            (OtpAction::FailedVerify, SubjectType::Mobile) => {
+                CONFIG.otp.max_failed_verifies_per_mobile
+            }
+            (OtpAction::FailedVerify, SubjectType::Ip) => CONFIG.otp.max_failed_verifies_per_ip,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SubjectType {
+    Mobile,
+    Ip,
+}
+
+impl SubjectType {
+    fn to_str(self) -> &'static str {
+        match self {
+            SubjectType::Mobile => "mobile",
+            SubjectType::Ip => "ip",
+        }
+    }
+}
+
+fn subjects<'a>(mobile: &'a str, ip_address: Option<&'a str>) -> Vec<(SubjectType, &'a str)> {
+    let mut subjects = vec![(SubjectType::Mobile, mobile)];
+    if let Some(ip_address) = ip_address {
+        subjects.push((SubjectType::Ip, ip_address));
+    }
+    subjects
+}
+
+// Seconds until `until`, rounded up so clients never retry too early
+fn seconds_until(until: DateTime<Utc>, now: DateTime<Utc>) -> u64 {
+    let millis = (until - now).num_milliseconds().max(0) as u64;
+    millis.div_ceil(1000).max(1)
+}
+
+// Remaining cooldown before another OTP can be sent, if any
+fn cooldown_remaining(
+    last_attempt_at: Option<DateTime<Utc>>,
+    cooldown: Duration,
+    now: DateTime<Utc>,
+) -> Option<u64> {
+    let available_at = last_attempt_at? + cooldown;
+    (available_at > now).then(|| seconds_until(available_at, now))
+}
+
+fn too_many_attempts(retry_after: u64) -> ApiResponseError {
+    ApiResponseError::new(
+        429,
+        format!(
+            "Too many attempts, please try again in {} seconds",
+            retry_after
+        ),
+        None,
+    )
+    .with_retry_after(retry_after)
+}
+
+async fn find_limit(
+    pool: &MySqlPool,
+    action: OtpAction,
+    subject_type: SubjectType,
+    subject: &str,
+) -> Result<Option<OtpRateLimitModel>, ApiResponseError> {
+    sqlx::query_as!(
+        OtpRateLimitModel,
+        "SELECT * FROM otp_rate_limits WHERE action = ? AND subject_type = ? AND subject = ?",
+        action.to_str(),
+        subject_type.to_str(),
+        subject
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))
+}
+
+// Return 429 when the mobile or the IP is locked out, or when an OTP was sent too recently
+pub async fn check_otp_limits(
+    pool: &MySqlPool,
+    action: OtpAction,
+    mobile: &str,
+    ip_address: Option<&str>,
+) -> Result<(), ApiResponseError> {
+    let now = Utc::now();
+
+    for (subject_type, subject) in subjects(mobile, ip_address) {
+        let Some(limit) = find_limit(pool, action, subject_type, subject).await? else {
+            continue;
+        };
+
+        if let Some(locked_until) = limit.locked_until.filter(|until| *until > now) {
+            return Err(too_many_attempts(seconds_until(locked_until, now)));
+        }
+
+        if action == OtpAction::Resend && subject_type == SubjectType::Mobile {
+            let cooldown = Duration::seconds(CONFIG.otp.resend_cooldown_seconds);
+            if let Some(retry_after) = cooldown_remaining(limit.last_attempt_at, cooldown, now) {
+                return Err(too_many_attempts(retry_after));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Count an attempt for the mobile and the IP, locking them out once the limit is reached.
+// Returns true when the mobile has just been locked out.
+pub async fn record_otp_attempt(
+    pool: &MySqlPool,
+    action: OtpAction,
+    mobile: &str,
+    ip_address: Option<&str>,
+) -> Result<bool, ApiResponseError> {
+    let now = Utc::now();
+    let window_start = now - Duration::minutes(CONFIG.otp.window_minutes);
+    let locked_until = now + Duration::minutes(CONFIG.otp.lockout_minutes);
+    let mut mobile_locked = false;
+
+    for (subject_type, subject) in subjects(mobile, ip_address) {
+        // Attempts outside of the window start a new one
+        sqlx::query(
+            "INSERT INTO otp_rate_limits (action, subject_type, subject, attempts, window_started_at, last_attempt_at) VALUES (?, ?, ?, 1, ?, ?) ON DUPLICATE KEY UPDATE attempts = IF(window_started_at < ?, 1, attempts + 1), window_started_at = IF(window_started_at < ?, ?, window_started_at), last_attempt_at = ?",
+        )
+        .bind(action.to_str())
+        .bind(subject_type.to_str())
+        .bind(subject)
+        .bind(now)
+        .bind(now)
+        .bind(window_start)
+        .bind(window_start)
+        .bind(now)
+        .bind(now)
+        .execute(pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+        // Lock out and start counting again once the lockout is over
+        let lock_result = sqlx::query(
+            "UPDATE otp_rate_limits SET locked_until = ?, attempts = 0, window_started_at = ? WHERE action = ? AND subject_type = ? AND subject = ? AND attempts >= ?",
+        )
+        .bind(locked_until)
+        .bind(locked_until)
+        .bind(action.to_str())
+        .bind(subject_type.to_str())
+        .bind(subject)
+        .bind(action.max_attempts(subject_type))
+        .execute(pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+        if lock_result.rows_affected() > 0 && subject_type == SubjectType::Mobile {
+            mobile_locked = true;
+        }
+    }
+
+    Ok(mobile_locked)
+}
+
+// Forget the attempts of a mobile (after a successful verify)
+pub async fn clear_otp_attempts(
+    pool: &MySqlPool,
+    action: OtpAction,
+    mobile: &str,
+) -> Result<(), ApiResponseError> {
+    sqlx::query(
+        "DELETE FROM otp_rate_limits WHERE action = ? AND subject_type = ? AND subject = ? AND (locked_until IS NULL OR locked_until < ?)",
+    )
+    .bind(action.to_str())
+    .bind(SubjectType::Mobile.to_str())
+    .bind(mobile)
+    .bind(Utc::now())
+    .execute(pool)
+    .await
+    .map(|_| ())
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seconds_until_rounds_up() {
+        let now = Utc::now();
+
+        assert_eq!(seconds_until(now + Duration::milliseconds(1500), now), 2);
+        assert_eq!(seconds_until(now + Duration::seconds(60), now), 60);
+        // Never tell the client to retry immediately
+        assert_eq!(seconds_until(now - Duration::seconds(5), now), 1);
+    }
+
+    #[test]
+    fn test_cooldown_remaining() {
+        let now = Utc::now();
+        let cooldown = Duration::seconds(60);
+
+        assert_eq!(cooldown_remaining(None, cooldown, now), None);
+        assert_eq!(
+            cooldown_remaining(Some(now - Duration::seconds(20)), cooldown, now),
+            Some(40)
+        );
+        assert_eq!(
+            cooldown_remaining(Some(now - Duration::seconds(61)), cooldown, now),
+            None
+        );
+    }
+
+    #[test]
+    fn test_too_many_attempts_sets_retry_after() {
+        let error = too_many_attempts(30);
+
+        assert_eq!(error.status, 429);
+        assert_eq!(error.retry_after, Some(30));
+    }
+}
@@ -0,0 +1,175 @@
+use sqlx::{MySqlConnection, MySqlPool};
+
+use crate::{
+    model::role::{PermissionModel, RoleModel},
+    schema::response::api_response_error::{ApiResponseError, ValidationErrorDetail},
+};
+
+const MAX_ROLE_NAME_LENGTH: usize = 50;
+
+// Whether the role of the user has been granted the permission
+pub async fn has_permission(
+    pool: &MySqlPool,
+    user_id: &str,
+    permission: &str,
+) -> Result<bool, sqlx::Error> {
+    let count = sqlx::query_scalar!(
+        "SELECT COUNT(*) FROM users u INNER JOIN role_permissions rp ON rp.role_id = u.role_id INNER JOIN permissions p ON p.id = rp.permission_id WHERE u.id = ? AND p.name = ?",
+        user_id,
+        permission
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(count > 0)
+}
+
+// Whether members of the role have to enable two-factor authentication
+pub async fn role_requires_two_factor(pool: &MySqlPool, role_id: i32) -> Result<bool, sqlx::Error> {
+    let count = sqlx::query_scalar!(
+        "SELECT COUNT(*) FROM roles WHERE id = ? AND requires_two_factor = 1",
+        role_id
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(count > 0)
+}
+
+pub async fn find_role(pool: &MySqlPool, role_id: i32) -> Result<RoleModel, ApiResponseError> {
+    sqlx::query_as!(RoleModel, "SELECT * FROM roles WHERE id = ?", role_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?
+        .ok_or_else(|| {
+            ApiResponseError::new(404, format!("Role with ID: {} not found", role_id), None)
+        })
+}
+
+pub async fn find_permissions(pool: &MySqlPool) -> Result<Vec<PermissionModel>, ApiResponseError> {
+    sqlx::query_as!(PermissionModel, "SELECT * FROM permissions ORDER BY name")
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))
+}
+
+// Names of the permissions granted to the role
+pub async fn role_permission_names(
+    pool: &MySqlPool,
+    role_id: i32,
+) -> Result<Vec<String>, ApiResponseError> {
+    sqlx::query_scalar!(
+        "SELECT p.name FROM permissions p INNER JOIN role_permissions rp ON rp.permission_id = p.id WHERE rp.role_id = ? ORDER BY p.name",
+        role_id
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))
+}
+
+pub fn validate_role_name(name: &str) -> Result<String, ApiResponseError> {
+    let name = name.trim();
+    if name.is_empty() || name.chars().count() > MAX_ROLE_NAME_LENGTH {
+        return Err(ApiResponseError::new(
+            400,
+            "Validation Error".to_string(),
+            Some(vec![ValidationErrorDetail {
+                field: "name".to_string(),
+                error: format!(
+                    "Role name must be between 1 and {} characters",
+                    MAX_ROLE_NAME_LENGTH
+                ),
+            }]),
+        ));
+    }
+    Ok(name.to_string())
+}
+
+// Map permission names to their ids, unknown names are a validation error
+pub fn resolve_permission_ids(
+    permissions: &[PermissionModel],
+    names: &[String],
+) -> Result<Vec<i32>, ApiResponseError> {
+    let mut ids = Vec::with_capacity(names.len());
+    for name in names {
+        let name = name.trim();
+        let permission = permissions
+            .iter()
+            .find(|permission| permission.name == name)
+            .ok_or_else(|| {
+                ApiResponseError::new(400, format!("Unknown permission: {}", name), None)
+            })?;
+        if !ids.contains(&permission.id) {
+            ids.push(permission.id);
+        }
+    }
+    Ok(ids)
+}
+
+// Replace the permissions of the role, run inside the transaction of the handler
+pub async fn set_role_permissions(
+    conn: &mut MySqlConnection,
+    role_id: i32,
+    permission_ids: &[i32],
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM role_permissions WHERE role_id = ?")
+        .bind(role_id)
+        .execute(&mut *conn)
+        .await?;
+    for permission_id in permission_ids {
+        sqlx::query("INSERT INTO role_permissions (role_id, permission_id) VALUES (?, ?)")
+            .bind(role_id)
+            .bind(permission_id)
+            .execute(&mut *conn)
+            .await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn permission(id: i32, name: &str) -> PermissionModel {
+        PermissionModel {
+            id,
+            name: name.to_string(),
+            description: None,
+            created_at: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_role_name() {
+        assert_eq!(validate_role_name(" editor ").unwrap(), "editor");
+        assert!(validate_role_name("  ").is_err());
+        assert!(validate_role_name(&"a".repeat(MAX_ROLE_NAME_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn test_resolve_permission_ids() {
+        let permissions = vec![
+            permission(1, "contents.view"),
+            permission(2, "contents.update"),
+        ];
+
+        let ids = resolve_permission_ids(
+            &permissions,
+            &[
+                "contents.update".to_string(),
+                " contents.view ".to_string(),
+                "contents.update".to_string(),
+            ],
+        )
+        .unwrap();
+        assert_eq!(ids, vec![2, 1]);
+
+        assert!(resolve_permission_ids(&permissions, &[])
+            .unwrap()
+            .is_empty());
+
+        let err =
+            resolve_permission_ids(&permissions, &["contents.delete".to_string()]).unwrap_err();
+        assert_eq!(err.status, 400);
+    }
+}
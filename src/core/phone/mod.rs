@@ -0,0 +1,105 @@
+use phonenumber::{country, metadata::DATABASE, Mode, Type};
+
+use crate::{
+    config::CONFIG,
+    schema::response::api_response_error::{ApiResponseError, ValidationErrorDetail},
+};
+
+// Parse a mobile number, local numbers are read in `default_region`.
+// Returns the number in E.164 format (e.g. +966501234567).
+pub fn parse_mobile(
+    mobile: &str,
+    default_region: &str,
+    allowed_countries: &[String],
+) -> Result<String, String> {
+    let region = default_region
+        .parse::<country::Id>()
+        .map_err(|_| format!("Invalid default region: {}", default_region))?;
+
+    let number = phonenumber::parse(Some(region), mobile.trim())
+        .map_err(|_| "Invalid mobile number".to_string())?;
+
+    if !number.is_valid() {
+        return Err("Invalid mobile number".to_string());
+    }
+
+    match number.number_type(&DATABASE) {
+        Type::Mobile | Type::FixedLineOrMobile => {}
+        _ => return Err("The number is not a mobile number".to_string()),
+    }
+
+    let country = number
+        .country()
+        .id()
+        .ok_or_else(|| "Invalid mobile number".to_string())?;
+    if !allowed_countries
+        .iter()
+        .any(|allowed| allowed.eq_ignore_ascii_case(country.as_ref()))
+    {
+        return Err(format!(
+            "Mobile numbers from {} are not supported",
+            country.as_ref()
+        ));
+    }
+
+    Ok(number.format().mode(Mode::E164).to_string())
+}
+
+// Normalize a mobile from a request with the configured region and countries
+pub fn normalize_mobile(mobile: &str) -> Result<String, ApiResponseError> {
+    parse_mobile(
+        mobile,
+        &CONFIG.phone.default_region,
+        &CONFIG.phone.allowed_countries,
+    )
+    .map_err(|error| {
+        ApiResponseError::new(
+            400,
+            "Validation Error".to_string(),
+            Some(vec![ValidationErrorDetail {
+                field: "mobile".to_string(),
+                error,
+            }]),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn allowed() -> Vec<String> {
+        vec!["SA".to_string(), "EG".to_string()]
+    }
+
+    #[test]
+    fn test_parse_mobile_normalizes_to_e164() {
+        let expected = Ok("+966501234567".to_string());
+
+        assert_eq!(parse_mobile("0501234567", "SA", &allowed()), expected);
+        assert_eq!(parse_mobile("+966501234567", "SA", &allowed()), expected);
+        assert_eq!(parse_mobile("00966501234567", "SA", &allowed()), expected);
+        assert_eq!(parse_mobile(" 050 123 4567 ", "SA", &allowed()), expected);
+        assert_eq!(
+            parse_mobile("+201018898522", "SA", &allowed()),
+            Ok("+201018898522".to_string())
+        );
+        assert_eq!(
+            parse_mobile("01018898522", "EG", &allowed()),
+            Ok("+201018898522".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_mobile_rejects_invalid_numbers() {
+        assert!(parse_mobile("not a number", "SA", &allowed()).is_err());
+        assert!(parse_mobile("05012", "SA", &allowed()).is_err());
+        // Saudi landline
+        assert!(parse_mobile("0112345678", "SA", &allowed()).is_err());
+        // Valid UAE mobile outside of the allowed countries
+        assert_eq!(
+            parse_mobile("+971501234567", "SA", &allowed()),
+            Err("Mobile numbers from AE are not supported".to_string())
+        );
+    }
+}
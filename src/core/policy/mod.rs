@@ -0,0 +1,309 @@
+use sqlx::MySqlPool;
+
+use crate::{
+    core::permission::has_permission,
+    model::{
+        application::ApplicationModel, content::ContentModel, role::RoleModel, user::UserModel,
+    },
+    schema::response::{admin::users::AuthUser, api_response_error::ApiResponseError},
+};
+
+// Permission needed to set or clear the `protected` flag of a record
+pub const PROTECT_PERMISSION: &str = "records.protect";
+
+// Permission needed to update, delete and restore the contents created by other users
+pub const MANAGE_ALL_CONTENTS_PERMISSION: &str = "contents.manage_all";
+
+// Records carrying the `protected` flag
+pub trait Protected {
+    const RESOURCE: &'static str;
+
+    fn is_protected(&self) -> bool;
+}
+
+impl Protected for UserModel {
+    const RESOURCE: &'static str = "user";
+
+    fn is_protected(&self) -> bool {
+        self.protected != 0
+    }
+}
+
+impl Protected for ContentModel {
+    const RESOURCE: &'static str = "content";
+
+    fn is_protected(&self) -> bool {
+        self.protected != 0
+    }
+}
+
+impl Protected for ApplicationModel {
+    const RESOURCE: &'static str = "application";
+
+    fn is_protected(&self) -> bool {
+        self.protected != 0
+    }
+}
+
+impl Protected for RoleModel {
+    const RESOURCE: &'static str = "role";
+
+    fn is_protected(&self) -> bool {
+        self.protected != 0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ContentAction {
+    Update,
+    Delete,
+    Restore,
+}
+
+impl ContentAction {
+    pub fn to_str(self) -> &'static str {
+        match self {
+            ContentAction::Update => "update",
+            ContentAction::Delete => "delete",
+            ContentAction::Restore => "restore",
+        }
+    }
+}
+
+// The error returned by every policy, so denials look the same on all endpoints
+pub fn forbidden(action: &str, resource: &str) -> ApiResponseError {
+    ApiResponseError::new(
+        403,
+        format!(
+            "Forbidden: you are not allowed to {} this {}",
+            action, resource
+        ),
+        None,
+    )
+}
+
+fn is_owner(user: &AuthUser, content: &ContentModel) -> bool {
+    user.id == content.created_by
+}
+
+fn protected_error(resource: &str) -> ApiResponseError {
+    ApiResponseError::new(
+        403,
+        format!("Forbidden: this {} is protected", resource),
+        None,
+    )
+}
+
+// Protected records cannot be changed or deleted by anyone, they have to be unprotected first
+pub fn ensure_unprotected<T: Protected>(record: &T) -> Result<(), ApiResponseError> {
+    if record.is_protected() {
+        return Err(protected_error(T::RESOURCE));
+    }
+    Ok(())
+}
+
+// The rules of `authorize_protected_update`, once the permission of the user is known
+fn check_protected_update<T: Protected>(
+    record: &T,
+    protected: Option<bool>,
+    can_protect: bool,
+) -> Result<(), ApiResponseError> {
+    let toggles = protected.is_some_and(|protected| protected != record.is_protected());
+    if toggles && !can_protect {
+        return Err(ApiResponseError::new(
+            403,
+            format!("Forbidden: missing permission {}", PROTECT_PERMISSION),
+            None,
+        ));
+    }
+    // A protected record only accepts the update that unprotects it
+    if record.is_protected() && !toggles {
+        return Err(protected_error(T::RESOURCE));
+    }
+    Ok(())
+}
+
+// Guard for updates, `protected` is the flag requested by the update if any.
+// - changing the flag requires the records.protect permission
+// - protected records cannot be updated unless the same update unprotects them
+pub async fn authorize_protected_update<T: Protected>(
+    pool: &MySqlPool,
+    user: &AuthUser,
+    record: &T,
+    protected: Option<bool>,
+) -> Result<(), ApiResponseError> {
+    let toggles = protected.is_some_and(|protected| protected != record.is_protected());
+    let can_protect = toggles
+        && has_permission(pool, &user.id, PROTECT_PERMISSION)
+            .await
+            .map_err(|e| {
+                ApiResponseError::new(500, format!("Internal Server Error: {}", e), None)
+            })?;
+    check_protected_update(record, protected, can_protect)
+}
+
+// Ownership rules for contents, checked by the handlers once the content is loaded.
+// The route permission (contents.update, contents.delete) is checked before by RequirePermission,
+// protected contents are guarded by `ensure_unprotected` and `authorize_protected_update`.
+// - roles with contents.manage_all may update, delete and restore any content
+// - other roles may only update, delete and restore the contents they created
+pub async fn authorize_content(
+    pool: &MySqlPool,
+    user: &AuthUser,
+    content: &ContentModel,
+    action: ContentAction,
+) -> Result<(), ApiResponseError> {
+    let manages_all = !is_owner(user, content)
+        && has_permission(pool, &user.id, MANAGE_ALL_CONTENTS_PERMISSION)
+            .await
+            .map_err(|e| {
+                ApiResponseError::new(500, format!("Internal Server Error: {}", e), None)
+            })?;
+    check_content_owner(user, content, action, manages_all)
+}
+
+// The rules of `authorize_content`, once the permission of the user is known
+fn check_content_owner(
+    user: &AuthUser,
+    content: &ContentModel,
+    action: ContentAction,
+    manages_all: bool,
+) -> Result<(), ApiResponseError> {
+    if !manages_all && !is_owner(user, content) {
+        return Err(forbidden(action.to_str(), "content"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EDITOR_ROLE: i32 = 2;
+
+    fn user(id: &str, role_id: i32) -> AuthUser {
+        AuthUser {
+            id: id.to_string(),
+            name: "test".to_string(),
+            mobile: "+966501234567".to_string(),
+            email: None,
+            gender: None,
+            role_id,
+            active: true,
+            protected: false,
+            createdAt: chrono::Utc::now(),
+            updatedAt: chrono::Utc::now(),
+            impersonator: None,
+        }
+    }
+
+    fn content(created_by: &str, protected: i8) -> ContentModel {
+        ContentModel {
+            id: uuid::Uuid::new_v4().to_string(),
+            content_type: "page".to_string(),
+            title: "title".to_string(),
+            slug: None,
+            summary: None,
+            details: None,
+            content_image: None,
+            content_image_width: None,
+            content_image_height: None,
+            content_image_media_id: None,
+            meta_title: None,
+            meta_description: None,
+            canonical_url: None,
+            record_state: 1,
+            protected,
+            publish_at: None,
+            unpublish_at: None,
+            scheduled_by: None,
+            created_at: None,
+            updated_at: None,
+            deleted_at: None,
+            created_by: created_by.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_editors_may_edit_their_own_content() {
+        let editor = user("editor", EDITOR_ROLE);
+
+        assert!(
+            check_content_owner(&editor, &content("editor", 0), ContentAction::Update, false)
+                .is_ok()
+        );
+        assert!(
+            check_content_owner(&editor, &content("editor", 1), ContentAction::Update, false)
+                .is_ok()
+        );
+        assert!(
+            check_content_owner(&editor, &content("editor", 0), ContentAction::Delete, false)
+                .is_ok()
+        );
+
+        let err = check_content_owner(&editor, &content("other", 0), ContentAction::Update, false)
+            .unwrap_err();
+        assert_eq!(err.status, 403);
+        assert_eq!(
+            err.message,
+            "Forbidden: you are not allowed to update this content"
+        );
+        assert!(
+            check_content_owner(&editor, &content("other", 0), ContentAction::Delete, false)
+                .is_err()
+        );
+        assert!(check_content_owner(
+            &editor,
+            &content("editor", 0),
+            ContentAction::Restore,
+            false
+        )
+        .is_ok());
+        assert!(
+            check_content_owner(&editor, &content("other", 0), ContentAction::Restore, false)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_managers_may_edit_any_content() {
+        let manager = user("manager", EDITOR_ROLE);
+
+        assert!(
+            check_content_owner(&manager, &content("other", 0), ContentAction::Delete, true)
+                .is_ok()
+        );
+        assert!(
+            check_content_owner(&manager, &content("other", 0), ContentAction::Update, true)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_protected_records_are_guarded() {
+        assert!(ensure_unprotected(&content("editor", 0)).is_ok());
+
+        let err = ensure_unprotected(&content("editor", 1)).unwrap_err();
+        assert_eq!(err.status, 403);
+        assert_eq!(err.message, "Forbidden: this content is protected");
+    }
+
+    #[test]
+    fn test_check_protected_update() {
+        // Plain updates of unprotected records, the flag is unchanged
+        assert!(check_protected_update(&content("editor", 0), None, false).is_ok());
+        assert!(check_protected_update(&content("editor", 0), Some(false), false).is_ok());
+
+        // Changing the flag requires the permission
+        let err = check_protected_update(&content("editor", 0), Some(true), false).unwrap_err();
+        assert_eq!(err.status, 403);
+        assert_eq!(err.message, "Forbidden: missing permission records.protect");
+        assert!(check_protected_update(&content("editor", 0), Some(true), true).is_ok());
+
+        // Protected records only accept the update that unprotects them
+        assert!(check_protected_update(&content("editor", 1), None, true).is_err());
+        assert!(check_protected_update(&content("editor", 1), Some(true), true).is_err());
+        assert!(check_protected_update(&content("editor", 1), Some(false), false).is_err());
+        assert!(check_protected_update(&content("editor", 1), Some(false), true).is_ok());
+    }
+}
@@ -0,0 +1,99 @@
+use chrono::{DateTime, Duration, Utc};
+use log::{error, info};
+use sqlx::MySqlPool;
+
+use crate::{config::CONFIG, schema::response::api_response_error::ApiResponseError};
+
+// Time a self-registered account has to verify its mobile
+pub fn verification_expires_at() -> DateTime<Utc> {
+    Utc::now() + Duration::hours(CONFIG.registration.verification_hours)
+}
+
+// Active users can login, as well as registrations waiting for their first OTP.
+// Inactive users that already verified were deactivated by an admin.
+pub fn check_account_status(
+    active: i8,
+    verification_expires_at: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> Result<(), ApiResponseError> {
+    if active == 1 {
+        return Ok(());
+    }
+
+    match verification_expires_at {
+        Some(expires_at) if expires_at > now => Ok(()),
+        Some(_) => Err(ApiResponseError::new(
+            403,
+            "Registration has expired, please register again".to_string(),
+            None,
+        )),
+        None => Err(ApiResponseError::new(
+            403,
+            "User is not active".to_string(),
+            None,
+        )),
+    }
+}
+
+// Activate a pending registration after its first successful OTP verification
+pub async fn activate_pending_user(pool: &MySqlPool, user_id: &str) -> Result<bool, sqlx::Error> {
+    sqlx::query(
+        "UPDATE users SET active = 1, verification_expires_at = NULL WHERE id = ? AND verification_expires_at IS NOT NULL",
+    )
+    .bind(user_id)
+    .execute(pool)
+    .await
+    .map(|result| result.rows_affected() > 0)
+}
+
+// Delete registrations that were never verified
+pub async fn purge_expired_registrations(pool: &MySqlPool) -> Result<u64, sqlx::Error> {
+    sqlx::query(
+        "DELETE FROM users WHERE active = 0 AND verification_expires_at IS NOT NULL AND verification_expires_at < ?",
+    )
+    .bind(Utc::now())
+    .execute(pool)
+    .await
+    .map(|result| result.rows_affected())
+}
+
+// Periodically remove expired registrations, safe to run on every instance
+pub async fn process_registration_cleanup(pool: MySqlPool) {
+    let interval_minutes = CONFIG.registration.cleanup_interval_minutes.max(1);
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs(interval_minutes * 60));
+
+    loop {
+        interval.tick().await;
+        match purge_expired_registrations(&pool).await {
+            Ok(0) => {}
+            Ok(deleted) => info!("Deleted {} expired registrations", deleted),
+            Err(e) => error!("Failed to delete expired registrations: {:?}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_account_status() {
+        let now = Utc::now();
+
+        // Active users and pending registrations can login
+        assert!(check_account_status(1, None, now).is_ok());
+        assert!(check_account_status(0, Some(now + Duration::hours(1)), now).is_ok());
+
+        // Expired registrations and deactivated users cannot
+        let expired = check_account_status(0, Some(now - Duration::hours(1)), now).unwrap_err();
+        assert_eq!(expired.status, 403);
+        assert_eq!(
+            expired.message,
+            "Registration has expired, please register again"
+        );
+
+        let inactive = check_account_status(0, None, now).unwrap_err();
+        assert_eq!(inactive.status, 403);
+        assert_eq!(inactive.message, "User is not active");
+    }
+}
@@ -0,0 +1,130 @@
+use sqlx::{MySqlConnection, MySqlPool};
+
+use crate::{
+    model::content_revision::{ContentRevisionModel, FieldDiffResponse},
+    schema::response::api_response_error::ApiResponseError,
+};
+
+// Run inside the transaction that changes the content, the revision is a snapshot of its
+// fields after the change. Locking the latest revision keeps the numbers sequential.
+pub async fn record_revision(
+    conn: &mut MySqlConnection,
+    content_id: &str,
+    user_id: &str,
+) -> Result<i32, sqlx::Error> {
+    let revision: i64 = sqlx::query_scalar(
+        "SELECT COALESCE(MAX(revision), 0) + 1 FROM content_revisions WHERE content_id = ? FOR UPDATE",
+    )
+    .bind(content_id)
+    .fetch_one(&mut *conn)
+    .await?;
+
+    sqlx::query(
+        "INSERT INTO content_revisions (content_id, revision, title, summary, details, user_id) SELECT id, ?, title, summary, details, ? FROM contents WHERE id = ?",
+    )
+    .bind(revision)
+    .bind(user_id)
+    .bind(content_id)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(revision as i32)
+}
+
+// Revisions of the content, latest first
+pub async fn content_revisions(
+    pool: &MySqlPool,
+    content_id: &str,
+) -> Result<Vec<ContentRevisionModel>, ApiResponseError> {
+    sqlx::query_as!(
+        ContentRevisionModel,
+        "SELECT * FROM content_revisions WHERE content_id = ? ORDER BY revision DESC",
+        content_id
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))
+}
+
+pub async fn find_revision(
+    pool: &MySqlPool,
+    content_id: &str,
+    revision: i32,
+) -> Result<ContentRevisionModel, ApiResponseError> {
+    sqlx::query_as!(
+        ContentRevisionModel,
+        "SELECT * FROM content_revisions WHERE content_id = ? AND revision = ?",
+        content_id,
+        revision
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?
+    .ok_or_else(|| ApiResponseError::new(404, format!("Revision {} not found", revision), None))
+}
+
+// Fields changed from one revision to the other, unchanged fields are left out
+pub fn diff_revisions(
+    from: &ContentRevisionModel,
+    to: &ContentRevisionModel,
+) -> Vec<FieldDiffResponse> {
+    let fields = [
+        ("title", Some(from.title.as_str()), Some(to.title.as_str())),
+        ("summary", from.summary.as_deref(), to.summary.as_deref()),
+        ("details", from.details.as_deref(), to.details.as_deref()),
+    ];
+
+    fields
+        .into_iter()
+        .filter(|(_, from, to)| from != to)
+        .map(|(field, from, to)| FieldDiffResponse {
+            field: field.to_string(),
+            from: from.map(str::to_string),
+            to: to.map(str::to_string),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn revision(revision: i32, title: &str, summary: Option<&str>) -> ContentRevisionModel {
+        ContentRevisionModel {
+            id: revision as i64,
+            content_id: "content".to_string(),
+            revision,
+            title: title.to_string(),
+            summary: summary.map(str::to_string),
+            details: Some("details".to_string()),
+            user_id: Some("user".to_string()),
+            created_at: None,
+        }
+    }
+
+    #[test]
+    fn test_diff_revisions() {
+        let first = revision(1, "Title", None);
+        let second = revision(2, "New title", Some("Summary"));
+
+        assert_eq!(
+            diff_revisions(&first, &second),
+            vec![
+                FieldDiffResponse {
+                    field: "title".to_string(),
+                    from: Some("Title".to_string()),
+                    to: Some("New title".to_string()),
+                },
+                FieldDiffResponse {
+                    field: "summary".to_string(),
+                    from: None,
+                    to: Some("Summary".to_string()),
+                },
+            ]
+        );
+
+        // The diff goes both ways, and is empty between equal revisions
+        assert_eq!(diff_revisions(&second, &first)[1].to, None);
+        assert!(diff_revisions(&first, &revision(3, "Title", None)).is_empty());
+    }
+}
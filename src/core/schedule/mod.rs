@@ -0,0 +1,208 @@
+use chrono::{DateTime, Utc};
+use log::{error, info};
+use sqlx::MySqlPool;
+
+use crate::{
+    config::CONFIG,
+    core::{
+        enums::ContentState,
+        permission::has_permission,
+        workflow::{record_transition, Transition},
+    },
+    schema::response::api_response_error::{ApiResponseError, ValidationErrorDetail},
+};
+
+// Scheduling skips the review, so it needs the permission of publishing
+pub const SCHEDULE_PERMISSION: &str = "contents.publish";
+
+// Contents moved on a single tick, the rest wait for the next one
+const BATCH_SIZE: u32 = 100;
+
+// A move made by the scheduler once the time in `column` is reached
+pub struct ScheduledMove {
+    pub column: &'static str,
+    pub from: &'static [ContentState],
+    pub to: ContentState,
+}
+
+pub const SCHEDULED_PUBLISH: ScheduledMove = ScheduledMove {
+    column: "publish_at",
+    from: &[ContentState::Draft, ContentState::InReview],
+    to: ContentState::Published,
+};
+
+pub const SCHEDULED_UNPUBLISH: ScheduledMove = ScheduledMove {
+    column: "unpublish_at",
+    from: &[ContentState::Published],
+    to: ContentState::Archived,
+};
+
+pub fn validate_schedule(
+    publish_at: Option<DateTime<Utc>>,
+    unpublish_at: Option<DateTime<Utc>>,
+) -> Result<(), ApiResponseError> {
+    match (publish_at, unpublish_at) {
+        (Some(publish_at), Some(unpublish_at)) if unpublish_at <= publish_at => {
+            Err(ApiResponseError::new(
+                400,
+                "Validation Error".to_string(),
+                Some(vec![ValidationErrorDetail {
+                    field: "unpublish_at".to_string(),
+                    error: "Unpublish time must be after the publish time".to_string(),
+                }]),
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+// Only publishers can set or cancel the publish and unpublish times
+pub async fn authorize_schedule(
+    pool: &MySqlPool,
+    user_id: &str,
+    changes_schedule: bool,
+) -> Result<(), ApiResponseError> {
+    if !changes_schedule {
+        return Ok(());
+    }
+
+    let allowed = has_permission(pool, user_id, SCHEDULE_PERMISSION)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+    if !allowed {
+        return Err(ApiResponseError::new(
+            403,
+            format!("Forbidden: missing permission {}", SCHEDULE_PERMISSION),
+            None,
+        ));
+    }
+    Ok(())
+}
+
+// Apply the due moves, returns how many contents were moved.
+// The state change, the cleared time and the transition are committed together, and the
+// update is conditional on both, so a restart or a second instance never applies a move twice
+// and a move interrupted before its commit is picked up again on the next tick.
+pub async fn apply_scheduled_moves(
+    pool: &MySqlPool,
+    scheduled: &ScheduledMove,
+    now: DateTime<Utc>,
+) -> Result<u64, sqlx::Error> {
+    let states = scheduled
+        .from
+        .iter()
+        .map(|state| state.to_i8().to_string())
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    let due_query = format!(
+        "SELECT id, record_state, COALESCE(scheduled_by, created_by) FROM contents WHERE {column} <= ? AND record_state IN ({states}) AND deleted_at IS NULL ORDER BY {column} LIMIT {limit}",
+        column = scheduled.column,
+        states = states,
+        limit = BATCH_SIZE
+    );
+    let due: Vec<(String, i8, String)> =
+        sqlx::query_as(&due_query).bind(now).fetch_all(pool).await?;
+
+    let update_query = format!(
+        "UPDATE contents SET record_state = ?, {column} = NULL, updated_at = CURRENT_TIMESTAMP WHERE id = ? AND record_state = ? AND {column} <= ? AND deleted_at IS NULL",
+        column = scheduled.column
+    );
+
+    let mut moved = 0;
+    for (content_id, record_state, user_id) in due {
+        let from = match ContentState::from_i8(record_state) {
+            Some(from) => from,
+            None => continue,
+        };
+
+        let mut tx = pool.begin().await?;
+        let update_result = sqlx::query(&update_query)
+            .bind(scheduled.to.to_i8())
+            .bind(&content_id)
+            .bind(record_state)
+            .bind(now)
+            .execute(&mut *tx)
+            .await?;
+
+        // Moved by another instance or by hand since it was selected
+        if update_result.rows_affected() == 0 {
+            continue;
+        }
+
+        let transition = Transition {
+            from,
+            to: scheduled.to,
+            permission: SCHEDULE_PERMISSION,
+            by_author: false,
+        };
+        record_transition(
+            &mut tx,
+            &content_id,
+            &transition,
+            &user_id,
+            Some("Scheduled"),
+        )
+        .await?;
+        tx.commit().await?;
+
+        info!(
+            "Scheduled move of content {} from {} to {}",
+            content_id,
+            from.to_str(),
+            scheduled.to.to_str()
+        );
+        moved += 1;
+    }
+
+    Ok(moved)
+}
+
+// Periodically publish and archive the contents that are due, safe to run on every instance
+pub async fn process_content_schedule(pool: MySqlPool) {
+    let interval_seconds = CONFIG.schedule.interval_seconds.max(1);
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs(interval_seconds));
+
+    loop {
+        // The first tick is immediate, catching up on what was due while the server was down
+        interval.tick().await;
+        for scheduled in [&SCHEDULED_PUBLISH, &SCHEDULED_UNPUBLISH] {
+            if let Err(e) = apply_scheduled_moves(&pool, scheduled, Utc::now()).await {
+                error!(
+                    "Failed to apply scheduled {} of contents: {:?}",
+                    scheduled.column, e
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_validate_schedule() {
+        let now = Utc::now();
+
+        assert!(validate_schedule(None, None).is_ok());
+        assert!(validate_schedule(Some(now), None).is_ok());
+        assert!(validate_schedule(None, Some(now)).is_ok());
+        assert!(validate_schedule(Some(now), Some(now + Duration::hours(1))).is_ok());
+
+        let err = validate_schedule(Some(now), Some(now)).unwrap_err();
+        assert_eq!(err.status, 400);
+        assert_eq!(err.validation_errors.unwrap()[0].field, "unpublish_at");
+    }
+
+    #[test]
+    fn test_scheduled_moves_follow_the_workflow_states() {
+        // Publishing skips the review, unpublishing archives
+        assert!(SCHEDULED_PUBLISH.from.contains(&ContentState::InReview));
+        assert!(!SCHEDULED_PUBLISH.from.contains(&ContentState::Archived));
+        assert_eq!(SCHEDULED_PUBLISH.to, ContentState::Published);
+        assert_eq!(SCHEDULED_UNPUBLISH.from, &[ContentState::Published]);
+        assert_eq!(SCHEDULED_UNPUBLISH.to, ContentState::Archived);
+    }
+}
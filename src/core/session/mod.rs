@@ -0,0 +1,328 @@
+use actix_web::{
+    cookie::{time, Cookie, SameSite},
+    http, HttpRequest,
+};
+use chrono::{Duration, Utc};
+use log::warn;
+use rand::{distributions::Alphanumeric, Rng};
+use sqlx::MySqlPool;
+
+use crate::{
+    config::CONFIG, core::utils::jwt::encode_jwt, model::session::SessionModel,
+    schema::response::api_response_error::ApiResponseError,
+};
+
+pub mod revocation;
+
+const REFRESH_TOKEN_LENGTH: usize = 64;
+pub const REFRESH_TOKEN_COOKIE: &str = "refresh_token";
+
+// Tokens handed to the client when a session is started or rotated
+pub struct IssuedTokens {
+    pub session_id: String,
+    pub access_token: String,
+    pub access_token_duration: Duration,
+    pub refresh_token: String,
+    pub refresh_token_duration: Duration,
+}
+
+// Client details stored alongside a session
+pub struct ClientInfo {
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+}
+
+impl ClientInfo {
+    pub fn from_request(req: &HttpRequest) -> Self {
+        Self {
+            user_agent: req
+                .headers()
+                .get(http::header::USER_AGENT)
+                .and_then(|h| h.to_str().ok())
+                .map(|ua| ua.chars().take(255).collect()),
+            ip_address: req
+                .connection_info()
+                .realip_remote_addr()
+                .map(|ip| ip.to_string()),
+        }
+    }
+}
+
+pub fn access_token_duration() -> Duration {
+    Duration::minutes(CONFIG.jwt.access_token_minutes)
+}
+
+pub fn refresh_token_duration() -> Duration {
+    Duration::days(CONFIG.jwt.refresh_token_days)
+}
+
+// Generate an opaque refresh token, only its hash is stored in the database
+pub fn generate_refresh_token() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(REFRESH_TOKEN_LENGTH)
+        .map(char::from)
+        .collect()
+}
+
+pub fn hash_refresh_token(token: &str) -> String {
+    sha256::digest(token)
+}
+
+// Start a new token family for the user (called after a successful login)
+pub async fn start_session(
+    pool: &MySqlPool,
+    user_id: &str,
+    client: &ClientInfo,
+) -> Result<IssuedTokens, ApiResponseError> {
+    let family_id = uuid::Uuid::new_v4().to_string();
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    let tokens = insert_session(&mut tx, user_id, &family_id, client).await?;
+
+    tx.commit()
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    Ok(tokens)
+}
+
+// Exchange a refresh token for a new token pair.
+// A refresh token can be used only once, presenting an already rotated token
+// revokes the whole family since it means the token has leaked.
+pub async fn rotate_session(
+    pool: &MySqlPool,
+    refresh_token: &str,
+    client: &ClientInfo,
+) -> Result<(SessionModel, IssuedTokens), ApiResponseError> {
+    let token_hash = hash_refresh_token(refresh_token);
+
+    let session = sqlx::query_as!(
+        SessionModel,
+        "SELECT * FROM sessions WHERE refresh_token_hash = ?",
+        token_hash
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?
+    .ok_or_else(|| ApiResponseError::new(401, "Invalid refresh token".to_string(), None))?;
+
+    if session.revoked_at.is_some() {
+        if session.replaced_by.is_some() {
+            warn!(
+                "Refresh token reuse detected for user {}, revoking session family {}",
+                session.user_id, session.family_id
+            );
+            revoke_family(pool, &session.family_id).await?;
+            return Err(ApiResponseError::new(
+                401,
+                "Refresh token reuse detected, please login again".to_string(),
+                None,
+            ));
+        }
+        return Err(ApiResponseError::new(
+            401,
+            "Session has been revoked".to_string(),
+            None,
+        ));
+    }
+
+    if session.expires_at < Utc::now() {
+        return Err(ApiResponseError::new(
+            401,
+            "Refresh token has expired".to_string(),
+            None,
+        ));
+    }
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    let tokens = insert_session(&mut tx, &session.user_id, &session.family_id, client).await?;
+
+    // Only one request may rotate a given token, a concurrent rotation counts as reuse
+    let update_result = sqlx::query(
+        "UPDATE sessions SET revoked_at = CURRENT_TIMESTAMP, replaced_by = ? WHERE id = ? AND revoked_at IS NULL",
+    )
+    .bind(&tokens.session_id)
+    .bind(&session.id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    if update_result.rows_affected() == 0 {
+        tx.rollback().await.ok();
+        revoke_family(pool, &session.family_id).await?;
+        return Err(ApiResponseError::new(
+            401,
+            "Refresh token reuse detected, please login again".to_string(),
+            None,
+        ));
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    Ok((session, tokens))
+}
+
+// Revoke every active session sharing the same token family
+pub async fn revoke_family(pool: &MySqlPool, family_id: &str) -> Result<u64, ApiResponseError> {
+    sqlx::query(
+        "UPDATE sessions SET revoked_at = CURRENT_TIMESTAMP WHERE family_id = ? AND revoked_at IS NULL",
+    )
+    .bind(family_id)
+    .execute(pool)
+    .await
+    .map(|result| result.rows_affected())
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))
+}
+
+// Revoke the token family of a single session (used on logout)
+pub async fn end_session(pool: &MySqlPool, session_id: &str) -> Result<u64, ApiResponseError> {
+    let family_id: Option<String> =
+        sqlx::query_scalar("SELECT family_id FROM sessions WHERE id = ?")
+            .bind(session_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| {
+                ApiResponseError::new(500, format!("Internal Server Error: {}", e), None)
+            })?;
+
+    match family_id {
+        Some(family_id) => revoke_family(pool, &family_id).await,
+        None => Ok(0),
+    }
+}
+
+// Revoke every active session of the user, signing them out on all devices
+pub async fn revoke_user_sessions(
+    pool: &MySqlPool,
+    user_id: &str,
+) -> Result<u64, ApiResponseError> {
+    sqlx::query(
+        "UPDATE sessions SET revoked_at = CURRENT_TIMESTAMP WHERE user_id = ? AND revoked_at IS NULL",
+    )
+    .bind(user_id)
+    .execute(pool)
+    .await
+    .map(|result| result.rows_affected())
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))
+}
+
+// Cookies carrying the access token and the refresh token
+pub fn build_auth_cookies(tokens: &IssuedTokens) -> (Cookie<'static>, Cookie<'static>) {
+    let access_cookie = Cookie::build("auth_token", tokens.access_token.clone())
+        .path("/")
+        .secure(false)
+        .http_only(true)
+        .same_site(SameSite::Strict)
+        .max_age(time::Duration::seconds(
+            tokens.access_token_duration.num_seconds(),
+        ))
+        .finish();
+
+    let refresh_cookie = Cookie::build(REFRESH_TOKEN_COOKIE, tokens.refresh_token.clone())
+        .path("/api/auth")
+        .secure(false)
+        .http_only(true)
+        .same_site(SameSite::Strict)
+        .max_age(time::Duration::seconds(
+            tokens.refresh_token_duration.num_seconds(),
+        ))
+        .finish();
+
+    (access_cookie, refresh_cookie)
+}
+
+// Expired cookies removing the access token and the refresh token
+pub fn build_logout_cookies() -> (Cookie<'static>, Cookie<'static>) {
+    let access_cookie = Cookie::build("auth_token", "")
+        .path("/")
+        .secure(false)
+        .http_only(true)
+        .max_age(time::Duration::seconds_f32(0.5))
+        .finish();
+
+    let refresh_cookie = Cookie::build(REFRESH_TOKEN_COOKIE, "")
+        .path("/api/auth")
+        .secure(false)
+        .http_only(true)
+        .max_age(time::Duration::seconds_f32(0.5))
+        .finish();
+
+    (access_cookie, refresh_cookie)
+}
+
+async fn insert_session(
+    tx: &mut sqlx::Transaction<'_, sqlx::MySql>,
+    user_id: &str,
+    family_id: &str,
+    client: &ClientInfo,
+) -> Result<IssuedTokens, ApiResponseError> {
+    let session_id = uuid::Uuid::new_v4().to_string();
+    let refresh_token = generate_refresh_token();
+    let refresh_token_duration = refresh_token_duration();
+    let expires_at = Utc::now() + refresh_token_duration;
+
+    sqlx::query(
+        "INSERT INTO sessions (id, user_id, family_id, refresh_token_hash, user_agent, ip_address, expires_at) VALUES (?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(&session_id)
+    .bind(user_id)
+    .bind(family_id)
+    .bind(hash_refresh_token(&refresh_token))
+    .bind(client.user_agent.as_deref())
+    .bind(client.ip_address.as_deref())
+    .bind(expires_at)
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Failed to create session: {}", e), None))?;
+
+    let access_token_duration = access_token_duration();
+    let access_token = encode_jwt(
+        user_id.to_string(),
+        Some(session_id.clone()),
+        access_token_duration,
+    )
+    .map_err(|e| ApiResponseError::new(500, format!("Failed to generate JWT: {:?}", e), None))?;
+
+    Ok(IssuedTokens {
+        session_id,
+        access_token,
+        access_token_duration,
+        refresh_token,
+        refresh_token_duration,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_refresh_token() {
+        let token = generate_refresh_token();
+
+        assert_eq!(token.len(), REFRESH_TOKEN_LENGTH);
+        assert!(token.chars().all(|c| c.is_ascii_alphanumeric()));
+        assert_ne!(token, generate_refresh_token());
+    }
+
+    #[test]
+    fn test_hash_refresh_token() {
+        let token = generate_refresh_token();
+        let hash = hash_refresh_token(&token);
+
+        // Hash is deterministic and never equals the raw token
+        assert_eq!(hash, hash_refresh_token(&token));
+        assert_eq!(hash.len(), 64);
+        assert_ne!(hash, token);
+    }
+}
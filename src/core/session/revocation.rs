@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use sqlx::MySqlPool;
+
+use crate::{core::utils::jwt::Claims, schema::response::api_response_error::ApiResponseError};
+
+// Revoke a single access token, it stays in the store until it would have expired
+pub async fn revoke_token(pool: &MySqlPool, claims: &Claims) -> Result<(), ApiResponseError> {
+    let expires_at = DateTime::<Utc>::from_timestamp(claims.exp as i64, 0).unwrap_or_else(Utc::now);
+
+    sqlx::query("INSERT IGNORE INTO revoked_tokens (jti, user_id, expires_at) VALUES (?, ?, ?)")
+        .bind(&claims.jti)
+        .bind(&claims.id)
+        .bind(expires_at)
+        .execute(pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Failed to revoke token: {}", e), None))?;
+
+    // Expired tokens are rejected by the JWT validation anyway, no need to keep them
+    purge_expired_tokens(pool).await?;
+
+    Ok(())
+}
+
+// A token is revoked when its jti is in the store, or when the session it was
+// issued for no longer has an active refresh token (logout, logout everywhere,
+// refresh token reuse or admin revocation)
+pub async fn is_token_revoked(pool: &MySqlPool, claims: &Claims) -> Result<bool, sqlx::Error> {
+    let revoked: i64 =
+        sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM revoked_tokens WHERE jti = ?)")
+            .bind(&claims.jti)
+            .fetch_one(pool)
+            .await?;
+
+    if revoked != 0 {
+        return Ok(true);
+    }
+
+    let Some(session_id) = &claims.sid else {
+        return Ok(false);
+    };
+
+    let active_sessions: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM sessions WHERE revoked_at IS NULL AND family_id = (SELECT family_id FROM sessions WHERE id = ?)",
+    )
+    .bind(session_id)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(active_sessions == 0)
+}
+
+pub async fn purge_expired_tokens(pool: &MySqlPool) -> Result<u64, ApiResponseError> {
+    sqlx::query("DELETE FROM revoked_tokens WHERE expires_at < CURRENT_TIMESTAMP")
+        .execute(pool)
+        .await
+        .map(|result| result.rows_affected())
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))
+}
@@ -0,0 +1,162 @@
+use chrono::{DateTime, Duration, Utc};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+use sqlx::MySqlPool;
+
+use crate::schema::response::api_response_error::ApiResponseError;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const MAX_NONCE_LENGTH: usize = 128;
+
+// The signed string, one field per line:
+// METHOD, path with query string, unix timestamp, nonce and the hex SHA-256 of the body
+pub fn canonical_request(
+    method: &str,
+    path_and_query: &str,
+    timestamp: i64,
+    nonce: &str,
+    body: &[u8],
+) -> String {
+    format!(
+        "{}\n{}\n{}\n{}\n{}",
+        method.to_ascii_uppercase(),
+        path_and_query,
+        timestamp,
+        nonce,
+        hex::encode(Sha256::digest(body))
+    )
+}
+
+fn signature_mac(secret: &str, canonical_request: &str) -> HmacSha256 {
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC can take a key of any size");
+    mac.update(canonical_request.as_bytes());
+    mac
+}
+
+// Hex HMAC-SHA256 of the canonical request, as computed by the client
+pub fn sign_request(secret: &str, canonical_request: &str) -> String {
+    hex::encode(
+        signature_mac(secret, canonical_request)
+            .finalize()
+            .into_bytes(),
+    )
+}
+
+// Constant-time check of the signature against every secret currently accepted
+pub fn verify_signature(secrets: &[&str], canonical_request: &str, signature: &str) -> bool {
+    let Ok(signature) = hex::decode(signature.trim()) else {
+        return false;
+    };
+    secrets.iter().any(|secret| {
+        signature_mac(secret, canonical_request)
+            .verify_slice(&signature)
+            .is_ok()
+    })
+}
+
+// Reject timestamps too far in the past or the future
+pub fn check_timestamp(
+    timestamp: i64,
+    now: DateTime<Utc>,
+    skew_seconds: i64,
+) -> Result<(), ApiResponseError> {
+    if (now.timestamp() - timestamp).abs() > skew_seconds {
+        return Err(ApiResponseError::new(
+            401,
+            "Request timestamp is outside the allowed window".to_string(),
+            None,
+        ));
+    }
+    Ok(())
+}
+
+pub fn check_nonce(nonce: &str) -> Result<(), ApiResponseError> {
+    if nonce.is_empty() || nonce.len() > MAX_NONCE_LENGTH {
+        return Err(ApiResponseError::new(
+            401,
+            format!(
+                "Nonce must be between 1 and {} characters",
+                MAX_NONCE_LENGTH
+            ),
+            None,
+        ));
+    }
+    Ok(())
+}
+
+// Remember a nonce until its timestamp leaves the skew window.
+// Returns false when the nonce has already been used by the application.
+pub async fn store_nonce(
+    pool: &MySqlPool,
+    application_id: &str,
+    nonce: &str,
+    timestamp: i64,
+    skew_seconds: i64,
+) -> Result<bool, sqlx::Error> {
+    let now = Utc::now();
+    let expires_at =
+        DateTime::from_timestamp(timestamp, 0).unwrap_or(now) + Duration::seconds(skew_seconds);
+
+    sqlx::query("DELETE FROM request_nonces WHERE expires_at < ?")
+        .bind(now)
+        .execute(pool)
+        .await?;
+
+    let result = sqlx::query(
+        "INSERT IGNORE INTO request_nonces (application_id, nonce, expires_at) VALUES (?, ?, ?)",
+    )
+    .bind(application_id)
+    .bind(nonce)
+    .bind(expires_at)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_request() {
+        let canonical = canonical_request("post", "/api/check/signed?a=1", 1720000000, "n1", b"");
+
+        assert_eq!(
+            canonical,
+            "POST\n/api/check/signed?a=1\n1720000000\nn1\ne3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        let canonical = canonical_request("GET", "/api/check/signed", 1720000000, "n1", b"{}");
+        let signature = sign_request("current", &canonical);
+
+        assert!(verify_signature(&["current"], &canonical, &signature));
+        // The previous secret still in its grace period is accepted too
+        assert!(verify_signature(
+            &["new", "current"],
+            &canonical,
+            &signature
+        ));
+        assert!(!verify_signature(&["other"], &canonical, &signature));
+        assert!(!verify_signature(&["current"], &canonical, "not-hex"));
+
+        // Any change to the request breaks the signature
+        let tampered = canonical_request("GET", "/api/check/signed", 1720000000, "n1", b"{ }");
+        assert!(!verify_signature(&["current"], &tampered, &signature));
+    }
+
+    #[test]
+    fn test_check_timestamp() {
+        let now = Utc::now();
+
+        assert!(check_timestamp(now.timestamp(), now, 300).is_ok());
+        assert!(check_timestamp(now.timestamp() - 300, now, 300).is_ok());
+        assert!(check_timestamp(now.timestamp() - 301, now, 300).is_err());
+        assert!(check_timestamp(now.timestamp() + 301, now, 300).is_err());
+    }
+}
@@ -0,0 +1,194 @@
+use sqlx::{MySqlConnection, MySqlPool};
+
+use crate::schema::response::api_response_error::{ApiResponseError, ValidationErrorDetail};
+
+// Leaves room in the column for the suffix of a collision
+const MAX_SLUG_LENGTH: usize = 200;
+
+// Latin spelling of the Arabic letters, following the common simplified romanization
+fn transliterate_arabic(c: char) -> Option<&'static str> {
+    let latin = match c {
+        'ا' | 'أ' | 'آ' | 'ى' => "a",
+        'إ' => "i",
+        'ب' => "b",
+        'ت' => "t",
+        'ث' => "th",
+        'ج' => "j",
+        'ح' | 'ه' | 'ة' => "h",
+        'خ' => "kh",
+        'د' | 'ض' => "d",
+        'ذ' => "dh",
+        'ر' => "r",
+        'ز' | 'ظ' => "z",
+        'س' | 'ص' => "s",
+        'ش' => "sh",
+        'ط' => "t",
+        'ع' => "a",
+        'غ' => "gh",
+        'ف' => "f",
+        'ق' => "q",
+        'ك' => "k",
+        'ل' => "l",
+        'م' => "m",
+        'ن' => "n",
+        'و' | 'ؤ' => "w",
+        'ي' | 'ئ' => "y",
+        '٠' => "0",
+        '١' => "1",
+        '٢' => "2",
+        '٣' => "3",
+        '٤' => "4",
+        '٥' => "5",
+        '٦' => "6",
+        '٧' => "7",
+        '٨' => "8",
+        '٩' => "9",
+        // Hamza, tatweel and the diacritics are not written
+        'ء' | 'ـ' | '\u{064B}'..='\u{0652}' => "",
+        _ => return None,
+    };
+    Some(latin)
+}
+
+// Lowercase ASCII letters and digits, Arabic is transliterated and any other run of
+// characters becomes a single dash
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if let Some(latin) = transliterate_arabic(c) {
+            slug.push_str(latin);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+        if slug.len() >= MAX_SLUG_LENGTH {
+            break;
+        }
+    }
+    slug.truncate(MAX_SLUG_LENGTH);
+    slug.trim_end_matches('-').to_string()
+}
+
+// Slug of a new content, titles without any letter or digit fall back to the id
+pub fn content_slug(title: &str, content_id: &str) -> String {
+    let slug = slugify(title);
+    if slug.is_empty() {
+        content_id.to_string()
+    } else {
+        slug
+    }
+}
+
+// Slugs sent by the user are normalized the same way as the generated ones
+pub fn validate_slug(slug: &str) -> Result<String, ApiResponseError> {
+    let slug = slugify(slug);
+    if slug.is_empty() {
+        return Err(ApiResponseError::new(
+            400,
+            "Validation Error".to_string(),
+            Some(vec![ValidationErrorDetail {
+                field: "slug".to_string(),
+                error: "Slug must contain letters or digits".to_string(),
+            }]),
+        ));
+    }
+    Ok(slug)
+}
+
+// A slug is taken by the current or a previous slug of another content
+pub async fn slug_taken(
+    pool: &MySqlPool,
+    slug: &str,
+    content_id: &str,
+) -> Result<bool, sqlx::Error> {
+    let taken: i64 = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM contents WHERE slug = ? AND id <> ?) OR EXISTS(SELECT 1 FROM content_slug_redirects WHERE old_slug = ? AND content_id <> ?)",
+    )
+    .bind(slug)
+    .bind(content_id)
+    .bind(slug)
+    .bind(content_id)
+    .fetch_one(pool)
+    .await?;
+    Ok(taken != 0)
+}
+
+// The first free slug among `slug`, `slug-2`, `slug-3`...
+pub async fn unique_slug(
+    pool: &MySqlPool,
+    slug: &str,
+    content_id: &str,
+) -> Result<String, sqlx::Error> {
+    let mut candidate = slug.to_string();
+    let mut suffix = 1;
+    while slug_taken(pool, &candidate, content_id).await? {
+        suffix += 1;
+        candidate = format!("{}-{}", slug, suffix);
+    }
+    Ok(candidate)
+}
+
+pub fn duplicate_slug_error() -> ApiResponseError {
+    ApiResponseError::new(409, "Slug already exists".to_string(), None)
+}
+
+// Run inside the transaction that changes the slug, the old slug keeps pointing to the content
+pub async fn record_slug_redirect(
+    conn: &mut MySqlConnection,
+    content_id: &str,
+    old_slug: &str,
+    new_slug: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO content_slug_redirects (old_slug, content_id) VALUES (?, ?) ON DUPLICATE KEY UPDATE content_id = VALUES(content_id)",
+    )
+    .bind(old_slug)
+    .bind(content_id)
+    .execute(&mut *conn)
+    .await?;
+
+    // A content taking back one of its previous slugs no longer redirects it
+    sqlx::query("DELETE FROM content_slug_redirects WHERE old_slug = ? AND content_id = ?")
+        .bind(new_slug)
+        .bind(content_id)
+        .execute(&mut *conn)
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Hello World"), "hello-world");
+        assert_eq!(slugify("  Rust & Actix: 2024!  "), "rust-actix-2024");
+        assert_eq!(slugify("content-1"), "content-1");
+        assert_eq!(slugify("---"), "");
+        assert_eq!(slugify(&"a".repeat(300)).len(), MAX_SLUG_LENGTH);
+    }
+
+    #[test]
+    fn test_slugify_transliterates_arabic() {
+        assert_eq!(slugify("من نحن"), "mn-nhn");
+        assert_eq!(slugify("شروط الخدمة"), "shrwt-alkhdmh");
+        assert_eq!(slugify("عروض ٢٠٢٤"), "arwd-2024");
+        // Diacritics do not split the words
+        assert_eq!(slugify("مَرْحَبًا"), "mrhba");
+        assert_eq!(slugify("About الشركة"), "about-alshrkh");
+    }
+
+    #[test]
+    fn test_content_slug() {
+        assert_eq!(content_slug("About us", "id"), "about-us");
+        assert_eq!(content_slug("🙂", "id"), "id");
+    }
+
+    #[test]
+    fn test_validate_slug() {
+        assert_eq!(validate_slug(" My Page ").unwrap(), "my-page");
+        assert_eq!(validate_slug("!!!").unwrap_err().status, 400);
+    }
+}
@@ -0,0 +1,39 @@
+// Soft deleted rows have `deleted_at` set, they stay in the table until purged
+
+// The `deleted_at` condition for the list endpoints.
+// Trashed rows are hidden by default, `only_trashed` wins over `with_trashed`.
+pub fn trashed_condition(
+    with_trashed: Option<bool>,
+    only_trashed: Option<bool>,
+) -> Option<&'static str> {
+    if only_trashed.unwrap_or(false) {
+        Some("deleted_at IS NOT NULL")
+    } else if with_trashed.unwrap_or(false) {
+        None
+    } else {
+        Some("deleted_at IS NULL")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trashed_condition() {
+        assert_eq!(trashed_condition(None, None), Some("deleted_at IS NULL"));
+        assert_eq!(
+            trashed_condition(Some(false), Some(false)),
+            Some("deleted_at IS NULL")
+        );
+        assert_eq!(trashed_condition(Some(true), None), None);
+        assert_eq!(
+            trashed_condition(None, Some(true)),
+            Some("deleted_at IS NOT NULL")
+        );
+        assert_eq!(
+            trashed_condition(Some(true), Some(true)),
+            Some("deleted_at IS NOT NULL")
+        );
+    }
+}
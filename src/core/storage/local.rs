@@ -0,0 +1,85 @@
+use std::{io::ErrorKind, path::PathBuf};
+
+use async_trait::async_trait;
+
+use super::{validate_key, Storage};
+
+// Files on the disk of the server, under `storage.local_root`
+pub struct LocalStorage {
+    root: PathBuf,
+}
+
+impl LocalStorage {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn path(&self, key: &str) -> Result<PathBuf, String> {
+        validate_key(key)?;
+        Ok(self.root.join(key))
+    }
+}
+
+#[async_trait]
+impl Storage for LocalStorage {
+    async fn put(&self, key: &str, bytes: Vec<u8>, _content_type: &str) -> Result<(), String> {
+        let path = self.path(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        tokio::fs::write(&path, bytes)
+            .await
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, String> {
+        let path = self.path(key)?;
+        tokio::fs::read(&path)
+            .await
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), String> {
+        let path = self.path(key)?;
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(format!("Failed to delete {}: {}", path.display(), e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_local_storage_put_and_delete() {
+        let root = std::env::temp_dir().join(format!("storage-{}", uuid::Uuid::new_v4()));
+        let storage = LocalStorage::new(&root);
+
+        storage
+            .put("contents/1/image.png", b"image".to_vec(), "image/png")
+            .await
+            .expect("Failed to store the file");
+        assert_eq!(
+            std::fs::read(root.join("contents/1/image.png")).unwrap(),
+            b"image"
+        );
+        assert_eq!(storage.get("contents/1/image.png").await.unwrap(), b"image");
+
+        storage.delete("contents/1/image.png").await.unwrap();
+        assert!(!root.join("contents/1/image.png").exists());
+        // Already deleted
+        assert!(storage.delete("contents/1/image.png").await.is_ok());
+
+        assert!(storage
+            .put("../outside.png", b"image".to_vec(), "image/png")
+            .await
+            .is_err());
+
+        std::fs::remove_dir_all(root).ok();
+    }
+}
@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::config::CONFIG;
+
+pub mod local;
+pub mod s3;
+
+use local::LocalStorage;
+use s3::S3Storage;
+
+// Where uploaded files are kept, files are addressed by a relative key such as
+// `contents/{id}/{name}.png`
+#[async_trait]
+pub trait Storage: Send + Sync {
+    async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<(), String>;
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, String>;
+
+    // Deleting a missing file is not an error
+    async fn delete(&self, key: &str) -> Result<(), String>;
+}
+
+// The backend selected by `storage.driver`
+pub fn storage_from_config() -> Arc<dyn Storage> {
+    match CONFIG.storage.driver.as_str() {
+        "s3" => Arc::new(S3Storage::new(&CONFIG.storage.s3)),
+        _ => Arc::new(LocalStorage::new(&CONFIG.storage.local_root)),
+    }
+}
+
+// Public URL of a stored file, values that are already URLs or paths are kept as they are
+pub fn file_url(key: &str) -> String {
+    if key.starts_with("http://") || key.starts_with("https://") || key.starts_with('/') {
+        return key.to_string();
+    }
+    format!(
+        "{}/{}",
+        CONFIG.storage.public_url.trim_end_matches('/'),
+        key
+    )
+}
+
+// Keys are generated by the server, anything climbing out of the storage root is refused
+pub fn validate_key(key: &str) -> Result<(), String> {
+    if key.is_empty()
+        || key.starts_with('/')
+        || key.contains('\\')
+        || key
+            .split('/')
+            .any(|part| part.is_empty() || part == "." || part == "..")
+    {
+        return Err(format!("Invalid storage key {}", key));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_url() {
+        assert_eq!(
+            file_url("contents/1/image.png"),
+            format!(
+                "{}/contents/1/image.png",
+                CONFIG.storage.public_url.trim_end_matches('/')
+            )
+        );
+        // Images set before the uploads keep their value
+        assert_eq!(file_url("/img/logo.png"), "/img/logo.png");
+        assert_eq!(
+            file_url("https://cdn.test/image.png"),
+            "https://cdn.test/image.png"
+        );
+    }
+
+    #[test]
+    fn test_validate_key() {
+        assert!(validate_key("contents/1/image.png").is_ok());
+        assert!(validate_key("").is_err());
+        assert!(validate_key("/etc/passwd").is_err());
+        assert!(validate_key("contents/../../etc/passwd").is_err());
+        assert!(validate_key("contents//image.png").is_err());
+        assert!(validate_key("contents\\image.png").is_err());
+    }
+}
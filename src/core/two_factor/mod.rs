@@ -0,0 +1,297 @@
+use base32::Alphabet;
+use rand::{distributions::Alphanumeric, Rng, RngCore};
+use sqlx::MySqlPool;
+use totp_rs::{Algorithm, TOTP};
+
+use crate::{
+    config::CONFIG, model::two_factor::TwoFactorModel,
+    schema::response::api_response_error::ApiResponseError,
+};
+
+const SECRET_BYTES: usize = 20; // 160 bits, the size recommended by RFC 4226
+const TOTP_DIGITS: usize = 6;
+const TOTP_STEP: u64 = 30;
+const TOTP_SKEW_STEPS: u64 = 1; // Accept one step before and after to allow clock drift
+const RECOVERY_CODE_LENGTH: usize = 10;
+
+const SECRET_ALPHABET: Alphabet = Alphabet::Rfc4648 { padding: false };
+
+// Data shown to the user while enrolling an authenticator app
+pub struct TwoFactorEnrollment {
+    pub secret: String,
+    pub provisioning_uri: String,
+    pub qr_code: String, // Base64 encoded PNG of the provisioning URI
+}
+
+// Generate a new base32 encoded TOTP secret
+pub fn generate_secret() -> String {
+    let mut secret = [0u8; SECRET_BYTES];
+    rand::thread_rng().fill_bytes(&mut secret);
+    base32::encode(SECRET_ALPHABET, &secret)
+}
+
+fn build_totp(secret: &str, account_name: &str) -> Result<TOTP, ApiResponseError> {
+    let secret = base32::decode(SECRET_ALPHABET, secret)
+        .ok_or_else(|| ApiResponseError::new(500, "Invalid two-factor secret".to_string(), None))?;
+
+    // Skew is handled by `verify_totp` so the matched step is known
+    TOTP::new(
+        Algorithm::SHA1,
+        TOTP_DIGITS,
+        0,
+        TOTP_STEP,
+        secret,
+        Some(CONFIG.two_factor.issuer.clone()),
+        account_name.to_string(),
+    )
+    .map_err(|e| ApiResponseError::new(500, format!("Failed to build TOTP: {}", e), None))
+}
+
+pub fn enrollment(
+    secret: &str,
+    account_name: &str,
+) -> Result<TwoFactorEnrollment, ApiResponseError> {
+    let totp = build_totp(secret, account_name)?;
+    let qr_code = totp.get_qr_base64().map_err(|e| {
+        ApiResponseError::new(500, format!("Failed to generate QR code: {}", e), None)
+    })?;
+
+    Ok(TwoFactorEnrollment {
+        secret: secret.to_string(),
+        provisioning_uri: totp.get_url(),
+        qr_code,
+    })
+}
+
+// Returns the time step matched by the code, if any
+pub fn verify_totp(
+    secret: &str,
+    account_name: &str,
+    code: &str,
+    now: u64,
+) -> Result<Option<u64>, ApiResponseError> {
+    let totp = build_totp(secret, account_name)?;
+    let current_step = now / TOTP_STEP;
+
+    let first_step = current_step.saturating_sub(TOTP_SKEW_STEPS);
+    for step in first_step..=current_step + TOTP_SKEW_STEPS {
+        if totp.check(code.trim(), step * TOTP_STEP) {
+            return Ok(Some(step));
+        }
+    }
+    Ok(None)
+}
+
+// Recovery codes are formatted as XXXXX-XXXXX to be easier to copy
+pub fn generate_recovery_codes(count: usize) -> Vec<String> {
+    (0..count)
+        .map(|_| {
+            let code: String = rand::thread_rng()
+                .sample_iter(&Alphanumeric)
+                .take(RECOVERY_CODE_LENGTH)
+                .map(|c| char::from(c).to_ascii_uppercase())
+                .collect();
+            format!(
+                "{}-{}",
+                &code[..RECOVERY_CODE_LENGTH / 2],
+                &code[RECOVERY_CODE_LENGTH / 2..]
+            )
+        })
+        .collect()
+}
+
+pub fn hash_recovery_code(code: &str) -> String {
+    let normalized: String = code
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    sha256::digest(normalized)
+}
+
+pub async fn find_two_factor(
+    pool: &MySqlPool,
+    user_id: &str,
+) -> Result<Option<TwoFactorModel>, ApiResponseError> {
+    sqlx::query_as!(
+        TwoFactorModel,
+        "SELECT * FROM two_factor_auth WHERE user_id = ?",
+        user_id
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))
+}
+
+pub async fn is_two_factor_enabled(pool: &MySqlPool, user_id: &str) -> Result<bool, sqlx::Error> {
+    let enabled = sqlx::query_scalar!(
+        "SELECT EXISTS(SELECT 1 FROM two_factor_auth WHERE user_id = ? AND confirmed_at IS NOT NULL) AS enabled",
+        user_id
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(enabled != 0)
+}
+
+// Replace the recovery codes of the user, only their hashes are stored
+pub async fn store_recovery_codes(
+    tx: &mut sqlx::Transaction<'_, sqlx::MySql>,
+    user_id: &str,
+    codes: &[String],
+) -> Result<(), ApiResponseError> {
+    sqlx::query("DELETE FROM two_factor_recovery_codes WHERE user_id = ?")
+        .bind(user_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    for code in codes {
+        sqlx::query(
+            "INSERT INTO two_factor_recovery_codes (id, user_id, code_hash) VALUES (?, ?, ?)",
+        )
+        .bind(uuid::Uuid::new_v4().to_string())
+        .bind(user_id)
+        .bind(hash_recovery_code(code))
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| {
+            ApiResponseError::new(500, format!("Failed to store recovery code: {}", e), None)
+        })?;
+    }
+
+    Ok(())
+}
+
+// Check the second factor of a user with confirmed 2FA.
+// A TOTP code can be used once, a recovery code is consumed when used.
+pub async fn verify_second_factor(
+    pool: &MySqlPool,
+    two_factor: &TwoFactorModel,
+    account_name: &str,
+    code: Option<&str>,
+    recovery_code: Option<&str>,
+) -> Result<(), ApiResponseError> {
+    if let Some(code) = code.filter(|c| !c.is_empty()) {
+        let now = chrono::Utc::now().timestamp() as u64;
+        let step = verify_totp(&two_factor.secret, account_name, code, now)?.ok_or_else(|| {
+            ApiResponseError::new(401, "Invalid two-factor code".to_string(), None)
+        })?;
+
+        let update_result = sqlx::query(
+            "UPDATE two_factor_auth SET last_used_step = ? WHERE user_id = ? AND (last_used_step IS NULL OR last_used_step < ?)",
+        )
+        .bind(step as i64)
+        .bind(&two_factor.user_id)
+        .bind(step as i64)
+        .execute(pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+        if update_result.rows_affected() == 0 {
+            return Err(ApiResponseError::new(
+                401,
+                "Two-factor code has already been used".to_string(),
+                None,
+            ));
+        }
+        return Ok(());
+    }
+
+    if let Some(recovery_code) = recovery_code.filter(|c| !c.is_empty()) {
+        let update_result = sqlx::query(
+            "UPDATE two_factor_recovery_codes SET used_at = CURRENT_TIMESTAMP WHERE user_id = ? AND code_hash = ? AND used_at IS NULL LIMIT 1",
+        )
+        .bind(&two_factor.user_id)
+        .bind(hash_recovery_code(recovery_code))
+        .execute(pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+        if update_result.rows_affected() == 0 {
+            return Err(ApiResponseError::new(
+                401,
+                "Invalid recovery code".to_string(),
+                None,
+            ));
+        }
+        return Ok(());
+    }
+
+    Err(ApiResponseError::new(
+        401,
+        "Two-factor authentication code required".to_string(),
+        None,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ACCOUNT_NAME: &str = "+201018898522";
+
+    #[test]
+    fn test_generate_secret() {
+        let secret = generate_secret();
+        let decoded = base32::decode(SECRET_ALPHABET, &secret).unwrap();
+
+        assert_eq!(decoded.len(), SECRET_BYTES);
+        assert_ne!(secret, generate_secret());
+    }
+
+    #[test]
+    fn test_enrollment_provisioning_uri() {
+        let secret = generate_secret();
+        let enrollment = enrollment(&secret, ACCOUNT_NAME).unwrap();
+
+        assert!(enrollment.provisioning_uri.starts_with("otpauth://totp/"));
+        assert!(enrollment
+            .provisioning_uri
+            .contains(&format!("secret={}", secret)));
+        assert!(!enrollment.qr_code.is_empty());
+    }
+
+    #[test]
+    fn test_verify_totp_allows_one_step_of_drift() {
+        let secret = generate_secret();
+        let totp = build_totp(&secret, ACCOUNT_NAME).unwrap();
+        let now = 1_720_000_000;
+        let step = now / TOTP_STEP;
+
+        let code = totp.generate(now);
+        assert_eq!(
+            verify_totp(&secret, ACCOUNT_NAME, &code, now).unwrap(),
+            Some(step)
+        );
+
+        let previous_code = totp.generate(now - TOTP_STEP);
+        assert_eq!(
+            verify_totp(&secret, ACCOUNT_NAME, &previous_code, now).unwrap(),
+            Some(step - 1)
+        );
+
+        let old_code = totp.generate(now - 3 * TOTP_STEP);
+        if old_code != code && old_code != previous_code {
+            assert_eq!(
+                verify_totp(&secret, ACCOUNT_NAME, &old_code, now).unwrap(),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn test_recovery_codes() {
+        let codes = generate_recovery_codes(10);
+
+        assert_eq!(codes.len(), 10);
+        assert!(codes.iter().all(|c| c.len() == RECOVERY_CODE_LENGTH + 1));
+
+        // Hash ignores case and separators
+        let code = &codes[0];
+        assert_eq!(
+            hash_recovery_code(code),
+            hash_recovery_code(&code.replace('-', "").to_lowercase())
+        );
+    }
+}
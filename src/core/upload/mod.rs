@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+
+use actix_multipart::{Field, Multipart, MultipartError};
+use futures_util::TryStreamExt;
+
+use crate::schema::response::api_response_error::{ApiResponseError, ValidationErrorDetail};
+
+// Longest name kept from the uploaded file, the key also holds a UUID
+const MAX_NAME_LENGTH: usize = 64;
+
+// Text fields sent with an image are short, e.g. its alt text
+const MAX_TEXT_FIELD_BYTES: usize = 4096;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageType {
+    Jpeg,
+    Png,
+    Gif,
+    Webp,
+}
+
+impl ImageType {
+    pub fn mime(&self) -> &'static str {
+        match self {
+            ImageType::Jpeg => "image/jpeg",
+            ImageType::Png => "image/png",
+            ImageType::Gif => "image/gif",
+            ImageType::Webp => "image/webp",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageType::Jpeg => "jpg",
+            ImageType::Png => "png",
+            ImageType::Gif => "gif",
+            ImageType::Webp => "webp",
+        }
+    }
+}
+
+// The type is read from the magic bytes of the file, the declared type and the file name
+// are not trusted
+pub fn detect_image_type(bytes: &[u8]) -> Option<ImageType> {
+    if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some(ImageType::Jpeg)
+    } else if bytes.starts_with(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]) {
+        Some(ImageType::Png)
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        Some(ImageType::Gif)
+    } else if bytes.len() >= 12 && bytes.starts_with(b"RIFF") && &bytes[8..12] == b"WEBP" {
+        Some(ImageType::Webp)
+    } else {
+        None
+    }
+}
+
+pub struct UploadedImage {
+    pub bytes: Vec<u8>,
+    pub image_type: ImageType,
+    // Sanitized stem of the uploaded file name
+    pub name: String,
+}
+
+impl UploadedImage {
+    // File name of the stored image, unique and ending with the detected extension
+    pub fn file_name(&self) -> String {
+        format!(
+            "{}-{}.{}",
+            uuid::Uuid::new_v4(),
+            self.name,
+            self.image_type.extension()
+        )
+    }
+}
+
+// Stem of the uploaded file name safe to use in a storage key
+pub fn file_stem(file_name: Option<&str>) -> String {
+    let sanitized = sanitize_filename::sanitize(file_name.unwrap_or_default());
+    let stem = match sanitized.rsplit_once('.') {
+        Some((stem, _)) if !stem.is_empty() => stem,
+        _ => sanitized.as_str(),
+    };
+    let mut name = String::new();
+    for c in stem.chars() {
+        if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+            name.push(c.to_ascii_lowercase());
+        } else if !name.is_empty() && !name.ends_with('-') {
+            name.push('-');
+        }
+        if name.len() >= MAX_NAME_LENGTH {
+            break;
+        }
+    }
+    let name = name.trim_end_matches('-');
+    if name.is_empty() {
+        "image".to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+fn image_required() -> ApiResponseError {
+    ApiResponseError::new(
+        400,
+        "Validation Error".to_string(),
+        Some(vec![ValidationErrorDetail {
+            field: "image".to_string(),
+            error: "Image is required".to_string(),
+        }]),
+    )
+}
+
+fn invalid_body(e: MultipartError) -> ApiResponseError {
+    ApiResponseError::new(400, format!("Invalid multipart body: {}", e), None)
+}
+
+// The whole field, refused once it is larger than `max_bytes`
+async fn read_field(
+    field: &mut Field,
+    max_bytes: usize,
+    too_large: impl Fn() -> ApiResponseError,
+) -> Result<Vec<u8>, ApiResponseError> {
+    let mut bytes = Vec::new();
+    while let Some(chunk) = field.try_next().await.map_err(invalid_body)? {
+        if bytes.len() + chunk.len() > max_bytes {
+            return Err(too_large());
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+    Ok(bytes)
+}
+
+// An image with the text fields sent along with it
+pub struct ImageForm {
+    pub image: UploadedImage,
+    pub fields: HashMap<String, String>,
+}
+
+// Read the image sent in the multipart field `image_field` and the `text_fields`, other fields
+// are skipped. The size limit is enforced while the upload is streamed so a large body is
+// never held in memory.
+pub async fn read_image_form(
+    mut payload: Multipart,
+    image_field: &str,
+    max_bytes: usize,
+    text_fields: &[&str],
+) -> Result<ImageForm, ApiResponseError> {
+    let mut image = None;
+    let mut fields = HashMap::new();
+
+    while let Some(mut field) = payload.try_next().await.map_err(invalid_body)? {
+        let Some(field_name) = field.name().map(str::to_string) else {
+            continue;
+        };
+
+        if field_name == image_field && image.is_none() {
+            let name = file_stem(
+                field
+                    .content_disposition()
+                    .and_then(|disposition| disposition.get_filename()),
+            );
+            let bytes = read_field(&mut field, max_bytes, || {
+                ApiResponseError::new(
+                    413,
+                    format!("Image must be at most {} bytes", max_bytes),
+                    None,
+                )
+            })
+            .await?;
+            if bytes.is_empty() {
+                return Err(image_required());
+            }
+
+            let image_type = detect_image_type(&bytes).ok_or_else(|| {
+                ApiResponseError::new(
+                    415,
+                    "Image must be a JPEG, PNG, GIF or WebP file".to_string(),
+                    None,
+                )
+            })?;
+            image = Some(UploadedImage {
+                bytes,
+                image_type,
+                name,
+            });
+        } else if text_fields.contains(&field_name.as_str()) {
+            let bytes = read_field(&mut field, MAX_TEXT_FIELD_BYTES, || {
+                ApiResponseError::new(
+                    413,
+                    format!(
+                        "Field {} must be at most {} bytes",
+                        field_name, MAX_TEXT_FIELD_BYTES
+                    ),
+                    None,
+                )
+            })
+            .await?;
+            let value = String::from_utf8(bytes).map_err(|_| {
+                ApiResponseError::new(
+                    400,
+                    "Validation Error".to_string(),
+                    Some(vec![ValidationErrorDetail {
+                        field: field_name.clone(),
+                        error: "Must be UTF-8 text".to_string(),
+                    }]),
+                )
+            })?;
+            fields.insert(field_name, value);
+        }
+    }
+
+    let image = image.ok_or_else(image_required)?;
+    Ok(ImageForm { image, fields })
+}
+
+// Read the image sent in the multipart field `field_name`
+pub async fn read_image(
+    payload: Multipart,
+    field_name: &str,
+    max_bytes: usize,
+) -> Result<UploadedImage, ApiResponseError> {
+    read_image_form(payload, field_name, max_bytes, &[])
+        .await
+        .map(|form| form.image)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_image_type() {
+        assert_eq!(
+            detect_image_type(&[0xFF, 0xD8, 0xFF, 0xE0, 0x00]),
+            Some(ImageType::Jpeg)
+        );
+        assert_eq!(
+            detect_image_type(b"\x89PNG\r\n\x1a\n\x00\x00"),
+            Some(ImageType::Png)
+        );
+        assert_eq!(detect_image_type(b"GIF89a..."), Some(ImageType::Gif));
+        assert_eq!(
+            detect_image_type(b"RIFF\x24\x00\x00\x00WEBPVP8 "),
+            Some(ImageType::Webp)
+        );
+        // A RIFF file that is not an image
+        assert_eq!(detect_image_type(b"RIFF\x24\x00\x00\x00WAVEfmt "), None);
+        assert_eq!(detect_image_type(b"<svg></svg>"), None);
+        assert_eq!(detect_image_type(b""), None);
+    }
+
+    #[test]
+    fn test_file_stem() {
+        assert_eq!(file_stem(Some("My Photo.PNG")), "my-photo");
+        assert_eq!(file_stem(Some("../../evil.png")), "evil");
+        assert_eq!(file_stem(Some(".png")), "png");
+        assert_eq!(file_stem(Some("صورة.jpg")), "image");
+        assert_eq!(file_stem(None), "image");
+        assert_eq!(file_stem(Some(&"a".repeat(100))).len(), MAX_NAME_LENGTH);
+    }
+}
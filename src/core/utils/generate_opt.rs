@@ -23,7 +23,20 @@ pub fn generate_otp(config: &Config) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{sms::Sms, smtp::Smtp, Config, Database, Jwt, Transactions};
+    use crate::config::{
+        applications::Applications,
+        files::Files,
+        impersonation::Impersonation,
+        otp::Otp,
+        phone::Phone,
+        registration::Registration,
+        schedule::Schedule,
+        sms::Sms,
+        smtp::Smtp,
+        storage::{Storage, S3},
+        two_factor::TwoFactor,
+        Config, Database, Jwt, Transactions,
+    };
 
     // Helper function to create a configuration with the specified environment
     fn create_config(env: &str) -> Config {
@@ -42,6 +55,8 @@ mod tests {
             },
             jwt: Jwt {
                 secret: String::new(),
+                access_token_minutes: 15,
+                refresh_token_days: 30,
             },
             cors: String::new(),
             sms: Sms {
@@ -58,6 +73,58 @@ mod tests {
                 encryption: String::new(),
                 from: String::new(),
             },
+            otp: Otp {
+                code_ttl_seconds: 60,
+                max_code_attempts: 3,
+                resend_cooldown_seconds: 60,
+                max_resends_per_mobile: 5,
+                max_resends_per_ip: 20,
+                max_failed_verifies_per_mobile: 5,
+                max_failed_verifies_per_ip: 20,
+                window_minutes: 15,
+                lockout_minutes: 15,
+            },
+            phone: Phone {
+                default_region: "SA".to_string(),
+                allowed_countries: vec!["SA".to_string()],
+            },
+            registration: Registration {
+                verification_hours: 24,
+                cleanup_interval_minutes: 60,
+            },
+            two_factor: TwoFactor {
+                issuer: String::new(),
+                enforce_for_admins: false,
+                recovery_codes: 10,
+            },
+            applications: Applications {
+                secret_grace_minutes: 1440,
+                signature_skew_seconds: 300,
+            },
+            impersonation: Impersonation { token_minutes: 15 },
+            schedule: Schedule {
+                interval_seconds: 30,
+            },
+            storage: Storage {
+                driver: "local".to_string(),
+                local_root: String::new(),
+                public_url: String::new(),
+                max_upload_bytes: 0,
+                s3: S3 {
+                    endpoint: String::new(),
+                    region: String::new(),
+                    bucket: String::new(),
+                    access_key: String::new(),
+                    secret_key: String::new(),
+                },
+            },
+            files: Files {
+                public_root: String::new(),
+                max_age_seconds: 0,
+                private_root: String::new(),
+                signing_key: String::new(),
+                signed_url_seconds: 3600,
+            },
         }
     }
 
@@ -12,9 +12,14 @@ use crate::config::CONFIG;
 
 #[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
 pub struct Claims {
-    pub exp: usize, //COMM: Expiration time as UNIX timestamp
-    pub iat: usize, //COMM: Issued at time as UNIX timestamp
-    pub id: String, //COMM: User ID
+    pub exp: usize,  //COMM: Expiration time as UNIX timestamp
+    pub iat: usize,  //COMM: Issued at time as UNIX timestamp
+    pub id: String,  //COMM: User ID
+    pub jti: String, //COMM: Unique token ID, used to revoke the token before it expires
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sid: Option<String>, //COMM: Session the token was issued for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub impersonator: Option<String>, //COMM: Admin acting as the user, only set on impersonation tokens
 }
 
 impl FromRequest for Claims {
@@ -34,21 +39,54 @@ impl FromRequest for Claims {
     }
 }
 
-//COMM: Encode JWT token with provided user ID and expiration duration
-pub fn encode_jwt(id: String, expire: Duration) -> Result<String, jsonwebtoken::errors::Error> {
+//COMM: Encode JWT token with provided user ID, session ID and expiration duration
+pub fn encode_jwt(
+    id: String,
+    sid: Option<String>,
+    expire: Duration,
+) -> Result<String, jsonwebtoken::errors::Error> {
     if id.is_empty() {
         return Err(ErrorKind::InvalidSubject.into());
     }
+    encode_claims(&new_claims(id, sid, None, expire))
+}
+
+//COMM: Encode a JWT token to act as the user on behalf of the impersonator, without a session.
+//COMM: The claims are returned with the token so the issue can be audited with its jti.
+pub fn encode_impersonation_jwt(
+    id: String,
+    impersonator: String,
+    expire: Duration,
+) -> Result<(String, Claims), jsonwebtoken::errors::Error> {
+    if id.is_empty() || impersonator.is_empty() {
+        return Err(ErrorKind::InvalidSubject.into());
+    }
+    let claims = new_claims(id, None, Some(impersonator), expire);
+    let token = encode_claims(&claims)?;
+    Ok((token, claims))
+}
+
+fn new_claims(
+    id: String,
+    sid: Option<String>,
+    impersonator: Option<String>,
+    expire: Duration,
+) -> Claims {
     let now = Utc::now();
-    let claims = Claims {
+    Claims {
         exp: (now + expire).timestamp() as usize, //COMM: Calculate expiration time
         iat: now.timestamp() as usize,            //COMM: Set issued at time to current time
         id,                                       //COMM: Set user ID
-    };
+        jti: uuid::Uuid::new_v4().to_string(),    //COMM: Generate a unique token ID
+        sid,                                      //COMM: Set session ID
+        impersonator,                             //COMM: Set impersonating admin ID
+    }
+}
 
+fn encode_claims(claims: &Claims) -> Result<String, jsonwebtoken::errors::Error> {
     encode(
         &Header::default(),
-        &claims,
+        claims,
         &EncodingKey::from_secret(CONFIG.jwt.secret.as_ref()), //COMM: Encode JWT using secret key
     )
 }
@@ -79,17 +117,57 @@ mod tests {
     fn test_create_and_decoded_valid_token() {
         let user_id = "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e2b".to_string();
 
-        let token = encode_jwt(user_id.clone(), Duration::hours(1)).unwrap();
+        let token = encode_jwt(user_id.clone(), None, Duration::hours(1)).unwrap();
         let decoded_user_id = decode_jwt(token).unwrap();
 
         assert_eq!(decoded_user_id.claims.id, user_id);
     }
 
+    #[test]
+    fn test_token_carries_unique_jti_and_session() {
+        let user_id = "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e2b".to_string();
+        let session_id = uuid::Uuid::new_v4().to_string();
+
+        let first = encode_jwt(
+            user_id.clone(),
+            Some(session_id.clone()),
+            Duration::hours(1),
+        );
+        let second = encode_jwt(user_id, None, Duration::hours(1));
+
+        let first = decode_jwt(first.unwrap()).unwrap();
+        let second = decode_jwt(second.unwrap()).unwrap();
+
+        assert_eq!(first.claims.sid, Some(session_id));
+        assert_eq!(second.claims.sid, None);
+        assert_ne!(first.claims.jti, second.claims.jti);
+    }
+
+    #[test]
+    fn test_impersonation_token_carries_impersonator() {
+        let user_id = "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e24".to_string();
+        let admin_id = "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e2b".to_string();
+
+        let (token, issued_claims) =
+            encode_impersonation_jwt(user_id.clone(), admin_id.clone(), Duration::minutes(15))
+                .unwrap();
+        let claims = decode_jwt(token).unwrap().claims;
+
+        assert_eq!(claims.id, user_id);
+        assert_eq!(claims.impersonator, Some(admin_id));
+        assert_eq!(claims.sid, None);
+        assert_eq!(claims.jti, issued_claims.jti);
+
+        // Regular tokens never carry the claim
+        let token = encode_jwt(user_id, None, Duration::hours(1)).unwrap();
+        assert_eq!(decode_jwt(token).unwrap().claims.impersonator, None);
+    }
+
     #[test]
     fn test_create_token_with_empty_user_id() {
         let user_id = "".to_string();
 
-        let result = encode_jwt(user_id, Duration::hours(1));
+        let result = encode_jwt(user_id, None, Duration::hours(1));
 
         assert!(result.is_err());
         assert_eq!(
@@ -110,7 +188,7 @@ mod tests {
     #[test]
     fn test_decode_expired_token() {
         let user_id = "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e2b".to_string();
-        let expired_token = encode_jwt(user_id, Duration::hours(-1)).unwrap();
+        let expired_token = encode_jwt(user_id, None, Duration::hours(-1)).unwrap();
 
         let result = decode_jwt(expired_token);
 
@@ -1,6 +1,5 @@
 pub mod export_to_csv;
 pub mod generate_opt;
 pub mod jwt;
-pub mod lazy_load;
 pub mod test_utils;
-pub mod transform_mobile;
+pub mod validator;
@@ -14,6 +14,9 @@ pub fn generate_jwt(user_id: &str) -> String {
         exp: (now + expire).timestamp() as usize,
         iat: now.timestamp() as usize,
         id: user_id.to_owned(),
+        jti: uuid::Uuid::new_v4().to_string(),
+        sid: None,
+        impersonator: None,
     };
     let encoding_key = jsonwebtoken::EncodingKey::from_secret(CONFIG.jwt.secret.as_ref());
     jsonwebtoken::encode(&jsonwebtoken::Header::default(), &claims, &encoding_key).unwrap()
@@ -27,6 +30,9 @@ pub fn generate_test_jwt() -> String {
         exp: (now + expire).timestamp() as usize,
         iat: now.timestamp() as usize,
         id: USER_ID.to_owned(),
+        jti: uuid::Uuid::new_v4().to_string(),
+        sid: None,
+        impersonator: None,
     };
     let encoding_key = jsonwebtoken::EncodingKey::from_secret(CONFIG.jwt.secret.as_ref());
     jsonwebtoken::encode(&jsonwebtoken::Header::default(), &claims, &encoding_key).unwrap()
@@ -3,13 +3,13 @@ use validator::Validate;
 
 //COMM: Validates a request object using the `validator` crate and converts validation errors
 //COMM: into an `ApiResponseError` containing detailed validation error messages.
-///
+//
 //COMM: # Arguments
-///
+//
 //COMM: * `data` - A reference to the data structure implementing `Validate` trait.
-///
+//
 //COMM: # Returns
-///
+//
 //COMM: * `Result<(), ApiResponseError>` - `Ok(())` if validation succeeds, or an `ApiResponseError`
 //COMM:   containing validation error details if validation fails.
 pub fn validate_request<T: Validate + 'static>(data: &T) -> Result<(), ApiResponseError> {
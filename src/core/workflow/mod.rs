@@ -0,0 +1,199 @@
+use sqlx::{MySqlConnection, MySqlPool};
+
+use crate::{
+    core::enums::ContentState,
+    model::content_transition::ContentTransitionModel,
+    schema::response::api_response_error::{ApiResponseError, ValidationErrorDetail},
+};
+
+const MAX_NOTE_LENGTH: usize = 255;
+
+pub struct Transition {
+    pub from: ContentState,
+    pub to: ContentState,
+    // Permission needed to move the content
+    pub permission: &'static str,
+    // Moves made by the author, the ownership policy applies
+    pub by_author: bool,
+}
+
+// The workflow of contents, any other move is rejected
+pub const TRANSITIONS: &[Transition] = &[
+    // Submit for review
+    Transition {
+        from: ContentState::Draft,
+        to: ContentState::InReview,
+        permission: "contents.update",
+        by_author: true,
+    },
+    // Send back to the author
+    Transition {
+        from: ContentState::InReview,
+        to: ContentState::Draft,
+        permission: "contents.publish",
+        by_author: false,
+    },
+    Transition {
+        from: ContentState::InReview,
+        to: ContentState::Published,
+        permission: "contents.publish",
+        by_author: false,
+    },
+    Transition {
+        from: ContentState::Published,
+        to: ContentState::Archived,
+        permission: "contents.publish",
+        by_author: false,
+    },
+    // Reopen an archived content
+    Transition {
+        from: ContentState::Archived,
+        to: ContentState::Draft,
+        permission: "contents.publish",
+        by_author: false,
+    },
+];
+
+fn validation_error(field: &str, error: String) -> ApiResponseError {
+    ApiResponseError::new(
+        400,
+        "Validation Error".to_string(),
+        Some(vec![ValidationErrorDetail {
+            field: field.to_string(),
+            error,
+        }]),
+    )
+}
+
+pub fn parse_state(name: &str) -> Result<ContentState, ApiResponseError> {
+    ContentState::from_name(name.trim()).ok_or_else(|| {
+        validation_error(
+            "state",
+            "State must be one of draft, in_review, published, archived".to_string(),
+        )
+    })
+}
+
+pub fn validate_note(note: Option<&str>) -> Result<Option<String>, ApiResponseError> {
+    let note = note.map(str::trim).filter(|note| !note.is_empty());
+    if note.is_some_and(|note| note.chars().count() > MAX_NOTE_LENGTH) {
+        return Err(validation_error(
+            "note",
+            format!("Note must be at most {} characters", MAX_NOTE_LENGTH),
+        ));
+    }
+    Ok(note.map(str::to_string))
+}
+
+pub fn find_transition(
+    from: ContentState,
+    to: ContentState,
+) -> Result<&'static Transition, ApiResponseError> {
+    TRANSITIONS
+        .iter()
+        .find(|transition| transition.from == from && transition.to == to)
+        .ok_or_else(|| {
+            ApiResponseError::new(
+                409,
+                format!(
+                    "Cannot move content from {} to {}",
+                    from.to_str(),
+                    to.to_str()
+                ),
+                None,
+            )
+        })
+}
+
+// Run inside the transaction that changes the state of the content
+pub async fn record_transition(
+    conn: &mut MySqlConnection,
+    content_id: &str,
+    transition: &Transition,
+    user_id: &str,
+    note: Option<&str>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO content_transitions (content_id, from_state, to_state, user_id, note) VALUES (?, ?, ?, ?, ?)",
+    )
+    .bind(content_id)
+    .bind(transition.from.to_i8())
+    .bind(transition.to.to_i8())
+    .bind(user_id)
+    .bind(note)
+    .execute(conn)
+    .await?;
+    Ok(())
+}
+
+// History of the content, latest first
+pub async fn content_transitions(
+    pool: &MySqlPool,
+    content_id: &str,
+) -> Result<Vec<ContentTransitionModel>, ApiResponseError> {
+    sqlx::query_as!(
+        ContentTransitionModel,
+        "SELECT * FROM content_transitions WHERE content_id = ? ORDER BY created_at DESC, id DESC",
+        content_id
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_transition() {
+        let submit = find_transition(ContentState::Draft, ContentState::InReview).unwrap();
+        assert_eq!(submit.permission, "contents.update");
+        assert!(submit.by_author);
+
+        let publish = find_transition(ContentState::InReview, ContentState::Published).unwrap();
+        assert_eq!(publish.permission, "contents.publish");
+        assert!(!publish.by_author);
+
+        // Contents are reviewed before they are published
+        let err = find_transition(ContentState::Draft, ContentState::Published)
+            .err()
+            .unwrap();
+        assert_eq!(err.status, 409);
+        assert_eq!(err.message, "Cannot move content from draft to published");
+
+        assert!(find_transition(ContentState::Archived, ContentState::Published).is_err());
+        assert!(find_transition(ContentState::Draft, ContentState::Draft).is_err());
+    }
+
+    #[test]
+    fn test_parse_state_and_note() {
+        assert_eq!(parse_state("in_review").unwrap(), ContentState::InReview);
+        assert_eq!(parse_state(" archived ").unwrap(), ContentState::Archived);
+        assert_eq!(parse_state("deleted").unwrap_err().status, 400);
+
+        assert_eq!(validate_note(None).unwrap(), None);
+        assert_eq!(validate_note(Some("  ")).unwrap(), None);
+        assert_eq!(
+            validate_note(Some(" Looks good ")).unwrap(),
+            Some("Looks good".to_string())
+        );
+        assert!(validate_note(Some(&"a".repeat(MAX_NOTE_LENGTH + 1))).is_err());
+    }
+
+    #[test]
+    fn test_states_round_trip() {
+        for state in [
+            ContentState::Draft,
+            ContentState::InReview,
+            ContentState::Published,
+            ContentState::Archived,
+        ] {
+            assert_eq!(ContentState::from_i8(state.to_i8()), Some(state));
+            assert_eq!(ContentState::from_name(state.to_str()), Some(state));
+        }
+        // Contents active before the workflow are published
+        assert_eq!(ContentState::from_i8(1), Some(ContentState::Published));
+        assert_eq!(ContentState::name_of(9), "unknown");
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{
+    core::{
+        app_state::AppState,
+        application::{find_application, generate_app_key, generate_app_secret},
+    },
+    model::application::ApplicationModelResponse,
+    schema::{
+        admin::application::CreateApplicationSchema,
+        response::{
+            admin::users::AuthUser, api_response::ApiResponse,
+            api_response_error::ApiResponseError, api_response_object::ApiResponseObject,
+        },
+    },
+};
+use actix_web::{post, web};
+use serde_json::json;
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    post,
+    path = "/admin/applications/create",
+    tag = "Admin: Applications Endpoint",
+    request_body(content = CreateApplicationSchema, description = "Application to create, the key and secret are generated", example = json!({"app_name": "mobile.app", "app_version": "1"})),
+    responses(
+        (status = 201, description= "Application created, the secret is only shown in this response", body = ApiResponse),
+        (status = 409, description= "Duplicate entry", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[post("/create")]
+pub async fn create_application_handler(
+    data: web::Json<CreateApplicationSchema>,
+    auth: AuthUser,
+    app_state: web::Data<AppState>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let application_id = uuid::Uuid::new_v4().to_string();
+    let app_secret = generate_app_secret();
+
+    // New applications are active until disabled by an admin
+    let insert_result = sqlx::query(
+        "INSERT INTO applications (id, app_name, app_version, app_key, app_secret, record_state, created_by) VALUES (?, ?, ?, ?, ?, 1, ?)",
+    )
+    .bind(&application_id)
+    .bind(&data.app_name)
+    .bind(data.app_version.as_deref().unwrap_or("1"))
+    .bind(generate_app_key())
+    .bind(&app_secret)
+    .bind(&auth.id)
+    .execute(&app_state.pool)
+    .await;
+
+    if let Err(err) = insert_result {
+        if err.to_string().contains("Duplicate entry") {
+            return Err(ApiResponseError::new(
+                409,
+                "Application name already exists".to_string(),
+                None,
+            ));
+        }
+        return Err(ApiResponseError::new(
+            500,
+            format!("Internal Server Error: {:?}", err),
+            None,
+        ));
+    }
+
+    let application = find_application(&app_state.pool, &application_id).await?;
+
+    let application_response = ApiResponseObject::new(json!({
+        "application": ApplicationModelResponse::filter_db(&application),
+        "app_secret": app_secret,
+    }))
+    .map_err(|err| ApiResponseError::new(500, err.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        201,
+        "Application Created. Store the secret now, it will not be shown again".to_string(),
+        Some(application_response),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::utils::test_utils::{create_test_app_state, generate_test_jwt},
+        middlewares::auth_middleware::RequireAuth,
+        routes,
+        schema::{
+            admin::application::CreateApplicationSchema, response::api_response::ApiResponse,
+        },
+    };
+    use actix_web::{test, web, App};
+
+    #[actix_web::test]
+    async fn test_create_application_handler() {
+        let app_state = create_test_app_state().await;
+
+        // create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .wrap(RequireAuth {})
+                .service(web::scope("/admin").configure(routes::admin::applications::config)),
+        )
+        .await;
+
+        let create_application_data = CreateApplicationSchema {
+            app_name: format!("test.{}", uuid::Uuid::new_v4()),
+            app_version: Some("2".to_string()),
+        };
+
+        let req = test::TestRequest::post()
+            .uri("/admin/applications/create")
+            .insert_header((
+                actix_web::http::header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .set_json(&create_application_data)
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 201);
+        let data = resp.data.expect("Missing response data").properties;
+        assert!(data["app_secret"].is_string());
+        assert!(data["application"].get("app_secret").is_none());
+    }
+}
@@ -0,0 +1,126 @@
+use actix_web::{delete, web};
+use uuid::Uuid;
+
+use crate::{
+    core::{app_state::AppState, application::find_application, policy::ensure_unprotected},
+    schema::response::{api_response::ApiResponse, api_response_error::ApiResponseError},
+};
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    delete,
+    path = "/admin/applications/delete/{id}",
+    tag = "Admin: Applications Endpoint",
+    params(
+        ("id" = Uuid, Path, description = "UUID of the application"),
+    ),
+    responses(
+        (status = 204, description= "Application Deleted", body = ApiResponse),
+        (status = 403, description= "Application Is Protected", body = ApiResponseError),
+        (status = 404, description= "Application Not Found", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[delete("/delete/{id}")]
+pub async fn delete_application_handler(
+    path: web::Path<Uuid>,
+    app_state: web::Data<AppState>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let application_id = path.into_inner().to_string();
+    let application = find_application(&app_state.pool, &application_id).await?;
+
+    ensure_unprotected(&application)?;
+
+    // Soft delete, the app key stops working right away
+    let query_result = sqlx::query(
+        "UPDATE applications SET deleted_at = CURRENT_TIMESTAMP WHERE id = ? AND deleted_at IS NULL",
+    )
+    .bind(&application_id)
+    .execute(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal server error: {}", e), None))?;
+
+    if query_result.rows_affected() == 0 {
+        return Err(ApiResponseError::new(
+            404,
+            format!("No data found with id {}", application_id),
+            None,
+        ));
+    }
+
+    Ok(ApiResponse::new(
+        204,
+        "Application deleted".to_string(),
+        None,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::{
+            application::{generate_app_key, generate_app_secret},
+            utils::test_utils::{create_test_app_state, generate_test_jwt, USER_ID},
+        },
+        middlewares::auth_middleware::RequireAuth,
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::StatusCode, test, web, App};
+
+    #[actix_web::test]
+    async fn test_delete_application_handler() {
+        let app_state = create_test_app_state().await;
+
+        // create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .wrap(RequireAuth {})
+                .service(web::scope("/admin").configure(routes::admin::applications::config)),
+        )
+        .await;
+
+        // insert test application data into the database
+        let application_id = uuid::Uuid::new_v4().to_string();
+        sqlx::query(
+            "INSERT INTO applications (id, app_name, app_key, app_secret, record_state, created_by) VALUES (?, ?, ?, ?, 1, ?)",
+        )
+        .bind(&application_id)
+        .bind(format!("test.{}", application_id))
+        .bind(generate_app_key())
+        .bind(generate_app_secret())
+        .bind(USER_ID)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test application");
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/admin/applications/delete/{}", application_id))
+            .insert_header((
+                actix_web::http::header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 204);
+
+        // The deleted application is not found anymore
+        let req = test::TestRequest::get()
+            .uri(&format!("/admin/applications/{}", application_id))
+            .insert_header((
+                actix_web::http::header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::schema::response::{
+    api_response::ApiResponse, api_response_error::ApiResponseError,
+    api_response_object::ApiResponseObject,
+};
+use actix_web::{get, web};
+use uuid::Uuid;
+
+use crate::{
+    core::{app_state::AppState, application::find_application},
+    model::application::ApplicationModelResponse,
+};
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    get,
+    path = "/admin/applications/{id}",
+    tag = "Admin: Applications Endpoint",
+    params(
+        ("id" = Uuid, Path, description = "UUID of the application to get")
+    ),
+    responses(
+        (status = 200, description= "Get Application By ID", body = ApiResponse),
+        (status = 404, description= "Application Not Found", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[get("/{id}")]
+pub async fn get_application_by_id_handler(
+    path: web::Path<Uuid>,
+    app_state: web::Data<AppState>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let application_id = path.into_inner().to_string();
+    let application = find_application(&app_state.pool, &application_id).await?;
+
+    let application_response = ApiResponseObject::new(serde_json::json!({
+        "application": ApplicationModelResponse::filter_db(&application)
+    }))
+    .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        200,
+        "Get Application By Id".to_string(),
+        Some(application_response),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::{
+            application::{generate_app_key, generate_app_secret},
+            utils::test_utils::{create_test_app_state, generate_test_jwt, USER_ID},
+        },
+        middlewares::auth_middleware::RequireAuth,
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{test, web, App};
+
+    #[actix_web::test]
+    async fn test_get_application_by_id_handler() {
+        let app_state = create_test_app_state().await;
+
+        // create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .wrap(RequireAuth {})
+                .service(web::scope("/admin").configure(routes::admin::applications::config)),
+        )
+        .await;
+
+        // insert test application data into the database
+        let application_id = uuid::Uuid::new_v4().to_string();
+        sqlx::query(
+            "INSERT INTO applications (id, app_name, app_key, app_secret, record_state, created_by) VALUES (?, ?, ?, ?, 1, ?)",
+        )
+        .bind(&application_id)
+        .bind(format!("test.{}", application_id))
+        .bind(generate_app_key())
+        .bind(generate_app_secret())
+        .bind(USER_ID)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test application");
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/admin/applications/{}", application_id))
+            .insert_header((
+                actix_web::http::header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 200);
+        assert_eq!(resp.message, "Get Application By Id");
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{
+    core::app_state::AppState,
+    model::application::{ApplicationModel, ApplicationModelResponse},
+    schema::{
+        admin::application::ApplicationsFilterOptions,
+        response::{
+            api_response_collection::ApiResponseCollection, api_response_error::ApiResponseError,
+            api_response_object::ApiResponseObject, Pagination,
+        },
+    },
+};
+use actix_web::{get, web, HttpResponse, Responder};
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    get,
+    path = "/admin/applications",
+    tag = "Admin: Applications Endpoint",
+    params(
+        ApplicationsFilterOptions
+    ),
+    responses(
+        (status = 200, description= "Get All Applications", body = ApiResponse),
+        (status = 401, description= "Unauthorized", body = ApiResponseError),
+        (status = 404, description= "No Data Found", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[get("")]
+pub async fn get_applications_handler(
+    opts: web::Query<ApplicationsFilterOptions>,
+    app_state: web::Data<AppState>,
+) -> Result<impl Responder, ApiResponseError> {
+    let limit = opts.limit.unwrap_or(10);
+    let page = opts.page.unwrap_or(1);
+    let offset = (page - 1) * limit;
+
+    // Deleted applications are not listed
+    let mut conditions = "deleted_at IS NULL".to_string();
+    let app_name = opts.app_name.as_ref().map(|name| format!("%{}%", name));
+    if app_name.is_some() {
+        conditions.push_str(" AND app_name LIKE ?");
+    }
+
+    let query = format!(
+        "SELECT * FROM applications WHERE {} ORDER BY created_at DESC LIMIT ? OFFSET ?",
+        conditions
+    );
+    let mut applications_query = sqlx::query_as::<_, ApplicationModel>(&query);
+    if let Some(ref app_name) = app_name {
+        applications_query = applications_query.bind(app_name);
+    }
+    let applications = applications_query
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&app_state.pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    if applications.is_empty() {
+        return Err(ApiResponseError::new(
+            404,
+            "No Data Found".to_string(),
+            None,
+        ));
+    }
+
+    let total_count_query = format!("SELECT COUNT(*) FROM applications WHERE {}", conditions);
+    let mut total_count_query = sqlx::query_as::<_, (i64,)>(&total_count_query);
+    if let Some(ref app_name) = app_name {
+        total_count_query = total_count_query.bind(app_name);
+    }
+    let total_count = total_count_query
+        .fetch_one(&app_state.pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    let application_response: Vec<ApplicationModelResponse> = applications
+        .iter()
+        .map(ApplicationModelResponse::filter_db)
+        .collect();
+
+    let total_items = total_count.0;
+    let total_pages = (total_items as f64 / limit as f64).ceil() as i64;
+    let pagination = Pagination {
+        total_items,
+        total_pages,
+        current_page: page,
+        per_page: limit,
+    };
+
+    let json_response = ApiResponseObject::new(serde_json::json!({
+        "applications": application_response,
+    }))
+    .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(HttpResponse::Ok().json(ApiResponseCollection::new(
+        200,
+        "Get All Applications".to_string(),
+        Some(json_response),
+        Some(pagination),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::utils::test_utils::{create_test_app_state, generate_test_jwt},
+        middlewares::auth_middleware::RequireAuth,
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{test, web, App};
+
+    #[actix_web::test]
+    async fn test_get_applications_handler() {
+        let app_state = create_test_app_state().await;
+
+        // create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .wrap(RequireAuth {})
+                .service(web::scope("/admin").configure(routes::admin::applications::config)),
+        )
+        .await;
+
+        // The seeded application is always listed
+        let req = test::TestRequest::get()
+            .uri("/admin/applications?limit=10&page=1&app_name=actix")
+            .insert_header((
+                actix_web::http::header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 200);
+        assert_eq!(resp.message, "Get All Applications");
+    }
+}
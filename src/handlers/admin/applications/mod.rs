@@ -0,0 +1,6 @@
+pub mod create_application;
+pub mod delete_application;
+pub mod get_application_by_id;
+pub mod get_applications;
+pub mod rotate_application_secret;
+pub mod update_application;
@@ -0,0 +1,138 @@
+use actix_web::{post, web};
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use crate::{
+    config::CONFIG,
+    core::{
+        app_state::AppState,
+        application::{find_application, generate_app_secret},
+        policy::ensure_unprotected,
+    },
+    model::application::ApplicationModelResponse,
+    schema::response::{
+        api_response::ApiResponse, api_response_error::ApiResponseError,
+        api_response_object::ApiResponseObject,
+    },
+};
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    post,
+    path = "/admin/applications/rotate-secret/{id}",
+    tag = "Admin: Applications Endpoint",
+    params(
+        ("id" = Uuid, Path, description = "UUID of the application"),
+    ),
+    responses(
+        (status = 200, description= "Secret rotated, the new secret is only shown in this response and the previous one works until previous_secret_expires_at", body = ApiResponse),
+        (status = 403, description= "Application Is Protected", body = ApiResponseError),
+        (status = 404, description= "Application Not Found", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[post("/rotate-secret/{id}")]
+pub async fn rotate_application_secret_handler(
+    path: web::Path<Uuid>,
+    app_state: web::Data<AppState>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let application_id = path.into_inner().to_string();
+    // Make sure the application exists, is not deleted and is not protected
+    let application = find_application(&app_state.pool, &application_id).await?;
+    ensure_unprotected(&application)?;
+
+    let app_secret = generate_app_secret();
+    let previous_secret_expires_at =
+        Utc::now() + Duration::minutes(CONFIG.applications.secret_grace_minutes);
+
+    // The current secret becomes the previous one, replacing any secret still in its grace period
+    sqlx::query(
+        "UPDATE applications SET previous_app_secret = app_secret, previous_secret_expires_at = ?, app_secret = ? WHERE id = ? AND deleted_at IS NULL",
+    )
+    .bind(previous_secret_expires_at)
+    .bind(&app_secret)
+    .bind(&application_id)
+    .execute(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal server error: {}", e), None))?;
+
+    let application = find_application(&app_state.pool, &application_id).await?;
+    let application_response = ApiResponseObject::new(serde_json::json!({
+        "application": ApplicationModelResponse::filter_db(&application),
+        "app_secret": app_secret,
+    }))
+    .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        200,
+        "Application Secret Rotated. Store the secret now, it will not be shown again".to_string(),
+        Some(application_response),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::{
+            application::{find_application, generate_app_key, generate_app_secret},
+            utils::test_utils::{create_test_app_state, generate_test_jwt, USER_ID},
+        },
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{test, web, App};
+
+    #[actix_web::test]
+    async fn test_rotate_application_secret_handler() {
+        let app_state = create_test_app_state().await;
+
+        // create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .service(web::scope("/admin").configure(routes::admin::applications::config)),
+        )
+        .await;
+
+        // insert test application data into the database
+        let application_id = uuid::Uuid::new_v4().to_string();
+        let app_secret = generate_app_secret();
+        sqlx::query(
+            "INSERT INTO applications (id, app_name, app_key, app_secret, record_state, created_by) VALUES (?, ?, ?, ?, 1, ?)",
+        )
+        .bind(&application_id)
+        .bind(format!("test.{}", application_id))
+        .bind(generate_app_key())
+        .bind(&app_secret)
+        .bind(USER_ID)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test application");
+
+        let req = test::TestRequest::post()
+            .uri(&format!(
+                "/admin/applications/rotate-secret/{}",
+                application_id
+            ))
+            .insert_header((
+                actix_web::http::header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 200);
+
+        // The old secret is kept for the grace period
+        let application = find_application(&app_state.pool, &application_id)
+            .await
+            .expect("Failed to fetch test application");
+        assert_ne!(application.app_secret, app_secret);
+        assert_eq!(application.previous_app_secret, Some(app_secret));
+        assert!(application.previous_secret_expires_at.is_some());
+    }
+}
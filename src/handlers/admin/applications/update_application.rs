@@ -0,0 +1,158 @@
+use actix_web::{put, web};
+use uuid::Uuid;
+
+use crate::{
+    core::{
+        app_state::AppState, application::find_application, policy::authorize_protected_update,
+    },
+    model::application::ApplicationModelResponse,
+    schema::{
+        admin::application::UpdateApplicationSchema,
+        response::{
+            admin::users::AuthUser, api_response::ApiResponse,
+            api_response_error::ApiResponseError, api_response_object::ApiResponseObject,
+        },
+    },
+};
+
+#[utoipa::path(
+    put,
+    path = "/admin/applications/update/{id}",
+    tag = "Admin: Applications Endpoint",
+    params(
+        ("id" = Uuid, Path, description = "UUID of the application to update"),
+    ),
+    request_body(content = UpdateApplicationSchema, description = "Application fields to update, record_state false disables the app key", example = json!({"app_name": "mobile.app", "app_version": "2", "record_state": true, "protected": false})),
+    responses(
+        (status = 200, description= "Application Updated", body = ApiResponse),
+        (status = 401, description= "Unauthorized", body = ApiResponseError),
+        (status = 403, description= "Application Is Protected Or Missing records.protect To Change It", body = ApiResponseError),
+        (status = 404, description= "Application Not Found", body = ApiResponseError),
+        (status = 409, description= "Duplicate entry", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[put("/update/{id}")]
+pub async fn update_application_handler(
+    path: web::Path<Uuid>,
+    auth: AuthUser,
+    app_state: web::Data<AppState>,
+    body: web::Json<UpdateApplicationSchema>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let application_id = path.into_inner().to_string();
+    let application = find_application(&app_state.pool, &application_id).await?;
+
+    authorize_protected_update(&app_state.pool, &auth, &application, body.protected).await?;
+
+    let record_state = body.record_state.unwrap_or(application.record_state != 0) as i8;
+    let protected = body.protected.unwrap_or(application.protected != 0) as i8;
+
+    let update_result = sqlx::query(
+        "UPDATE applications SET app_name = ?, app_version = ?, record_state = ?, protected = ? WHERE id = ?",
+    )
+    .bind(body.app_name.as_deref().unwrap_or(&application.app_name))
+    .bind(body.app_version.as_deref().unwrap_or(&application.app_version))
+    .bind(record_state)
+    .bind(protected)
+    .bind(&application_id)
+    .execute(&app_state.pool)
+    .await;
+
+    if let Err(err) = update_result {
+        if err.to_string().contains("Duplicate entry") {
+            return Err(ApiResponseError::new(
+                409,
+                "Application name already exists".to_string(),
+                None,
+            ));
+        }
+        return Err(ApiResponseError::new(
+            500,
+            format!("Internal server error: {:?}", err),
+            None,
+        ));
+    }
+
+    let application = find_application(&app_state.pool, &application_id).await?;
+    let application_response = ApiResponseObject::new(serde_json::json!({
+        "application": ApplicationModelResponse::filter_db(&application)
+    }))
+    .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        200,
+        "Application updated".to_string(),
+        Some(application_response),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::{
+            application::{generate_app_key, generate_app_secret},
+            utils::test_utils::{create_test_app_state, generate_test_jwt, USER_ID},
+        },
+        middlewares::auth_middleware::RequireAuth,
+        routes,
+        schema::{
+            admin::application::UpdateApplicationSchema, response::api_response::ApiResponse,
+        },
+    };
+    use actix_web::{test, web, App};
+
+    #[actix_web::test]
+    async fn test_update_application_handler() {
+        let app_state = create_test_app_state().await;
+
+        // create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .wrap(RequireAuth {})
+                .service(web::scope("/admin").configure(routes::admin::applications::config)),
+        )
+        .await;
+
+        // insert test application data into the database
+        let application_id = uuid::Uuid::new_v4().to_string();
+        sqlx::query(
+            "INSERT INTO applications (id, app_name, app_key, app_secret, record_state, created_by) VALUES (?, ?, ?, ?, 1, ?)",
+        )
+        .bind(&application_id)
+        .bind(format!("test.{}", application_id))
+        .bind(generate_app_key())
+        .bind(generate_app_secret())
+        .bind(USER_ID)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test application");
+
+        // Disable the application
+        let update_application_data = UpdateApplicationSchema {
+            app_name: None,
+            app_version: Some("2".to_string()),
+            record_state: Some(false),
+            protected: None,
+        };
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/admin/applications/update/{}", application_id))
+            .insert_header((
+                actix_web::http::header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .set_json(&update_application_data)
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 200);
+        let application = &resp.data.expect("Missing response data").properties["application"];
+        assert_eq!(application["app_version"], "2");
+        assert_eq!(application["record_state"], false);
+    }
+}
@@ -1,5 +1,17 @@
 use crate::{
-    core::app_state::AppState,
+    core::{
+        app_state::AppState,
+        image_variant::variant_queue::VariantQueue,
+        media::{
+            content_galleries, find_content_media, set_content_gallery, set_content_image_media,
+            validate_gallery,
+        },
+        revision::record_revision,
+        schedule::{authorize_schedule, validate_schedule},
+        slug::{content_slug, duplicate_slug_error, slug_taken, unique_slug, validate_slug},
+        utils::validator::validate_request,
+    },
+    middlewares::permission_middleware::RequirePermission,
     model::{
         content::{ContentModel, ContentModelResponse},
         user::UserModel,
@@ -20,41 +32,128 @@ use serde_json::json;
     post,
     path = "/admin/contents/create",
     tag = "Admin: Contents Endpoint",
-    request_body(content = CreateContentSchema, description = "Credentials to create content", example = json!({"content_type": "page", "title": "test page","summary": "test summary", "details": "test details", "configurations": json!({"property1": "value", "property2": json!({"sub-property": "value"})})})),
+    request_body(content = CreateContentSchema, description = "Credentials to create content", example = json!({"content_type": "page", "title": "test page","summary": "test summary", "details": "test details", "publish_at": "2024-08-01T09:00:00Z", "unpublish_at": "2024-09-01T09:00:00Z", "slug": "test-page", "meta_title": "Test page", "meta_description": "A page to test", "canonical_url": "https://example.com/test-page", "image_media_id": "0b1c2d3e-4f50-4617-8293-a4b5c6d7e8f9", "gallery": ["0b1c2d3e-4f50-4617-8293-a4b5c6d7e8f9"], "configurations": json!({"property1": "value", "property2": json!({"sub-property": "value"})})})),
     responses(
         (status = 201, description= "Content created", body = ApiResponse),       
-        (status = 409, description= "Duplicate entry", body = ApiResponseError),       
+        (status = 400, description= "Validation Error", body = ApiResponseError),
+        (status = 403, description= "Missing the contents.publish permission to schedule", body = ApiResponseError),
+        (status = 409, description= "Slug already exists", body = ApiResponseError),       
         (status = 500, description= "Internal Server Error", body = ApiResponseError),       
     ),
     security(
        ("auth_token" = [])
    )
 )]
-#[post("/create")]
+#[post("/create", wrap = "RequirePermission(\"contents.create\")")]
 pub async fn create_contents_handler(
     data: web::Json<CreateContentSchema>, // JSON request body as `CreateContentSchema`
     auth: AuthUser,                       // JWT claims extracted from authorization token
     app_state: web::Data<AppState>, // Shared application state containing database connection pool
+    variant_queue: web::Data<VariantQueue>, // Queue of the image variants made in the background
 ) -> Result<ApiResponse, ApiResponseError> {
+    validate_request(&*data)?;
+    validate_schedule(data.publish_at, data.unpublish_at)?;
+    let schedules = data.publish_at.is_some() || data.unpublish_at.is_some();
+    authorize_schedule(&app_state.pool, &auth.id, schedules).await?;
+    let scheduled_by = schedules.then_some(&auth.id);
+
     // Generate a new UUID for content ID
     let content_id = uuid::Uuid::new_v4().to_string();
 
+    // A slug that is sent must be free, a generated one gets a suffix when it is taken
+    let slug = match data.slug {
+        Some(ref slug) => {
+            let slug = validate_slug(slug)?;
+            let taken = slug_taken(&app_state.pool, &slug, &content_id)
+                .await
+                .map_err(|e| {
+                    ApiResponseError::new(500, format!("Internal Server Error: {:?}", e), None)
+                })?;
+            if taken {
+                return Err(duplicate_slug_error());
+            }
+            slug
+        }
+        None => unique_slug(
+            &app_state.pool,
+            &content_slug(&data.title, &content_id),
+            &content_id,
+        )
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {:?}", e), None))?,
+    };
+
+    // Media are checked before anything is written
+    let image_media = match data.image_media_id {
+        Some(ref media_id) => {
+            Some(find_content_media(&app_state.pool, "image_media_id", media_id).await?)
+        }
+        None => None,
+    };
+    let gallery = match data.gallery {
+        Some(ref media_ids) => validate_gallery(&app_state.pool, media_ids).await?,
+        None => Vec::new(),
+    };
+
+    // The content and its first revision are created together
+    let mut tx =
+        app_state.pool.begin().await.map_err(|e| {
+            ApiResponseError::new(500, format!("Internal Server Error: {:?}", e), None)
+        })?;
+
     // Execute SQL query to insert new content into database
     let insert_result = sqlx::query(
-        "INSERT INTO contents (id, content_type, title, summary, details, created_by) VALUES (?, ?, ?, ?, ?, ?, ?)"
+        "INSERT INTO contents (id, content_type, title, slug, summary, details, meta_title, meta_description, canonical_url, publish_at, unpublish_at, scheduled_by, created_by) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
     )
     .bind(&content_id) // Binds content ID
     .bind(&data.content_type) // Binds content type
     .bind(&data.title) // Binds title
+    .bind(&slug) // Binds slug sent or derived from the title
     .bind(data.summary.as_deref()) // Binds summary if present
     .bind(data.details.as_deref()) // Binds details if present
+    .bind(data.meta_title.as_deref()) // Binds SEO fields if present
+    .bind(data.meta_description.as_deref())
+    .bind(data.canonical_url.as_deref())
+    .bind(data.publish_at) // Binds publish time if scheduled
+    .bind(data.unpublish_at) // Binds unpublish time if scheduled
+    .bind(scheduled_by) // Binds who scheduled the content
     .bind(&auth.id) // Binds creator's ID from JWT claims
-    .execute(&app_state.pool) // Executes query against database pool
+    .execute(&mut *tx) // Executes query inside the transaction
     .await;
 
     // Handle insert result
     match insert_result {
         Ok(_) => {
+            if let Some(ref media) = image_media {
+                set_content_image_media(&mut tx, &content_id, media)
+                    .await
+                    .map_err(|err| {
+                        ApiResponseError::new(
+                            500,
+                            format!("Internal Server Error: {:?}", err),
+                            None,
+                        )
+                    })?;
+            }
+            set_content_gallery(&mut tx, &content_id, &gallery)
+                .await
+                .map_err(|err| {
+                    ApiResponseError::new(500, format!("Internal Server Error: {:?}", err), None)
+                })?;
+            record_revision(&mut tx, &content_id, &auth.id)
+                .await
+                .map_err(|err| {
+                    ApiResponseError::new(500, format!("Internal Server Error: {:?}", err), None)
+                })?;
+            tx.commit().await.map_err(|err| {
+                ApiResponseError::new(500, format!("Internal Server Error: {:?}", err), None)
+            })?;
+
+            // The variants of the image are made in the background
+            if let Some(ref media) = image_media {
+                variant_queue.queue(&content_id, &media.storage_key).await;
+            }
+
             // Fetch newly created content from database
             let mut content = sqlx::query_as!(
                 ContentModel,
@@ -81,7 +180,9 @@ pub async fn create_contents_handler(
                     })?;
 
             // Generate response with filtered content details
-            let response = ContentModelResponse::filter_db(&mut content, &created_user);
+            let gallery = content_galleries(&app_state.pool, &[&content_id]).await?;
+            let response =
+                ContentModelResponse::filter_db(&mut content, &created_user).with_gallery(&gallery);
             let content_response = ApiResponseObject::new(serde_json::json!({"content": response}))
                 .map_err(|err| ApiResponseError::new(500, err.to_string(), None))?;
 
@@ -93,13 +194,9 @@ pub async fn create_contents_handler(
             ))
         }
         Err(err) => {
-            // Handle specific error case for duplicate entry
+            // Handle specific error case for duplicate entry, the slug was taken meanwhile
             if err.to_string().contains("Duplicate entry") {
-                Err(ApiResponseError::new(
-                    409,
-                    "Title already exists".to_string(),
-                    None,
-                ))
+                Err(duplicate_slug_error())
             } else {
                 // Return general internal server error for other errors
                 Err(ApiResponseError::new(
@@ -132,6 +229,9 @@ mod tests {
             exp: (now + expire).timestamp() as usize,
             iat: now.timestamp() as usize,
             id: user_id.to_owned(),
+            jti: uuid::Uuid::new_v4().to_string(),
+            sid: None,
+            impersonator: None,
         };
         let encoding_key = jsonwebtoken::EncodingKey::from_secret(CONFIG.jwt.secret.as_ref());
         jsonwebtoken::encode(&jsonwebtoken::Header::default(), &claims, &encoding_key).unwrap()
@@ -158,6 +258,7 @@ mod tests {
         let app = test::init_service(
             App::new()
                 .app_data(app_state.clone())
+                .app_data(web::Data::new(VariantQueue::new().0))
                 .service(web::scope("/admin").configure(routes::admin::content::config)),
         )
         .await;
@@ -172,6 +273,14 @@ mod tests {
             title: Word().fake(),
             summary: Some(Sentence(5..10).fake()),
             details: Some(Sentence(10..15).fake()),
+            publish_at: None,
+            unpublish_at: None,
+            slug: None,
+            meta_title: None,
+            meta_description: None,
+            canonical_url: None,
+            image_media_id: None,
+            gallery: None,
         };
 
         // Send POST request to create content
@@ -186,5 +295,119 @@ mod tests {
         // Verify the response
         assert_eq!(resp.status, 201);
         assert_eq!(resp.message, "Content Created");
+        let first = resp.data.expect("Missing response data").properties;
+        let first_slug = first["content"]["slug"].as_str().unwrap().to_string();
+
+        // Titles may repeat, the generated slug gets a suffix
+        let req = test::TestRequest::post()
+            .uri("/admin/contents/create")
+            .set_json(&create_content_data)
+            .insert_header(("Authorization", format!("Bearer {}", jwt)))
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 201);
+        let data = resp.data.expect("Missing response data").properties;
+        let slug = data["content"]["slug"].as_str().unwrap();
+        assert_ne!(slug, first_slug);
+        assert!(slug.starts_with(&crate::core::slug::slugify(&create_content_data.title)));
+    }
+
+    #[actix_web::test]
+    async fn test_create_scheduled_content() {
+        use crate::core::schedule::{
+            apply_scheduled_moves, SCHEDULED_PUBLISH, SCHEDULED_UNPUBLISH,
+        };
+
+        let app_state = create_test_app_state().await;
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .app_data(web::Data::new(VariantQueue::new().0))
+                .service(web::scope("/admin").configure(routes::admin::content::config)),
+        )
+        .await;
+
+        let user_id = "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e2b";
+        let jwt = generate_jwt(user_id);
+        let now = chrono::Utc::now();
+
+        let create = |publish_at, unpublish_at| {
+            let data = CreateContentSchema {
+                content_type: "page".to_string(),
+                title: Word().fake(),
+                summary: None,
+                details: None,
+                publish_at,
+                unpublish_at,
+                slug: None,
+                meta_title: None,
+                meta_description: None,
+                canonical_url: None,
+                image_media_id: None,
+                gallery: None,
+            };
+            test::TestRequest::post()
+                .uri("/admin/contents/create")
+                .set_json(&data)
+                .insert_header(("Authorization", format!("Bearer {}", jwt)))
+                .to_request()
+        };
+
+        // The content is archived after it is published
+        let resp = test::call_service(&app, create(Some(now), Some(now))).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+
+        // Both times have passed, e.g. while the server was down
+        let resp: ApiResponse = test::call_and_read_body_json(
+            &app,
+            create(
+                Some(now - chrono::Duration::hours(2)),
+                Some(now - chrono::Duration::hours(1)),
+            ),
+        )
+        .await;
+        assert_eq!(resp.status, 201);
+        let data = resp.data.expect("Missing response data").properties;
+        assert_eq!(data["content"]["recordState"], "draft");
+        let content_id = data["content"]["id"].as_str().unwrap().to_string();
+
+        let state = |pool: MySqlPool, content_id: String| async move {
+            sqlx::query_as::<_, (i8, Option<chrono::DateTime<chrono::Utc>>, i64)>(
+                "SELECT record_state, publish_at, (SELECT COUNT(*) FROM content_transitions WHERE content_id = ?) FROM contents WHERE id = ?",
+            )
+            .bind(&content_id)
+            .bind(&content_id)
+            .fetch_one(&pool)
+            .await
+            .expect("Failed to fetch content state")
+        };
+
+        apply_scheduled_moves(&app_state.pool, &SCHEDULED_PUBLISH, chrono::Utc::now())
+            .await
+            .expect("Failed to publish due contents");
+        assert_eq!(
+            state(app_state.pool.clone(), content_id.clone()).await,
+            (1, None, 1)
+        );
+
+        // Running again, as after a restart, does not publish it twice
+        apply_scheduled_moves(&app_state.pool, &SCHEDULED_PUBLISH, chrono::Utc::now())
+            .await
+            .expect("Failed to publish due contents");
+        assert_eq!(
+            state(app_state.pool.clone(), content_id.clone()).await,
+            (1, None, 1)
+        );
+
+        apply_scheduled_moves(&app_state.pool, &SCHEDULED_UNPUBLISH, chrono::Utc::now())
+            .await
+            .expect("Failed to unpublish due contents");
+        assert_eq!(
+            state(app_state.pool.clone(), content_id.clone()).await,
+            (3, None, 2)
+        );
     }
 }
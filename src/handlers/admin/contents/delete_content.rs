@@ -2,8 +2,15 @@ use actix_web::{delete, web};
 use uuid::Uuid;
 
 use crate::{
-    core::app_state::AppState, // Import application state AppState
-    schema::response::{api_response::ApiResponse, api_response_error::ApiResponseError}, // Import ApiResponse and ApiResponseError from response module
+    core::{
+        app_state::AppState, // Import application state AppState
+        policy::{authorize_content, ensure_unprotected, ContentAction},
+    },
+    middlewares::permission_middleware::RequirePermission,
+    model::content::ContentModel,
+    schema::response::{
+        admin::users::AuthUser, api_response::ApiResponse, api_response_error::ApiResponseError,
+    }, // Import ApiResponse and ApiResponseError from response module
 };
 
 // Endpoint metadata using `utoipa` attributes for API documentation
@@ -16,6 +23,7 @@ use crate::{
     ),
     responses(
         (status = 204, description= "Content Deleted", body = ApiResponse),       
+        (status = 403, description= "Not allowed to delete this content or content is protected", body = ApiResponseError),       
         (status = 404, description= "Content Not Found", body = ApiResponseError),       
         (status = 500, description= "Internal Server Error", body = ApiResponseError),       
     ),
@@ -23,23 +31,43 @@ use crate::{
        ("auth_token" = []) 
    )
 )]
-#[delete("/delete/{id}")] // HTTP DELETE method endpoint
+#[delete("/delete/{id}", wrap = "RequirePermission(\"contents.delete\")")] // HTTP DELETE method endpoint
 pub async fn delete_contents_handler(
     path: web::Path<Uuid>,
+    auth: AuthUser,
     app_state: web::Data<AppState>,
 ) -> Result<ApiResponse, ApiResponseError> {
     let content_id = path.into_inner().to_string();
 
-    // Execute SQL DELETE query for content ID
-    let query_result = sqlx::query!("DELETE FROM contents WHERE id = ?", content_id)
-        .execute(&app_state.pool)
-        .await;
+    // Load the content so the ownership policy can be checked, trashed contents are not found
+    let content = sqlx::query_as!(
+        ContentModel,
+        "SELECT * FROM contents WHERE id = ? AND deleted_at IS NULL",
+        content_id
+    )
+    .fetch_optional(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal server error: {}", e), None))?
+    .ok_or_else(|| {
+        ApiResponseError::new(404, format!("No data found with id {}", content_id), None)
+    })?;
+
+    authorize_content(&app_state.pool, &auth, &content, ContentAction::Delete).await?;
+    ensure_unprotected(&content)?;
+
+    // Soft delete the content, it can be restored or purged later
+    let query_result = sqlx::query(
+        "UPDATE contents SET deleted_at = CURRENT_TIMESTAMP WHERE id = ? AND deleted_at IS NULL",
+    )
+    .bind(&content_id)
+    .execute(&app_state.pool)
+    .await;
 
     // Match query result for handling success or error cases
     match query_result {
         Ok(result) => {
             if result.rows_affected() == 0 {
-                // If no rows affected by the DELETE query
+                // If no rows affected by the UPDATE query
                 return Err(ApiResponseError::new(
                     404,
                     format!("No data found with id {}", content_id),
@@ -84,6 +112,9 @@ mod tests {
             exp: (now + expire).timestamp() as usize,
             iat: now.timestamp() as usize,
             id: user_id.to_owned(),
+            jti: uuid::Uuid::new_v4().to_string(),
+            sid: None,
+            impersonator: None,
         };
         let encoding_key = jsonwebtoken::EncodingKey::from_secret(CONFIG.jwt.secret.as_ref());
         jsonwebtoken::encode(&jsonwebtoken::Header::default(), &claims, &encoding_key).unwrap()
@@ -123,6 +154,14 @@ mod tests {
             title: Paragraph(1..3).fake(),
             summary: Some(Sentence(5..10).fake()),
             details: Some(Sentence(10..15).fake()),
+            publish_at: None,
+            unpublish_at: None,
+            slug: None,
+            meta_title: None,
+            meta_description: None,
+            canonical_url: None,
+            image_media_id: None,
+            gallery: None,
         };
         let content_id = uuid::Uuid::new_v4().to_string();
         let _insert_result = sqlx::query(
@@ -152,19 +191,19 @@ mod tests {
         assert_eq!(resp.status, 204);
         assert_eq!(resp.message, "Content deleted");
 
-        // Verify the content was deleted from the database
+        // Verify the content was soft deleted, the row is kept
         let deleted_content = sqlx::query_as!(
             ContentModel,
             "SELECT * FROM contents WHERE id = ?",
             content_id
         )
-        .fetch_optional(&app_state.pool)
+        .fetch_one(&app_state.pool)
         .await
-        .expect("Failed to fetch deleted user");
+        .expect("Failed to fetch deleted content");
 
         assert!(
-            deleted_content.is_none(),
-            "Content should not exist in the database"
+            deleted_content.deleted_at.is_some(),
+            "Content should be marked as deleted"
         );
     }
 }
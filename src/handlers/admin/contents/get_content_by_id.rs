@@ -1,4 +1,5 @@
 use crate::{
+    middlewares::permission_middleware::RequirePermission,
     model::content::{ContentModel, ContentModelResponse}, // Import ContentModel and ContentModelResponse from content module
     schema::response::{
         api_response::ApiResponse, api_response_error::ApiResponseError,
@@ -8,7 +9,10 @@ use crate::{
 use actix_web::{get, web}; // Import get macro and web module from Actix Web
 use uuid::Uuid; // Import Uuid type
 
-use crate::{core::app_state::AppState, model::user::UserModel}; // Import AppState and UserModel from core and model modules
+use crate::{
+    core::{app_state::AppState, image_variant::content_image_variants, media::content_galleries},
+    model::user::UserModel,
+}; // Import AppState and UserModel from core and model modules
 
 // Endpoint metadata using `utoipa` attributes for API documentation
 #[utoipa::path(
@@ -27,7 +31,7 @@ use crate::{core::app_state::AppState, model::user::UserModel}; // Import AppSta
        ("auth_token" = []) // Security requirement: auth_token is required
    )
 )]
-#[get("/{id}")] // HTTP GET method endpoint
+#[get("/{id}", wrap = "RequirePermission(\"contents.view\")")] // HTTP GET method endpoint
 pub async fn get_content_by_id_handler(
     path: web::Path<Uuid>,
     data: web::Data<AppState>,
@@ -61,10 +65,16 @@ pub async fn get_content_by_id_handler(
                 // Return internal server error if user fetch fails
             })?;
 
+            // Fetch the variants of the content image
+            let variants = content_image_variants(&data.pool, &[&content_id]).await?;
+            let gallery = content_galleries(&data.pool, &[&content_id]).await?;
+
             // Prepare response object with content and creator user details
-            let content_response = ApiResponseObject::new(
-                serde_json::json!({"content": ContentModelResponse::filter_db(&mut content, &created_user)}),
-            )
+            let content_response = ApiResponseObject::new(serde_json::json!({
+                "content": ContentModelResponse::filter_db(&mut content, &created_user)
+                    .with_image_variants(&variants)
+                    .with_gallery(&gallery)
+            }))
             .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
 
             // Return success response with content details
@@ -112,6 +122,9 @@ mod tests {
             exp: (now + expire).timestamp() as usize,
             iat: now.timestamp() as usize,
             id: "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e2b".to_owned(),
+            jti: uuid::Uuid::new_v4().to_string(),
+            sid: None,
+            impersonator: None,
         };
         let encoding_key = jsonwebtoken::EncodingKey::from_secret(CONFIG.jwt.secret.as_ref());
         jsonwebtoken::encode(&jsonwebtoken::Header::default(), &claims, &encoding_key).unwrap()
@@ -150,6 +163,14 @@ mod tests {
             title: Word().fake(),
             summary: Some(Sentence(5..10).fake()),
             details: Some(Sentence(10..15).fake()),
+            publish_at: None,
+            unpublish_at: None,
+            slug: None,
+            meta_title: None,
+            meta_description: None,
+            canonical_url: None,
+            image_media_id: None,
+            gallery: None,
         };
         let content_id = uuid::Uuid::new_v4().to_string();
         let _insert_result = sqlx::query(
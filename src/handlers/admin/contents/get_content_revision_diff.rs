@@ -0,0 +1,139 @@
+use crate::{
+    core::{
+        app_state::AppState,
+        revision::{diff_revisions, find_revision},
+    },
+    middlewares::permission_middleware::RequirePermission,
+    schema::{
+        admin::content::RevisionDiffOptions,
+        response::{
+            api_response::ApiResponse, api_response_error::ApiResponseError,
+            api_response_object::ApiResponseObject,
+        },
+    },
+};
+use actix_web::{get, web};
+use uuid::Uuid;
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    get,
+    path = "/admin/contents/{id}/revisions/diff",
+    tag = "Admin: Contents Endpoint",
+    params(
+        ("id" = Uuid, Path, description = "UUID of the content"),
+        RevisionDiffOptions
+    ),
+    responses(
+        (status = 200, description= "Fields changed between the two revisions", body = ApiResponse),
+        (status = 404, description= "Revision not found", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[get("/{id}/revisions/diff", wrap = "RequirePermission(\"contents.view\")")]
+pub async fn get_content_revision_diff_handler(
+    id: web::Path<Uuid>,
+    opts: web::Query<RevisionDiffOptions>,
+    app_state: web::Data<AppState>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let content_id = id.into_inner().to_string();
+
+    let from = find_revision(&app_state.pool, &content_id, opts.from).await?;
+    let to = find_revision(&app_state.pool, &content_id, opts.to).await?;
+
+    let response = ApiResponseObject::new(serde_json::json!({
+        "from": from.revision,
+        "to": to.revision,
+        "changes": diff_revisions(&from, &to),
+    }))
+    .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        200,
+        "Get Content Revision Diff".to_string(),
+        Some(response),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::{
+            image_variant::variant_queue::VariantQueue,
+            utils::test_utils::{create_test_app_state, generate_test_jwt},
+        },
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::header, http::StatusCode, test, web, App};
+    use fake::{faker::lorem::en::Sentence, Fake};
+    use serde_json::json;
+
+    #[actix_web::test]
+    async fn test_get_content_revision_diff_handler() {
+        let app_state = create_test_app_state().await;
+
+        // Create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .app_data(web::Data::new(VariantQueue::new().0))
+                .service(web::scope("/admin").configure(routes::admin::content::config)),
+        )
+        .await;
+
+        let title: String = Sentence(3..6).fake();
+        let req = test::TestRequest::post()
+            .uri("/admin/contents/create")
+            .insert_header((
+                header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .set_json(json!({"content_type": "page", "title": title}))
+            .to_request();
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+        let data = resp.data.expect("Missing response data").properties;
+        let content_id = data["content"]["id"].as_str().unwrap().to_string();
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/admin/contents/update/{}", content_id))
+            .insert_header((
+                header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .set_json(json!({"summary": "New summary"}))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let diff = |from: i32, to: i32| {
+            test::TestRequest::get()
+                .uri(&format!(
+                    "/admin/contents/{}/revisions/diff?from={}&to={}",
+                    content_id, from, to
+                ))
+                .insert_header((
+                    header::AUTHORIZATION,
+                    format!("Bearer {}", generate_test_jwt()),
+                ))
+                .to_request()
+        };
+
+        // Only the summary changed
+        let resp: ApiResponse = test::call_and_read_body_json(&app, diff(1, 2)).await;
+
+        assert_eq!(resp.status, 200);
+        let data = resp.data.expect("Missing response data").properties;
+        assert_eq!(
+            data["changes"],
+            json!([{"field": "summary", "from": null, "to": "New summary"}])
+        );
+
+        let resp = test::call_service(&app, diff(1, 3)).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{
+    core::{app_state::AppState, workflow::content_transitions},
+    middlewares::permission_middleware::RequirePermission,
+    model::content_transition::ContentTransitionResponse,
+    schema::response::{
+        api_response::ApiResponse, api_response_error::ApiResponseError,
+        api_response_object::ApiResponseObject,
+    },
+};
+use actix_web::{get, web};
+use uuid::Uuid;
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    get,
+    path = "/admin/contents/{id}/transitions",
+    tag = "Admin: Contents Endpoint",
+    params(
+        ("id" = Uuid, Path, description = "UUID of the content"),
+    ),
+    responses(
+        (status = 200, description= "Workflow history of the content, latest first", body = ApiResponse),
+        (status = 404, description= "Content not found", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[get("/{id}/transitions", wrap = "RequirePermission(\"contents.view\")")]
+pub async fn get_content_transitions_handler(
+    id: web::Path<Uuid>,
+    app_state: web::Data<AppState>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let content_id = id.into_inner().to_string();
+
+    let content_exists = sqlx::query_scalar!(
+        "SELECT EXISTS(SELECT 1 FROM contents WHERE id = ?) AS content_exists",
+        content_id
+    )
+    .fetch_one(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    if content_exists == 0 {
+        return Err(ApiResponseError::new(
+            404,
+            "Content not found".to_string(),
+            None,
+        ));
+    }
+
+    let transitions = content_transitions(&app_state.pool, &content_id)
+        .await?
+        .iter()
+        .map(ContentTransitionResponse::filter_db)
+        .collect::<Vec<ContentTransitionResponse>>();
+
+    let response = ApiResponseObject::new(serde_json::json!({"transitions": transitions}))
+        .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        200,
+        "Get Content Transitions".to_string(),
+        Some(response),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::utils::test_utils::{create_test_app_state, generate_test_jwt},
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::header, http::StatusCode, test, web, App};
+
+    #[actix_web::test]
+    async fn test_get_content_transitions_handler() {
+        let app_state = create_test_app_state().await;
+
+        // Create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .service(web::scope("/admin").configure(routes::admin::content::config)),
+        )
+        .await;
+
+        // Seeded content
+        let req = test::TestRequest::get()
+            .uri("/admin/contents/1f34e48a-d5b1-4bfa-9f10-9345d0a66a1d/transitions")
+            .insert_header((
+                header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 200);
+        let data = resp.data.expect("Missing response data").properties;
+        assert!(data["transitions"].is_array());
+
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "/admin/contents/{}/transitions",
+                uuid::Uuid::new_v4()
+            ))
+            .insert_header((
+                header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+}
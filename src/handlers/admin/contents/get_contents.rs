@@ -1,5 +1,9 @@
 use crate::{
-    core::{app_state::AppState, utils::export_to_csv::export_to_csv},
+    core::{
+        app_state::AppState, image_variant::content_image_variants, soft_delete::trashed_condition,
+        utils::export_to_csv::export_to_csv, workflow::parse_state,
+    },
+    middlewares::permission_middleware::RequirePermission,
     model::{
         content::{ContentModel, ContentModelResponse},
         user::UserModel,
@@ -22,6 +26,7 @@ use actix_web::{get, web, HttpResponse, Responder};
     ),
     responses(
         (status = 200, description= "Get All Contentes", body = ApiResponse), // Response metadata for successful retrieval
+        (status = 400, description= "Validation Error", body = ApiResponseError), // Response metadata for an unknown state filter
         (status = 401, description= "Unauthorized", body = ApiResponseError), // Response metadata for unauthorized access
         (status = 404, description= "No Data Found", body = ApiResponseError), // Response metadata for no data found
         (status = 500, description= "Internal Server Error", body = ApiResponseError), // Response metadata for internal server error
@@ -30,7 +35,7 @@ use actix_web::{get, web, HttpResponse, Responder};
        ("auth_token" = []) // Security requirement: auth_token is required
    )
 )]
-#[get("")] // HTTP GET method endpoint
+#[get("", wrap = "RequirePermission(\"contents.view\")")] // HTTP GET method endpoint
 pub async fn get_contents_handler(
     opts: web::Query<ContentsFilterOptions>, // Query parameter: ContentsFilterOptions for filtering contents
     app_state: web::Data<AppState>, // Shared application state containing database connection pool
@@ -42,6 +47,11 @@ pub async fn get_contents_handler(
     let mut query = "SELECT * FROM contents".to_string(); // Initialize SQL query string to fetch contents
     let mut conditions = Vec::new(); // Initialize vector to store query conditions
 
+    // Soft deleted contents are hidden unless asked for
+    if let Some(condition) = trashed_condition(opts.with_trashed, opts.only_trashed) {
+        conditions.push(condition.to_string());
+    }
+
     if let Some(ref content_type) = opts.content_type {
         // Check if content_type filter is provided
         conditions.push(format!("content_type = '{}'", content_type)); // Add content_type filter condition to vector
@@ -50,6 +60,11 @@ pub async fn get_contents_handler(
         // Check if title filter is provided
         conditions.push(format!("title LIKE '%{}%'", title)); // Add title filter condition to vector
     }
+    if let Some(ref state) = opts.state {
+        // The state is parsed, only its numeric value reaches the query
+        let state = parse_state(state)?;
+        conditions.push(format!("record_state = {}", state.to_i8()));
+    }
 
     if !conditions.is_empty() {
         // If there are any conditions in the vector
@@ -103,6 +118,12 @@ pub async fn get_contents_handler(
         .await
         .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?; // Handle total count query error
 
+    let content_ids = contents
+        .iter()
+        .map(|content| content.id.as_str())
+        .collect::<Vec<&str>>();
+    let variants = content_image_variants(&app_state.pool, &content_ids).await?; // Fetch the image variants of the page at once
+
     let mut content_response = Vec::new(); // Initialize vector to store content responses
 
     for mut content in contents {
@@ -117,7 +138,8 @@ pub async fn get_contents_handler(
         .await
         .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {:?}", e), None))?; // Handle user fetch error
 
-        let response = ContentModelResponse::filter_db(&mut content, &created_user); // Filter content and creator user details
+        let response = ContentModelResponse::filter_db(&mut content, &created_user)
+            .with_image_variants(&variants); // Filter content and creator user details
         content_response.push(response); // Push filtered response to content_response vector
     }
 
@@ -168,6 +190,9 @@ mod tests {
             exp: (now + expire).timestamp() as usize,
             iat: now.timestamp() as usize,
             id: "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e2b".to_owned(),
+            jti: uuid::Uuid::new_v4().to_string(),
+            sid: None,
+            impersonator: None,
         };
         let encoding_key = jsonwebtoken::EncodingKey::from_secret(CONFIG.jwt.secret.as_ref());
         jsonwebtoken::encode(&jsonwebtoken::Header::default(), &claims, &encoding_key).unwrap()
@@ -206,6 +231,14 @@ mod tests {
             title: Word().fake(),
             summary: Some(Sentence(5..10).fake()),
             details: Some(Sentence(10..15).fake()),
+            publish_at: None,
+            unpublish_at: None,
+            slug: None,
+            meta_title: None,
+            meta_description: None,
+            canonical_url: None,
+            image_media_id: None,
+            gallery: None,
         };
         let content_id = uuid::Uuid::new_v4().to_string();
         let _insert_result = sqlx::query(
@@ -233,5 +266,30 @@ mod tests {
 
         assert_eq!(resp.status, 200);
         assert_eq!(resp.message, "Get All Contents");
+
+        // The seeded contents are published
+        let req = test::TestRequest::get()
+            .uri("/admin/contents?state=published")
+            .insert_header((
+                actix_web::http::header::AUTHORIZATION,
+                format!("Bearer {}", jwt),
+            ))
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 200);
+
+        let req = test::TestRequest::get()
+            .uri("/admin/contents?state=deleted")
+            .insert_header((
+                actix_web::http::header::AUTHORIZATION,
+                format!("Bearer {}", jwt),
+            ))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
     }
 }
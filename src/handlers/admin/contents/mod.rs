@@ -1,5 +1,13 @@
 pub mod create_contents;
 pub mod delete_content;
 pub mod get_content_by_id;
+pub mod get_content_revision_diff;
+pub mod get_content_revisions;
+pub mod get_content_transitions;
 pub mod get_contents;
+pub mod purge_content;
+pub mod restore_content;
+pub mod rollback_content;
+pub mod transition_content;
 pub mod update_contents;
+pub mod upload_content_image;
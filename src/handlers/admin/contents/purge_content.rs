@@ -0,0 +1,145 @@
+use actix_web::{delete, web};
+use uuid::Uuid;
+
+use crate::{
+    core::{app_state::AppState, policy::ensure_unprotected},
+    middlewares::permission_middleware::RequirePermission,
+    model::content::ContentModel,
+    schema::response::{api_response::ApiResponse, api_response_error::ApiResponseError},
+};
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    delete,
+    path = "/admin/contents/purge/{id}",
+    tag = "Admin: Contents Endpoint",
+    params(
+        ("id" = Uuid, Path, description = "UUID of the deleted content"),
+    ),
+    responses(
+        (status = 204, description= "Content Purged", body = ApiResponse),
+        (status = 403, description= "Missing the contents.purge permission or content is protected", body = ApiResponseError),
+        (status = 404, description= "Content Not Found", body = ApiResponseError),
+        (status = 409, description= "Content Is Not Deleted", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[delete("/purge/{id}", wrap = "RequirePermission(\"contents.purge\")")]
+pub async fn purge_content_handler(
+    path: web::Path<Uuid>,
+    app_state: web::Data<AppState>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let content_id = path.into_inner().to_string();
+
+    let content = sqlx::query_as!(
+        ContentModel,
+        "SELECT * FROM contents WHERE id = ?",
+        content_id
+    )
+    .fetch_optional(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal server error: {}", e), None))?
+    .ok_or_else(|| {
+        ApiResponseError::new(404, format!("No data found with id {}", content_id), None)
+    })?;
+
+    ensure_unprotected(&content)?;
+
+    // Only contents in the trash can be purged
+    if content.deleted_at.is_none() {
+        return Err(ApiResponseError::new(
+            409,
+            "Content must be deleted before it is purged".to_string(),
+            None,
+        ));
+    }
+
+    sqlx::query("DELETE FROM contents WHERE id = ? AND deleted_at IS NOT NULL")
+        .bind(&content_id)
+        .execute(&app_state.pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal server error: {}", e), None))?;
+
+    Ok(ApiResponse::new(204, "Content purged".to_string(), None))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::utils::test_utils::{
+            create_test_app_state, generate_jwt, generate_test_jwt, USER_ID,
+        },
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::header, http::StatusCode, test, web, App};
+    use fake::{faker::lorem::en::Sentence, Fake};
+
+    // Seeded moderator, who is not granted contents.purge
+    const MODERATOR_ID: &str = "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e24";
+
+    #[actix_web::test]
+    async fn test_purge_content_handler() {
+        let app_state = create_test_app_state().await;
+
+        // Create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .service(web::scope("/admin").configure(routes::admin::content::config)),
+        )
+        .await;
+
+        let content_id = uuid::Uuid::new_v4().to_string();
+        let title: String = Sentence(3..6).fake();
+        sqlx::query(
+            "INSERT INTO contents (id, title, content_type, created_by) VALUES (?, ?, ?, ?)",
+        )
+        .bind(&content_id)
+        .bind(&title)
+        .bind("page")
+        .bind(USER_ID)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test content");
+
+        let purge = |jwt: String| {
+            test::TestRequest::delete()
+                .uri(&format!("/admin/contents/purge/{}", content_id))
+                .insert_header((header::AUTHORIZATION, format!("Bearer {}", jwt)))
+                .to_request()
+        };
+
+        // Contents that are not deleted cannot be purged
+        let resp = test::call_service(&app, purge(generate_test_jwt())).await;
+
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        sqlx::query("UPDATE contents SET deleted_at = CURRENT_TIMESTAMP WHERE id = ?")
+            .bind(&content_id)
+            .execute(&app_state.pool)
+            .await
+            .expect("Failed to delete test content");
+
+        // Purging is kept for admins
+        let resp = test::call_service(&app, purge(generate_jwt(MODERATOR_ID))).await;
+
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        let resp: ApiResponse =
+            test::call_and_read_body_json(&app, purge(generate_test_jwt())).await;
+
+        assert_eq!(resp.status, 204);
+        assert_eq!(resp.message, "Content purged");
+
+        let exists = sqlx::query_scalar!("SELECT COUNT(*) FROM contents WHERE id = ?", content_id)
+            .fetch_one(&app_state.pool)
+            .await
+            .expect("Failed to count contents");
+
+        assert_eq!(exists, 0);
+    }
+}
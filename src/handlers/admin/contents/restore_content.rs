@@ -0,0 +1,174 @@
+use crate::{
+    core::{
+        app_state::AppState,
+        image_variant::content_image_variants,
+        media::content_galleries,
+        policy::{authorize_content, ContentAction},
+    },
+    middlewares::permission_middleware::RequirePermission,
+    model::{
+        content::{ContentModel, ContentModelResponse},
+        user::UserModel,
+    },
+    schema::response::{
+        admin::users::AuthUser, api_response::ApiResponse, api_response_error::ApiResponseError,
+        api_response_object::ApiResponseObject,
+    },
+};
+use actix_web::{post, web};
+use serde_json::json;
+use uuid::Uuid;
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    post,
+    path = "/admin/contents/restore/{id}",
+    tag = "Admin: Contents Endpoint",
+    params(
+        ("id" = Uuid, Path, description = "UUID of the deleted content"),
+    ),
+    responses(
+        (status = 200, description= "Content Restored", body = ApiResponse),
+        (status = 403, description= "Not allowed to restore this content", body = ApiResponseError),
+        (status = 404, description= "Deleted Content Not Found", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[post("/restore/{id}", wrap = "RequirePermission(\"contents.delete\")")]
+pub async fn restore_content_handler(
+    path: web::Path<Uuid>,
+    auth: AuthUser,
+    app_state: web::Data<AppState>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let content_id = path.into_inner().to_string();
+
+    // Only contents in the trash can be restored
+    let content = sqlx::query_as!(
+        ContentModel,
+        "SELECT * FROM contents WHERE id = ? AND deleted_at IS NOT NULL",
+        content_id
+    )
+    .fetch_optional(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal server error: {}", e), None))?
+    .ok_or_else(|| {
+        ApiResponseError::new(
+            404,
+            format!("No deleted content found with id {}", content_id),
+            None,
+        )
+    })?;
+
+    authorize_content(&app_state.pool, &auth, &content, ContentAction::Restore).await?;
+
+    sqlx::query("UPDATE contents SET deleted_at = NULL WHERE id = ?")
+        .bind(&content_id)
+        .execute(&app_state.pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal server error: {}", e), None))?;
+
+    let mut restored_content = sqlx::query_as!(
+        ContentModel,
+        "SELECT * FROM contents WHERE id = ?",
+        content_id
+    )
+    .fetch_one(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal server error: {}", e), None))?;
+
+    let created_user = sqlx::query_as!(
+        UserModel,
+        "SELECT * FROM users WHERE id = ?",
+        restored_content.created_by
+    )
+    .fetch_one(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal server error: {}", e), None))?;
+
+    let variants = content_image_variants(&app_state.pool, &[&content_id]).await?;
+    let gallery = content_galleries(&app_state.pool, &[&content_id]).await?;
+    let response = ContentModelResponse::filter_db(&mut restored_content, &created_user)
+        .with_image_variants(&variants)
+        .with_gallery(&gallery);
+    let content_response = ApiResponseObject::new(json!({"content": response}))
+        .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        200,
+        "Content restored".to_string(),
+        Some(content_response),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::utils::test_utils::{create_test_app_state, generate_test_jwt, USER_ID},
+        model::content::ContentModel,
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::header, http::StatusCode, test, web, App};
+    use fake::{faker::lorem::en::Sentence, Fake};
+
+    #[actix_web::test]
+    async fn test_restore_content_handler() {
+        let app_state = create_test_app_state().await;
+
+        // Create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .service(web::scope("/admin").configure(routes::admin::content::config)),
+        )
+        .await;
+
+        // Insert a soft deleted content
+        let content_id = uuid::Uuid::new_v4().to_string();
+        let title: String = Sentence(3..6).fake();
+        sqlx::query(
+            "INSERT INTO contents (id, title, content_type, created_by, deleted_at) VALUES (?, ?, ?, ?, CURRENT_TIMESTAMP)",
+        )
+        .bind(&content_id)
+        .bind(&title)
+        .bind("page")
+        .bind(USER_ID)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test content");
+
+        let restore = || {
+            test::TestRequest::post()
+                .uri(&format!("/admin/contents/restore/{}", content_id))
+                .insert_header((
+                    header::AUTHORIZATION,
+                    format!("Bearer {}", generate_test_jwt()),
+                ))
+                .to_request()
+        };
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, restore()).await;
+
+        assert_eq!(resp.status, 200);
+        assert_eq!(resp.message, "Content restored");
+
+        let content = sqlx::query_as!(
+            ContentModel,
+            "SELECT * FROM contents WHERE id = ?",
+            content_id
+        )
+        .fetch_one(&app_state.pool)
+        .await
+        .expect("Failed to fetch restored content");
+
+        assert!(content.deleted_at.is_none());
+
+        // Contents that are not deleted cannot be restored
+        let resp = test::call_service(&app, restore()).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,214 @@
+use crate::{
+    core::{
+        app_state::AppState,
+        image_variant::content_image_variants,
+        media::content_galleries,
+        policy::{authorize_content, ensure_unprotected, ContentAction},
+        revision::{find_revision, record_revision},
+    },
+    middlewares::permission_middleware::RequirePermission,
+    model::{
+        content::{ContentModel, ContentModelResponse},
+        user::UserModel,
+    },
+    schema::response::{
+        admin::users::AuthUser, api_response::ApiResponse, api_response_error::ApiResponseError,
+        api_response_object::ApiResponseObject,
+    },
+};
+use actix_web::{post, web};
+use log::info;
+use serde_json::json;
+use uuid::Uuid;
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    post,
+    path = "/admin/contents/{id}/revisions/{revision}/rollback",
+    tag = "Admin: Contents Endpoint",
+    params(
+        ("id" = Uuid, Path, description = "UUID of the content"),
+        ("revision" = i32, Path, description = "Revision to roll back to"),
+    ),
+    responses(
+        (status = 200, description= "Content rolled back, as a new revision", body = ApiResponse),
+        (status = 403, description= "Not allowed to update this content or content is protected", body = ApiResponseError),
+        (status = 404, description= "Content or revision not found", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[post(
+    "/{id}/revisions/{revision}/rollback",
+    wrap = "RequirePermission(\"contents.update\")"
+)]
+pub async fn rollback_content_handler(
+    path: web::Path<(Uuid, i32)>,
+    auth: AuthUser,
+    app_state: web::Data<AppState>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let (content_id, revision) = path.into_inner();
+    let content_id = content_id.to_string();
+
+    let content = sqlx::query_as!(
+        ContentModel,
+        "SELECT * FROM contents WHERE id = ? AND deleted_at IS NULL",
+        content_id
+    )
+    .fetch_optional(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?
+    .ok_or_else(|| ApiResponseError::new(404, "Content not found".to_string(), None))?;
+
+    authorize_content(&app_state.pool, &auth, &content, ContentAction::Update).await?;
+    ensure_unprotected(&content)?;
+
+    let target = find_revision(&app_state.pool, &content_id, revision).await?;
+
+    // The rollback is an update like any other, it is kept as a new revision
+    let mut tx =
+        app_state.pool.begin().await.map_err(|e| {
+            ApiResponseError::new(500, format!("Internal Server Error: {}", e), None)
+        })?;
+
+    sqlx::query(
+        "UPDATE contents SET title = ?, summary = ?, details = ?, updated_at = CURRENT_TIMESTAMP WHERE id = ?",
+    )
+    .bind(&target.title)
+    .bind(target.summary.as_deref())
+    .bind(target.details.as_deref())
+    .bind(&content_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    let new_revision = record_revision(&mut tx, &content_id, &auth.id)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+    info!(
+        "Content {} rolled back to revision {} by {}",
+        content_id, revision, auth.id
+    );
+
+    let mut updated_content = sqlx::query_as!(
+        ContentModel,
+        "SELECT * FROM contents WHERE id = ?",
+        content_id
+    )
+    .fetch_one(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    let created_user = sqlx::query_as!(
+        UserModel,
+        "SELECT * FROM users WHERE id = ?",
+        updated_content.created_by
+    )
+    .fetch_one(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    let variants = content_image_variants(&app_state.pool, &[&content_id]).await?;
+    let gallery = content_galleries(&app_state.pool, &[&content_id]).await?;
+    let response = ContentModelResponse::filter_db(&mut updated_content, &created_user)
+        .with_image_variants(&variants)
+        .with_gallery(&gallery);
+    let content_response =
+        ApiResponseObject::new(json!({"content": response, "revision": new_revision}))
+            .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        200,
+        "Content Rolled Back".to_string(),
+        Some(content_response),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::{
+            image_variant::variant_queue::VariantQueue,
+            utils::test_utils::{create_test_app_state, generate_jwt, generate_test_jwt},
+        },
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::header, http::StatusCode, test, web, App};
+    use fake::{faker::lorem::en::Sentence, Fake};
+    use serde_json::json;
+
+    // Seeded moderator, who did not create the test content
+    const MODERATOR_ID: &str = "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e24";
+
+    #[actix_web::test]
+    async fn test_rollback_content_handler() {
+        let app_state = create_test_app_state().await;
+
+        // Create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .app_data(web::Data::new(VariantQueue::new().0))
+                .service(web::scope("/admin").configure(routes::admin::content::config)),
+        )
+        .await;
+
+        let title: String = Sentence(3..6).fake();
+        let req = test::TestRequest::post()
+            .uri("/admin/contents/create")
+            .insert_header((
+                header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .set_json(json!({"content_type": "page", "title": title, "summary": "First"}))
+            .to_request();
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+        let data = resp.data.expect("Missing response data").properties;
+        let content_id = data["content"]["id"].as_str().unwrap().to_string();
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/admin/contents/update/{}", content_id))
+            .insert_header((
+                header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .set_json(json!({"summary": "Second"}))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let rollback = |jwt: String, revision: i32| {
+            test::TestRequest::post()
+                .uri(&format!(
+                    "/admin/contents/{}/revisions/{}/rollback",
+                    content_id, revision
+                ))
+                .insert_header((header::AUTHORIZATION, format!("Bearer {}", jwt)))
+                .to_request()
+        };
+
+        // The ownership policy applies as for updates
+        let resp = test::call_service(&app, rollback(generate_jwt(MODERATOR_ID), 1)).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        let resp = test::call_service(&app, rollback(generate_test_jwt(), 9)).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        // Rolling back creates the third revision with the fields of the first
+        let resp: ApiResponse =
+            test::call_and_read_body_json(&app, rollback(generate_test_jwt(), 1)).await;
+
+        assert_eq!(resp.status, 200);
+        let data = resp.data.expect("Missing response data").properties;
+        assert_eq!(data["revision"], 3);
+        assert_eq!(data["content"]["summary"], "First");
+        assert_eq!(data["content"]["title"], title);
+    }
+}
@@ -0,0 +1,411 @@
+use crate::{
+    core::{
+        app_state::AppState,
+        enums::ContentState,
+        image_variant::content_image_variants,
+        media::content_galleries,
+        permission::has_permission,
+        policy::{authorize_content, ensure_unprotected, ContentAction},
+        schedule::{SCHEDULED_PUBLISH, SCHEDULED_UNPUBLISH},
+        workflow::{find_transition, parse_state, record_transition, validate_note},
+    },
+    model::{
+        content::{ContentModel, ContentModelResponse},
+        user::UserModel,
+    },
+    schema::admin::content::TransitionContentSchema,
+    schema::response::{
+        admin::users::AuthUser, api_response::ApiResponse, api_response_error::ApiResponseError,
+        api_response_object::ApiResponseObject,
+    },
+};
+use actix_web::{post, web};
+use chrono::Utc;
+use log::info;
+use serde_json::json;
+use uuid::Uuid;
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    post,
+    path = "/admin/contents/{id}/transition",
+    tag = "Admin: Contents Endpoint",
+    params(
+        ("id" = Uuid, Path, description = "UUID of the content"),
+    ),
+    request_body(content = TransitionContentSchema, description = "State to move the content to: draft, in_review, published or archived", example = json!({"state": "in_review", "note": "Ready for review"})),
+    responses(
+        (status = 200, description= "Content state changed", body = ApiResponse),
+        (status = 400, description= "Validation Error", body = ApiResponseError),
+        (status = 403, description= "Missing the permission of the transition, not the author or content is protected", body = ApiResponseError),
+        (status = 404, description= "Content not found", body = ApiResponseError),
+        (status = 409, description= "Transition not allowed from the current state", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[post("/{id}/transition")]
+pub async fn transition_content_handler(
+    id: web::Path<Uuid>,
+    data: web::Json<TransitionContentSchema>,
+    auth: AuthUser,
+    app_state: web::Data<AppState>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let content_id = id.into_inner().to_string();
+    let to = parse_state(&data.state)?;
+    let note = validate_note(data.note.as_deref())?;
+
+    let content = sqlx::query_as!(
+        ContentModel,
+        "SELECT * FROM contents WHERE id = ? AND deleted_at IS NULL",
+        content_id
+    )
+    .fetch_optional(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?
+    .ok_or_else(|| ApiResponseError::new(404, "Content not found".to_string(), None))?;
+
+    let from = ContentState::from_i8(content.record_state).ok_or_else(|| {
+        ApiResponseError::new(
+            409,
+            format!("Content has an unknown state {}", content.record_state),
+            None,
+        )
+    })?;
+    let transition = find_transition(from, to)?;
+
+    // Each transition has its own permission, e.g. only reviewers publish
+    let allowed = has_permission(&app_state.pool, &auth.id, transition.permission)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+    if !allowed {
+        return Err(ApiResponseError::new(
+            403,
+            format!("Forbidden: missing permission {}", transition.permission),
+            None,
+        ));
+    }
+    if transition.by_author {
+        authorize_content(&app_state.pool, &auth, &content, ContentAction::Update).await?;
+    }
+    ensure_unprotected(&content)?;
+
+    let mut tx =
+        app_state.pool.begin().await.map_err(|e| {
+            ApiResponseError::new(500, format!("Internal Server Error: {}", e), None)
+        })?;
+
+    // A move made by hand replaces the scheduled one, so the time is not applied later,
+    // e.g. after the content is reopened
+    let mut cleared_schedule: String = [SCHEDULED_PUBLISH, SCHEDULED_UNPUBLISH]
+        .iter()
+        .filter(|scheduled| scheduled.to == to)
+        .map(|scheduled| format!("{} = NULL, ", scheduled.column))
+        .collect();
+    // An archive time that has already passed would archive the content just published
+    // on the next tick of the scheduler
+    let drops_due_unpublish = to == SCHEDULED_PUBLISH.to;
+    if drops_due_unpublish {
+        cleared_schedule.push_str(&format!(
+            "{column} = IF({column} <= ?, NULL, {column}), ",
+            column = SCHEDULED_UNPUBLISH.column
+        ));
+    }
+
+    // The current state is part of the condition, a concurrent transition wins the race
+    let update_query = format!(
+        "UPDATE contents SET record_state = ?, {}updated_at = CURRENT_TIMESTAMP WHERE id = ? AND record_state = ?",
+        cleared_schedule
+    );
+    let mut update = sqlx::query(&update_query).bind(to.to_i8());
+    if drops_due_unpublish {
+        update = update.bind(Utc::now());
+    }
+    let update_result = update
+        .bind(&content_id)
+        .bind(from.to_i8())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    if update_result.rows_affected() == 0 {
+        return Err(ApiResponseError::new(
+            409,
+            "Content state has changed, try again".to_string(),
+            None,
+        ));
+    }
+
+    record_transition(&mut tx, &content_id, transition, &auth.id, note.as_deref())
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+    info!(
+        "Content {} moved from {} to {} by {}",
+        content_id,
+        from.to_str(),
+        to.to_str(),
+        auth.id
+    );
+
+    let mut updated_content = sqlx::query_as!(
+        ContentModel,
+        "SELECT * FROM contents WHERE id = ?",
+        content_id
+    )
+    .fetch_one(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    let created_user = sqlx::query_as!(
+        UserModel,
+        "SELECT * FROM users WHERE id = ?",
+        updated_content.created_by
+    )
+    .fetch_one(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    let variants = content_image_variants(&app_state.pool, &[&content_id]).await?;
+    let gallery = content_galleries(&app_state.pool, &[&content_id]).await?;
+    let response = ContentModelResponse::filter_db(&mut updated_content, &created_user)
+        .with_image_variants(&variants)
+        .with_gallery(&gallery);
+    let content_response = ApiResponseObject::new(json!({"content": response}))
+        .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        200,
+        "Content state changed".to_string(),
+        Some(content_response),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::schedule::{apply_scheduled_moves, SCHEDULED_PUBLISH, SCHEDULED_UNPUBLISH},
+        core::utils::test_utils::{
+            create_test_app_state, generate_jwt, generate_test_jwt, USER_ID,
+        },
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::header, http::StatusCode, test, web, App};
+    use fake::{faker::lorem::en::Sentence, Fake};
+    use serde_json::json;
+
+    // Seeded moderator, granted contents.publish
+    const MODERATOR_ID: &str = "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e24";
+
+    #[actix_web::test]
+    async fn test_transition_content_handler() {
+        let app_state = create_test_app_state().await;
+
+        // Create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .service(web::scope("/admin").configure(routes::admin::content::config)),
+        )
+        .await;
+
+        // New contents start as drafts
+        let content_id = uuid::Uuid::new_v4().to_string();
+        let title: String = Sentence(3..6).fake();
+        sqlx::query(
+            "INSERT INTO contents (id, title, content_type, created_by) VALUES (?, ?, ?, ?)",
+        )
+        .bind(&content_id)
+        .bind(&title)
+        .bind("page")
+        .bind(USER_ID)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test content");
+
+        let transition = |jwt: String, data: serde_json::Value| {
+            test::TestRequest::post()
+                .uri(&format!("/admin/contents/{}/transition", content_id))
+                .insert_header((header::AUTHORIZATION, format!("Bearer {}", jwt)))
+                .set_json(data)
+                .to_request()
+        };
+
+        // Drafts are reviewed before they are published
+        let resp = test::call_service(
+            &app,
+            transition(generate_test_jwt(), json!({"state": "published"})),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        // Only the author submits the draft for review
+        let resp = test::call_service(
+            &app,
+            transition(generate_jwt(MODERATOR_ID), json!({"state": "in_review"})),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        let resp: ApiResponse = test::call_and_read_body_json(
+            &app,
+            transition(generate_test_jwt(), json!({"state": "in_review"})),
+        )
+        .await;
+        assert_eq!(resp.status, 200);
+
+        // A reviewer publishes it
+        let resp: ApiResponse = test::call_and_read_body_json(
+            &app,
+            transition(
+                generate_jwt(MODERATOR_ID),
+                json!({"state": "published", "note": "Looks good"}),
+            ),
+        )
+        .await;
+        assert_eq!(resp.status, 200);
+        let data = resp.data.expect("Missing response data").properties;
+        assert_eq!(data["content"]["recordState"], "published");
+
+        // Every transition is recorded with who made it
+        let transitions: Vec<(i8, i8, String)> = sqlx::query_as(
+            "SELECT from_state, to_state, user_id FROM content_transitions WHERE content_id = ? ORDER BY id",
+        )
+        .bind(&content_id)
+        .fetch_all(&app_state.pool)
+        .await
+        .expect("Failed to fetch transitions");
+
+        assert_eq!(
+            transitions,
+            vec![
+                (0, 2, USER_ID.to_string()),
+                (2, 1, MODERATOR_ID.to_string())
+            ]
+        );
+
+        let resp = test::call_service(
+            &app,
+            transition(generate_test_jwt(), json!({"state": "unknown"})),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_transition_clears_schedule() {
+        let app_state = create_test_app_state().await;
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .service(web::scope("/admin").configure(routes::admin::content::config)),
+        )
+        .await;
+
+        // A content in review, scheduled to be published and archived later
+        let content_id = uuid::Uuid::new_v4().to_string();
+        let title: String = Sentence(3..6).fake();
+        let now = chrono::Utc::now();
+        sqlx::query(
+            "INSERT INTO contents (id, title, content_type, record_state, publish_at, unpublish_at, created_by) VALUES (?, ?, ?, 2, ?, ?, ?)",
+        )
+        .bind(&content_id)
+        .bind(&title)
+        .bind("page")
+        .bind(now + chrono::Duration::hours(1))
+        .bind(now + chrono::Duration::hours(2))
+        .bind(USER_ID)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test content");
+
+        let transition = |state: &str| {
+            test::TestRequest::post()
+                .uri(&format!("/admin/contents/{}/transition", content_id))
+                .insert_header((
+                    header::AUTHORIZATION,
+                    format!("Bearer {}", generate_jwt(MODERATOR_ID)),
+                ))
+                .set_json(json!({ "state": state }))
+                .to_request()
+        };
+        let schedule = |pool: sqlx::MySqlPool, content_id: String| async move {
+            sqlx::query_as::<_, (i8, bool, bool)>(
+                "SELECT record_state, publish_at IS NOT NULL, unpublish_at IS NOT NULL FROM contents WHERE id = ?",
+            )
+            .bind(&content_id)
+            .fetch_one(&pool)
+            .await
+            .expect("Failed to fetch content schedule")
+        };
+
+        // Publishing by hand cancels the scheduled publish, the archive stays scheduled
+        let resp = test::call_service(&app, transition("published")).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            schedule(app_state.pool.clone(), content_id.clone()).await,
+            (1, false, true)
+        );
+
+        let resp = test::call_service(&app, transition("archived")).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            schedule(app_state.pool.clone(), content_id.clone()).await,
+            (3, false, false)
+        );
+
+        // An archive time that passed before the content is published by hand is dropped
+        let due_content_id = uuid::Uuid::new_v4().to_string();
+        sqlx::query(
+            "INSERT INTO contents (id, title, content_type, record_state, unpublish_at, created_by) VALUES (?, ?, ?, 2, ?, ?)",
+        )
+        .bind(&due_content_id)
+        .bind(format!("{} due", title))
+        .bind("page")
+        .bind(now - chrono::Duration::hours(1))
+        .bind(USER_ID)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test content");
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/admin/contents/{}/transition", due_content_id))
+            .insert_header((
+                header::AUTHORIZATION,
+                format!("Bearer {}", generate_jwt(MODERATOR_ID)),
+            ))
+            .set_json(json!({"state": "published"}))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        apply_scheduled_moves(&app_state.pool, &SCHEDULED_UNPUBLISH, chrono::Utc::now())
+            .await
+            .expect("Failed to unpublish due contents");
+        assert_eq!(
+            schedule(app_state.pool.clone(), due_content_id.clone()).await,
+            (1, false, false)
+        );
+
+        // Once reopened, the old publish time does not publish the draft
+        let resp = test::call_service(&app, transition("draft")).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        apply_scheduled_moves(
+            &app_state.pool,
+            &SCHEDULED_PUBLISH,
+            now + chrono::Duration::hours(3),
+        )
+        .await
+        .expect("Failed to publish due contents");
+        assert_eq!(
+            schedule(app_state.pool.clone(), content_id.clone()).await,
+            (0, false, false)
+        );
+    }
+}
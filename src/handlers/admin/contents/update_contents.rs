@@ -1,12 +1,25 @@
 use crate::{
-    core::app_state::AppState,
+    core::{
+        app_state::AppState,
+        image_variant::{content_image_variants, variant_queue::VariantQueue},
+        media::{
+            content_galleries, find_content_media, set_content_gallery, set_content_image_media,
+            validate_gallery,
+        },
+        policy::{authorize_content, authorize_protected_update, ContentAction},
+        revision::record_revision,
+        schedule::{authorize_schedule, validate_schedule},
+        slug::{duplicate_slug_error, record_slug_redirect, slug_taken, validate_slug},
+        utils::validator::validate_request,
+    },
+    middlewares::permission_middleware::RequirePermission,
     model::{
         content::{ContentModel, ContentModelResponse},
         user::UserModel,
     },
     schema::admin::content::UpdateContentSchema,
     schema::response::{
-        api_response::ApiResponse, api_response_error::ApiResponseError,
+        admin::users::AuthUser, api_response::ApiResponse, api_response_error::ApiResponseError,
         api_response_object::ApiResponseObject,
     },
 };
@@ -22,35 +35,80 @@ use uuid::Uuid;
     params(
         ("id" = Uuid, Path, description = "UUID of the content"),
     ),
-    request_body(content = UpdateContentSchema, description = "Content data to update", example = json!({"title": "Updated title", "configurations": json!({"property1": "updated_value", "property2": json!({"sub-property": "updated_value"})})})),
+    request_body(content = UpdateContentSchema, description = "Content data to update", example = json!({"title": "Updated title", "slug": "updated-title", "meta_description": "Updated description", "image_media_id": "0b1c2d3e-4f50-4617-8293-a4b5c6d7e8f9", "gallery": ["0b1c2d3e-4f50-4617-8293-a4b5c6d7e8f9"], "publish_at": "2024-08-01T09:00:00Z", "configurations": json!({"property1": "updated_value", "property2": json!({"sub-property": "updated_value"})})})),
     responses(
         (status = 200, description= "Content updated", body = ApiResponse),
+        (status = 400, description= "Validation Error", body = ApiResponseError),
+        (status = 403, description= "Not allowed to update or schedule this content or content is protected", body = ApiResponseError),
         (status = 404, description= "Content not found", body = ApiResponseError),
-        (status = 409, description= "Duplicate entry", body = ApiResponseError),
+        (status = 409, description= "Slug already exists", body = ApiResponseError),
         (status = 500, description= "Internal Server Error", body = ApiResponseError),
     ),
     security(
        ("auth_token" = [])
    )
 )]
-#[put("/update/{id}")]
+#[put("/update/{id}", wrap = "RequirePermission(\"contents.update\")")]
 pub async fn update_contents_handler(
     id: web::Path<Uuid>,
     data: web::Json<UpdateContentSchema>,
+    auth: AuthUser,
     app_state: web::Data<AppState>,
+    variant_queue: web::Data<VariantQueue>,
 ) -> Result<ApiResponse, ApiResponseError> {
+    validate_request(&*data)?;
     let content_id = id.into_inner().to_string();
 
-    // Fetch the existing content to merge configurations
+    // Fetch the existing content to merge configurations, trashed contents have to be restored first
     let existing_content = sqlx::query_as!(
         ContentModel,
-        "SELECT * FROM contents WHERE id = ?",
+        "SELECT * FROM contents WHERE id = ? AND deleted_at IS NULL",
         content_id
     )
     .fetch_one(&app_state.pool)
     .await
     .map_err(|_| ApiResponseError::new(404, "Content not found".to_string(), None))?;
 
+    authorize_content(
+        &app_state.pool,
+        &auth,
+        &existing_content,
+        ContentAction::Update,
+    )
+    .await?;
+    authorize_protected_update(&app_state.pool, &auth, &existing_content, data.protected).await?;
+
+    // Times that are not sent keep their scheduled value, unless the schedule is cleared
+    let clear_schedule = data.clear_schedule.unwrap_or(false);
+    let (kept_publish_at, kept_unpublish_at) = match clear_schedule {
+        true => (None, None),
+        false => (existing_content.publish_at, existing_content.unpublish_at),
+    };
+    validate_schedule(
+        data.publish_at.or(kept_publish_at),
+        data.unpublish_at.or(kept_unpublish_at),
+    )?;
+    let schedules = data.publish_at.is_some() || data.unpublish_at.is_some();
+    authorize_schedule(&app_state.pool, &auth.id, schedules || clear_schedule).await?;
+    let scheduled_by = schedules.then_some(&auth.id);
+
+    // Only a slug that differs from the current one is changed
+    let new_slug = match data.slug {
+        Some(ref slug) => Some(validate_slug(slug)?)
+            .filter(|slug| existing_content.slug.as_deref() != Some(slug.as_str())),
+        None => None,
+    };
+    if let Some(ref slug) = new_slug {
+        let taken = slug_taken(&app_state.pool, slug, &content_id)
+            .await
+            .map_err(|e| {
+                ApiResponseError::new(500, format!("Internal Server Error: {:?}", e), None)
+            })?;
+        if taken {
+            return Err(duplicate_slug_error());
+        }
+    }
+
     let created_user = sqlx::query_as!(
         UserModel,
         "SELECT * FROM users WHERE id = ?",
@@ -60,30 +118,54 @@ pub async fn update_contents_handler(
     .await
     .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {:?}", e), None))?; // Fetch user who created the existing content
 
+    // Media are checked before anything is written
+    let image_media = match data.image_media_id {
+        Some(ref media_id) => {
+            Some(find_content_media(&app_state.pool, "image_media_id", media_id).await?)
+        }
+        None => None,
+    };
+    let gallery = match data.gallery {
+        Some(ref media_ids) => Some(validate_gallery(&app_state.pool, media_ids).await?),
+        None => None,
+    };
+
     // Merge existing configurations with new configurations
 
+    // Every update is kept as a revision, written with the update
+    let mut tx =
+        app_state.pool.begin().await.map_err(|e| {
+            ApiResponseError::new(500, format!("Internal Server Error: {:?}", e), None)
+        })?;
+
     // Update the contents table
     let query_result = sqlx::query!(
-        "UPDATE contents SET content_type = COALESCE(?, content_type), title = COALESCE(?, title), summary = COALESCE(?, summary), details = COALESCE(?, details), updated_at = CURRENT_TIMESTAMP WHERE id = ?",
+        "UPDATE contents SET content_type = COALESCE(?, content_type), title = COALESCE(?, title), summary = COALESCE(?, summary), details = COALESCE(?, details), slug = COALESCE(?, slug), meta_title = COALESCE(?, meta_title), meta_description = COALESCE(?, meta_description), canonical_url = COALESCE(?, canonical_url), protected = COALESCE(?, protected), publish_at = COALESCE(?, IF(?, NULL, publish_at)), unpublish_at = COALESCE(?, IF(?, NULL, unpublish_at)), scheduled_by = COALESCE(?, scheduled_by), updated_at = CURRENT_TIMESTAMP WHERE id = ?",
         data.content_type,
         data.title,
         data.summary,
         data.details,
+        new_slug,
+        data.meta_title,
+        data.meta_description,
+        data.canonical_url,
+        data.protected,
+        data.publish_at,
+        clear_schedule,
+        data.unpublish_at,
+        clear_schedule,
+        scheduled_by,
         content_id
     )
-    .execute(&app_state.pool)
+    .execute(&mut *tx)
     .await; // Execute SQL update query
 
     if let Err(err) = query_result {
         // Handle SQL update query error
         let error_message = err.to_string();
         if error_message.contains("Duplicate entry") {
-            // If duplicate entry error
-            return Err(ApiResponseError::new(
-                409,
-                "Title already exists".to_string(),
-                None,
-            ));
+            // If the slug was taken meanwhile
+            return Err(duplicate_slug_error());
         }
         return Err(ApiResponseError::new(
             500,
@@ -92,6 +174,46 @@ pub async fn update_contents_handler(
         ));
     }
 
+    // The previous slug keeps working for links that were shared
+    if let (Some(old_slug), Some(slug)) = (existing_content.slug.as_deref(), new_slug.as_deref()) {
+        record_slug_redirect(&mut tx, &content_id, old_slug, slug)
+            .await
+            .map_err(|err| {
+                ApiResponseError::new(500, format!("Internal Server Error: {:?}", err), None)
+            })?;
+    }
+
+    if let Some(ref media) = image_media {
+        set_content_image_media(&mut tx, &content_id, media)
+            .await
+            .map_err(|err| {
+                ApiResponseError::new(500, format!("Internal Server Error: {:?}", err), None)
+            })?;
+    }
+    if let Some(ref gallery) = gallery {
+        set_content_gallery(&mut tx, &content_id, gallery)
+            .await
+            .map_err(|err| {
+                ApiResponseError::new(500, format!("Internal Server Error: {:?}", err), None)
+            })?;
+    }
+
+    record_revision(&mut tx, &content_id, &auth.id)
+        .await
+        .map_err(|err| {
+            ApiResponseError::new(500, format!("Internal Server Error: {:?}", err), None)
+        })?;
+    tx.commit().await.map_err(|err| {
+        ApiResponseError::new(500, format!("Internal Server Error: {:?}", err), None)
+    })?;
+
+    // The variants of a new image are made in the background
+    if let Some(media) = image_media.filter(|media| {
+        existing_content.content_image.as_deref() != Some(media.storage_key.as_str())
+    }) {
+        variant_queue.queue(&content_id, &media.storage_key).await;
+    }
+
     // Fetch updated content from database
     let mut updated_content = sqlx::query_as!(
         ContentModel,
@@ -102,7 +224,11 @@ pub async fn update_contents_handler(
     .await
     .map_err(|err| ApiResponseError::new(500, format!("Internal Server Error: {:?}", err), None))?; // Handle fetch updated content error
 
-    let response = ContentModelResponse::filter_db(&mut updated_content, &created_user); // Filter updated content and creator user details
+    let variants = content_image_variants(&app_state.pool, &[&content_id]).await?;
+    let gallery = content_galleries(&app_state.pool, &[&content_id]).await?;
+    let response = ContentModelResponse::filter_db(&mut updated_content, &created_user)
+        .with_image_variants(&variants)
+        .with_gallery(&gallery); // Filter updated content and creator user details
 
     let content_response = ApiResponseObject::new(json!({"content": response})) // Create JSON response object
         .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?; // Handle JSON response object creation error
@@ -138,6 +264,9 @@ mod tests {
             exp: (now + expire).timestamp() as usize,
             iat: now.timestamp() as usize,
             id: user_id.to_owned(),
+            jti: uuid::Uuid::new_v4().to_string(),
+            sid: None,
+            impersonator: None,
         };
         let encoding_key = jsonwebtoken::EncodingKey::from_secret(CONFIG.jwt.secret.as_ref());
         jsonwebtoken::encode(&jsonwebtoken::Header::default(), &claims, &encoding_key).unwrap()
@@ -164,6 +293,7 @@ mod tests {
         let app = test::init_service(
             App::new()
                 .app_data(app_state.clone())
+                .app_data(web::Data::new(VariantQueue::new().0))
                 .service(web::scope("/admin").configure(routes::admin::content::config)),
         )
         .await;
@@ -177,6 +307,14 @@ mod tests {
             title: Word().fake(),
             summary: Some(Sentence(5..10).fake()),
             details: Some(Sentence(10..15).fake()),
+            publish_at: None,
+            unpublish_at: None,
+            slug: None,
+            meta_title: None,
+            meta_description: None,
+            canonical_url: None,
+            image_media_id: None,
+            gallery: None,
         };
         let content_id = uuid::Uuid::new_v4().to_string();
         let _insert_result = sqlx::query(
@@ -197,6 +335,16 @@ mod tests {
             title: Some(Word().fake()),
             summary: Some("Updated Summary".to_string()),
             details: Some("Updated Details".to_string()),
+            protected: None,
+            publish_at: None,
+            unpublish_at: None,
+            clear_schedule: None,
+            slug: None,
+            meta_title: None,
+            meta_description: None,
+            canonical_url: None,
+            image_media_id: None,
+            gallery: None,
         };
 
         let req = test::TestRequest::put()
@@ -224,6 +372,159 @@ mod tests {
         .await
         .expect("Failed to fetch updated content");
 
-        assert_eq!(updated_content.title, update_content_data.title.unwrap());
+        assert_eq!(
+            updated_content.title,
+            update_content_data.title.clone().unwrap()
+        );
+
+        // The update is kept as a revision
+        let revisions = sqlx::query_scalar!(
+            "SELECT COUNT(*) FROM content_revisions WHERE content_id = ?",
+            content_id
+        )
+        .fetch_one(&app_state.pool)
+        .await
+        .expect("Failed to count revisions");
+
+        assert_eq!(revisions, 1);
+
+        // A moderator may not update content created by someone else
+        let moderator_jwt = generate_jwt("a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e24");
+        let req = test::TestRequest::put()
+            .uri(&format!("/admin/contents/update/{}", content_id))
+            .insert_header((
+                actix_web::http::header::AUTHORIZATION,
+                format!("Bearer {}", moderator_jwt),
+            ))
+            .set_json(&update_content_data)
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), actix_web::http::StatusCode::FORBIDDEN);
+
+        // Once protected, the content only accepts the update that unprotects it
+        let update = |data: serde_json::Value| {
+            test::TestRequest::put()
+                .uri(&format!("/admin/contents/update/{}", content_id))
+                .insert_header((
+                    actix_web::http::header::AUTHORIZATION,
+                    format!("Bearer {}", jwt),
+                ))
+                .set_json(data)
+                .to_request()
+        };
+
+        let resp = test::call_service(&app, update(json!({"protected": true}))).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::OK);
+
+        let resp = test::call_service(&app, update(json!({"summary": "Protected"}))).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::FORBIDDEN);
+
+        let resp = test::call_service(&app, update(json!({"protected": false}))).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::OK);
+
+        // Slugs are normalized, the previous one is kept as a redirect
+        let first_slug = format!("first-{}", content_id);
+        let resp: ApiResponse = test::call_and_read_body_json(
+            &app,
+            update(json!({"slug": format!("First {}", content_id)})),
+        )
+        .await;
+        assert_eq!(resp.status, 200);
+        let data = resp.data.expect("Missing response data").properties;
+        assert_eq!(data["content"]["slug"], first_slug);
+
+        let resp = test::call_service(
+            &app,
+            update(json!({"slug": format!("second-{}", content_id)})),
+        )
+        .await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::OK);
+
+        let redirect = sqlx::query_scalar!(
+            "SELECT content_id FROM content_slug_redirects WHERE old_slug = ?",
+            first_slug
+        )
+        .fetch_one(&app_state.pool)
+        .await
+        .expect("Failed to fetch redirect");
+        assert_eq!(redirect, content_id);
+
+        // Slugs of other contents are taken
+        let resp = test::call_service(&app, update(json!({"slug": "content-1"}))).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::CONFLICT);
+
+        let resp = test::call_service(
+            &app,
+            update(json!({"canonical_url": "not a url", "meta_title": "Title"})),
+        )
+        .await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+
+        // A schedule can be cancelled, times sent with it are kept
+        let publish_at = chrono::Utc::now() + chrono::Duration::hours(1);
+        let resp = test::call_service(&app, update(json!({ "publish_at": publish_at }))).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::OK);
+
+        let unpublish_at = chrono::Utc::now() + chrono::Duration::minutes(30);
+        let resp: ApiResponse = test::call_and_read_body_json(
+            &app,
+            update(json!({"clear_schedule": true, "unpublish_at": unpublish_at})),
+        )
+        .await;
+        assert_eq!(resp.status, 200);
+        let data = resp.data.expect("Missing response data").properties;
+        assert!(data["content"]["publishAt"].is_null());
+        assert!(!data["content"]["unpublishAt"].is_null());
+
+        // Images come from the media library, the gallery keeps the order sent
+        let mut media_ids = Vec::new();
+        for name in ["first.png", "second.png"] {
+            let media_id = uuid::Uuid::new_v4().to_string();
+            sqlx::query(
+                "INSERT INTO media (id, storage_key, file_name, mime_type, size_bytes, width, height, checksum, uploaded_by) VALUES (?, ?, ?, 'image/png', 10, 40, 30, ?, ?)",
+            )
+            .bind(&media_id)
+            .bind(format!("media/{}-{}", media_id, name))
+            .bind(name)
+            .bind(format!("{:0>64}", media_id.replace('-', "")))
+            .bind(user_id)
+            .execute(&app_state.pool)
+            .await
+            .expect("Failed to insert test media");
+            media_ids.push(media_id);
+        }
+
+        let resp: ApiResponse = test::call_and_read_body_json(
+            &app,
+            update(json!({
+                "image_media_id": media_ids[0],
+                "gallery": [media_ids[1], media_ids[0], media_ids[1]],
+            })),
+        )
+        .await;
+        assert_eq!(resp.status, 200);
+        let data = resp.data.expect("Missing response data").properties;
+        assert_eq!(data["content"]["contentImageMediaId"], media_ids[0]);
+        assert_eq!(data["content"]["contentImageWidth"], 40);
+        let gallery = data["content"]["gallery"].as_array().unwrap();
+        assert_eq!(gallery.len(), 2);
+        assert_eq!(gallery[0]["mediaId"], media_ids[1]);
+        assert_eq!(gallery[1]["mediaId"], media_ids[0]);
+
+        let resp = test::call_service(
+            &app,
+            update(json!({"gallery": [uuid::Uuid::new_v4().to_string()]})),
+        )
+        .await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+
+        // Media used by a content cannot be deleted
+        let deleted = sqlx::query("DELETE FROM media WHERE id = ?")
+            .bind(&media_ids[1])
+            .execute(&app_state.pool)
+            .await;
+        assert!(deleted.is_err());
     }
 }
@@ -0,0 +1,391 @@
+use crate::{
+    config::CONFIG,
+    core::{
+        app_state::AppState,
+        image_variant::{content_image_variants, variant_queue::VariantQueue},
+        media::{content_galleries, set_content_image_media, store_media},
+        policy::{authorize_content, ensure_unprotected, ContentAction},
+        storage::Storage,
+        upload::read_image,
+    },
+    middlewares::permission_middleware::RequirePermission,
+    model::{
+        content::{ContentModel, ContentModelResponse},
+        user::UserModel,
+    },
+    schema::response::{
+        admin::users::AuthUser, api_response::ApiResponse, api_response_error::ApiResponseError,
+        api_response_object::ApiResponseObject,
+    },
+};
+use actix_multipart::Multipart;
+use actix_web::{post, web};
+use log::{info, warn};
+use serde_json::json;
+use uuid::Uuid;
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    post,
+    path = "/admin/contents/{id}/image",
+    tag = "Admin: Contents Endpoint",
+    params(
+        ("id" = Uuid, Path, description = "UUID of the content"),
+    ),
+    request_body(content = UploadImageSchema, description = "Image of the content in the `image` field", content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description= "Content image uploaded", body = ApiResponse),
+        (status = 400, description= "Validation Error", body = ApiResponseError),
+        (status = 403, description= "Not allowed to update this content or content is protected", body = ApiResponseError),
+        (status = 404, description= "Content not found", body = ApiResponseError),
+        (status = 413, description= "Image is too large", body = ApiResponseError),
+        (status = 415, description= "Image is not a JPEG, PNG, GIF or WebP file", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[post("/{id}/image", wrap = "RequirePermission(\"contents.update\")")]
+pub async fn upload_content_image_handler(
+    id: web::Path<Uuid>,
+    payload: Multipart,
+    auth: AuthUser,
+    app_state: web::Data<AppState>,
+    storage: web::Data<dyn Storage>,
+    variant_queue: web::Data<VariantQueue>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let content_id = id.into_inner().to_string();
+
+    let content = sqlx::query_as!(
+        ContentModel,
+        "SELECT * FROM contents WHERE id = ? AND deleted_at IS NULL",
+        content_id
+    )
+    .fetch_optional(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?
+    .ok_or_else(|| ApiResponseError::new(404, "Content not found".to_string(), None))?;
+
+    authorize_content(&app_state.pool, &auth, &content, ContentAction::Update).await?;
+    ensure_unprotected(&content)?;
+
+    // The body is only read once the user is allowed to change the content
+    let image = read_image(payload, "image", CONFIG.storage.max_upload_bytes).await?;
+
+    // The image joins the media library, an image already in it is reused
+    let (media, _) = store_media(&app_state.pool, storage.as_ref(), image, None, &auth.id).await?;
+
+    let mut tx =
+        app_state.pool.begin().await.map_err(|e| {
+            ApiResponseError::new(500, format!("Internal Server Error: {}", e), None)
+        })?;
+    set_content_image_media(&mut tx, &content_id, &media)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+    sqlx::query("UPDATE contents SET updated_at = CURRENT_TIMESTAMP WHERE id = ?")
+        .bind(&content_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+    tx.commit()
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+    info!("Content {} image uploaded by {}", content_id, auth.id);
+
+    // The variants are made in the background, the response lists them once they are ready.
+    // The variants of the previous image are removed once the new ones are recorded.
+    if content.content_image.as_deref() != Some(media.storage_key.as_str()) {
+        variant_queue.queue(&content_id, &media.storage_key).await;
+    }
+
+    // Images set before the media library are owned by the content, media are kept
+    if let Some(previous) = content
+        .content_image
+        .as_deref()
+        .filter(|_| content.content_image_media_id.is_none())
+        .filter(|previous| !previous.contains("://") && !previous.starts_with('/'))
+    {
+        if let Err(e) = storage.delete(previous).await {
+            warn!("Failed to delete the previous image {}: {}", previous, e);
+        }
+    }
+
+    let mut updated_content = sqlx::query_as!(
+        ContentModel,
+        "SELECT * FROM contents WHERE id = ?",
+        content_id
+    )
+    .fetch_one(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    let created_user = sqlx::query_as!(
+        UserModel,
+        "SELECT * FROM users WHERE id = ?",
+        updated_content.created_by
+    )
+    .fetch_one(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    let variants = content_image_variants(&app_state.pool, &[&content_id]).await?;
+    let gallery = content_galleries(&app_state.pool, &[&content_id]).await?;
+    let response = ContentModelResponse::filter_db(&mut updated_content, &created_user)
+        .with_image_variants(&variants)
+        .with_gallery(&gallery);
+    let content_response = ApiResponseObject::new(json!({"content": response}))
+        .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        200,
+        "Content Image Uploaded".to_string(),
+        Some(content_response),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        core::{
+            image_variant::{
+                process_image_variants, variant_queue::VariantQueue, VARIANT_FORMATS, VARIANT_SIZES,
+            },
+            storage::{local::LocalStorage, Storage},
+            utils::test_utils::{create_test_app_state, generate_jwt, generate_test_jwt, USER_ID},
+        },
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::header, http::StatusCode, test, web, App};
+    use fake::{faker::lorem::en::Sentence, Fake};
+    use image::{ImageFormat, Rgb, RgbImage};
+    use std::io::Cursor;
+
+    // Seeded moderator, who did not create the test content
+    const MODERATOR_ID: &str = "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e24";
+    const BOUNDARY: &str = "image-upload-boundary";
+
+    // Images are shared through the media library, every run uploads new ones
+    fn test_png(width: u32, height: u32) -> Vec<u8> {
+        let seed = uuid::Uuid::new_v4();
+        let mut image = RgbImage::from_pixel(width, height, Rgb([200, 80, 40]));
+        image.put_pixel(
+            0,
+            0,
+            Rgb([seed.as_bytes()[0], seed.as_bytes()[1], seed.as_bytes()[2]]),
+        );
+        image.put_pixel(
+            1,
+            0,
+            Rgb([seed.as_bytes()[3], seed.as_bytes()[4], seed.as_bytes()[5]]),
+        );
+        let mut png = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+            .unwrap();
+        png
+    }
+
+    fn multipart_body(field: &str, file_name: &str, bytes: &[u8]) -> Vec<u8> {
+        let mut body = format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: application/octet-stream\r\n\r\n",
+            BOUNDARY, field, file_name
+        )
+        .into_bytes();
+        body.extend_from_slice(bytes);
+        body.extend_from_slice(format!("\r\n--{}--\r\n", BOUNDARY).as_bytes());
+        body
+    }
+
+    #[actix_web::test]
+    async fn test_upload_content_image_handler() {
+        let app_state = create_test_app_state().await;
+        let root = std::env::temp_dir().join(format!("uploads-{}", uuid::Uuid::new_v4()));
+        let storage: Arc<dyn Storage> = Arc::new(LocalStorage::new(&root));
+        let (variant_queue, mut variant_receiver) = VariantQueue::new();
+
+        // Create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .app_data(web::Data::from(storage.clone()))
+                .app_data(web::Data::new(variant_queue))
+                .service(web::scope("/admin").configure(routes::admin::content::config)),
+        )
+        .await;
+
+        let content_id = uuid::Uuid::new_v4().to_string();
+        let title: String = Sentence(3..6).fake();
+        sqlx::query(
+            "INSERT INTO contents (id, title, content_type, created_by) VALUES (?, ?, ?, ?)",
+        )
+        .bind(&content_id)
+        .bind(&title)
+        .bind("page")
+        .bind(USER_ID)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test content");
+
+        let upload = |jwt: String, body: Vec<u8>| {
+            test::TestRequest::post()
+                .uri(&format!("/admin/contents/{}/image", content_id))
+                .insert_header((header::AUTHORIZATION, format!("Bearer {}", jwt)))
+                .insert_header((
+                    header::CONTENT_TYPE,
+                    format!("multipart/form-data; boundary={}", BOUNDARY),
+                ))
+                .set_payload(body)
+                .to_request()
+        };
+        let png = test_png(800, 600);
+
+        // The ownership policy applies as for updates
+        let resp = test::call_service(
+            &app,
+            upload(
+                generate_jwt(MODERATOR_ID),
+                multipart_body("image", "a.png", &png),
+            ),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        // The type comes from the content, not from the name
+        let resp = test::call_service(
+            &app,
+            upload(
+                generate_test_jwt(),
+                multipart_body("image", "image.png", b"<svg></svg>"),
+            ),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+        let resp = test::call_service(
+            &app,
+            upload(generate_test_jwt(), multipart_body("file", "a.png", &png)),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let resp: ApiResponse = test::call_and_read_body_json(
+            &app,
+            upload(
+                generate_test_jwt(),
+                multipart_body("image", "My Photo.jpg", &png),
+            ),
+        )
+        .await;
+        assert_eq!(resp.status, 200);
+
+        let key: String = sqlx::query_scalar("SELECT content_image FROM contents WHERE id = ?")
+            .bind(&content_id)
+            .fetch_one(&app_state.pool)
+            .await
+            .expect("Failed to fetch the content image");
+        assert!(key.starts_with("media/"));
+        assert!(key.ends_with("-my-photo.png"));
+        assert_eq!(std::fs::read(root.join(&key)).unwrap(), png);
+
+        let data = resp.data.expect("Missing response data").properties;
+        assert!(data["content"]["contentImage"]
+            .as_str()
+            .unwrap()
+            .ends_with(&key));
+        assert_eq!(data["content"]["contentImageWidth"], 800);
+        assert_eq!(data["content"]["contentImageHeight"], 600);
+
+        // Run the queued job as the background task does
+        let job = variant_receiver.recv().await.expect("Missing variant job");
+        assert_eq!(job.source_key, key);
+        let count = process_image_variants(
+            &app_state.pool,
+            storage.as_ref(),
+            &job.content_id,
+            &job.source_key,
+        )
+        .await
+        .expect("Failed to process the image variants");
+        assert_eq!(count, VARIANT_SIZES.len() * VARIANT_FORMATS.len());
+
+        let variant_keys: Vec<String> = sqlx::query_scalar(
+            "SELECT storage_key FROM content_image_variants WHERE content_id = ?",
+        )
+        .bind(&content_id)
+        .fetch_all(&app_state.pool)
+        .await
+        .expect("Failed to fetch the image variants");
+        assert_eq!(variant_keys.len(), count);
+        assert!(variant_keys.iter().all(|key| root.join(key).exists()));
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/admin/contents/{}", content_id))
+            .insert_header((
+                header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .to_request();
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+        let data = resp.data.expect("Missing response data").properties;
+        let variants = data["content"]["contentImageVariants"].as_array().unwrap();
+        assert_eq!(variants.len(), count);
+        assert!(variants.iter().any(|variant| variant["size"] == "thumbnail"
+            && variant["format"] == "webp"
+            && variant["width"] == 150));
+
+        // The same image is not stored twice
+        let resp = test::call_service(
+            &app,
+            upload(generate_test_jwt(), multipart_body("image", "b.png", &png)),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let media_count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM media WHERE storage_key = ?")
+                .bind(&key)
+                .fetch_one(&app_state.pool)
+                .await
+                .expect("Failed to count the media");
+        assert_eq!(media_count, 1);
+        assert!(variant_receiver.try_recv().is_err());
+
+        // A new image replaces the variants, the previous media stays in the library
+        let other_png = test_png(400, 300);
+        let resp = test::call_service(
+            &app,
+            upload(
+                generate_test_jwt(),
+                multipart_body("image", "c.png", &other_png),
+            ),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert!(root.join(&key).exists());
+
+        let job = variant_receiver.recv().await.expect("Missing variant job");
+        assert_ne!(job.source_key, key);
+        process_image_variants(
+            &app_state.pool,
+            storage.as_ref(),
+            &job.content_id,
+            &job.source_key,
+        )
+        .await
+        .expect("Failed to process the image variants");
+        assert!(variant_keys.iter().all(|key| !root.join(key).exists()));
+
+        let remaining: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM content_image_variants WHERE content_id = ?")
+                .bind(&content_id)
+                .fetch_one(&app_state.pool)
+                .await
+                .expect("Failed to count the image variants");
+        assert_eq!(remaining as usize, count);
+
+        std::fs::remove_dir_all(root).ok();
+    }
+}
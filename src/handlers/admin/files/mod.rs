@@ -0,0 +1 @@
+pub mod sign_file;
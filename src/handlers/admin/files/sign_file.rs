@@ -0,0 +1,166 @@
+use std::path::Path;
+
+use actix_web::{post, web};
+use chrono::Utc;
+use serde_json::json;
+
+use crate::{
+    config::CONFIG,
+    core::{files::signed_url, storage::validate_key},
+    middlewares::permission_middleware::RequirePermission,
+    schema::{
+        admin::file::SignFileSchema,
+        response::{
+            api_response::ApiResponse,
+            api_response_error::{ApiResponseError, ValidationErrorDetail},
+            api_response_object::ApiResponseObject,
+        },
+    },
+};
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    post,
+    path = "/admin/files/sign",
+    tag = "Admin: Files Endpoint",
+    request_body(content = SignFileSchema, description = "Private file to hand out", example = json!({"path": "exports/report.csv"})),
+    responses(
+        (status = 200, description= "Signed URL of the file, served under /files until it expires", body = ApiResponse),
+        (status = 400, description= "Validation Error", body = ApiResponseError),
+        (status = 404, description= "File Not Found", body = ApiResponseError),
+        (status = 500, description= "Signed URLs are not configured", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[post("/sign", wrap = "RequirePermission(\"files.share\")")]
+pub async fn sign_file_handler(
+    data: web::Json<SignFileSchema>,
+) -> Result<ApiResponse, ApiResponseError> {
+    validate_key(&data.path).map_err(|e| {
+        ApiResponseError::new(
+            400,
+            "Validation Error".to_string(),
+            Some(vec![ValidationErrorDetail {
+                field: "path".to_string(),
+                error: e,
+            }]),
+        )
+    })?;
+
+    // A URL signed with an empty key would never be accepted
+    if CONFIG.files.signing_key.is_empty() {
+        return Err(ApiResponseError::new(
+            500,
+            "Signed URLs are not configured".to_string(),
+            None,
+        ));
+    }
+
+    let file_path = Path::new(&CONFIG.files.private_root).join(&data.path);
+    match tokio::fs::metadata(&file_path).await {
+        Ok(metadata) if metadata.is_file() => {}
+        _ => {
+            return Err(ApiResponseError::new(
+                404,
+                "File Not Found".to_string(),
+                None,
+            ))
+        }
+    }
+
+    let file_response = ApiResponseObject::new(json!({
+        "url": signed_url(&data.path, Utc::now()),
+        "expiresIn": CONFIG.files.signed_url_seconds,
+    }))
+    .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        200,
+        "Signed URL created".to_string(),
+        Some(file_response),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        config::CONFIG,
+        core::utils::test_utils::{create_test_app_state, generate_jwt, generate_test_jwt},
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::header, http::StatusCode, test, web, App};
+    use serde_json::json;
+    use std::path::Path;
+
+    // Seeded moderator, not granted files.share
+    const MODERATOR_ID: &str = "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e24";
+
+    #[actix_web::test]
+    async fn test_sign_file_handler() {
+        let app_state = create_test_app_state().await;
+
+        let dir = format!("test-{}", uuid::Uuid::new_v4());
+        let root = Path::new(&CONFIG.files.private_root).join(&dir);
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join("report.csv"), "id,name\n1,Ahmed\n").unwrap();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .configure(routes::files::config)
+                .service(web::scope("/admin").configure(routes::admin::files::config)),
+        )
+        .await;
+
+        let sign = |jwt: String, path: String| {
+            test::TestRequest::post()
+                .uri("/admin/files/sign")
+                .insert_header((header::AUTHORIZATION, format!("Bearer {}", jwt)))
+                .set_json(json!({ "path": path }))
+                .to_request()
+        };
+
+        let resp: ApiResponse = test::call_and_read_body_json(
+            &app,
+            sign(generate_test_jwt(), format!("{}/report.csv", dir)),
+        )
+        .await;
+        assert_eq!(resp.status, 200);
+        let data = resp.data.expect("Missing response data").properties;
+        assert_eq!(data["expiresIn"], CONFIG.files.signed_url_seconds);
+
+        // The URL serves the file
+        let url = data["url"].as_str().unwrap();
+        let req = test::TestRequest::get().uri(url).to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(test::read_body(resp).await, "id,name\n1,Ahmed\n");
+
+        let resp = test::call_service(
+            &app,
+            sign(generate_test_jwt(), format!("{}/missing.csv", dir)),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        // Nothing outside the private directory is signed
+        let resp = test::call_service(
+            &app,
+            sign(generate_test_jwt(), format!("{}/../Cargo.toml", dir)),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let resp = test::call_service(
+            &app,
+            sign(generate_jwt(MODERATOR_ID), format!("{}/report.csv", dir)),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,184 @@
+use actix_web::{delete, web};
+use log::{info, warn};
+use uuid::Uuid;
+
+use crate::{
+    core::{
+        app_state::AppState,
+        media::{find_media, media_references},
+        storage::Storage,
+    },
+    middlewares::permission_middleware::RequirePermission,
+    schema::response::{
+        admin::users::AuthUser, api_response::ApiResponse, api_response_error::ApiResponseError,
+    },
+};
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    delete,
+    path = "/admin/media/delete/{id}",
+    tag = "Admin: Media Endpoint",
+    params(
+        ("id" = Uuid, Path, description = "UUID of the media to delete"),
+    ),
+    responses(
+        (status = 204, description= "Media Deleted", body = ApiResponse),
+        (status = 404, description= "Media not found", body = ApiResponseError),
+        (status = 409, description= "Media is used by contents", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[delete("/delete/{id}", wrap = "RequirePermission(\"media.delete\")")]
+pub async fn delete_media_handler(
+    path: web::Path<Uuid>,
+    auth: AuthUser,
+    app_state: web::Data<AppState>,
+    storage: web::Data<dyn Storage>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let media_id = path.into_inner().to_string();
+    let media = find_media(&app_state.pool, &media_id).await?;
+
+    let references = media_references(&app_state.pool, &media_id).await?;
+    if references > 0 {
+        return Err(media_in_use(references));
+    }
+
+    // The foreign keys still refuse a media that was used meanwhile
+    sqlx::query("DELETE FROM media WHERE id = ?")
+        .bind(&media_id)
+        .execute(&app_state.pool)
+        .await
+        .map_err(|e| {
+            if e.to_string().contains("foreign key constraint fails") {
+                media_in_use(1)
+            } else {
+                ApiResponseError::new(500, format!("Internal Server Error: {}", e), None)
+            }
+        })?;
+    info!("Media {} deleted by {}", media_id, auth.id);
+
+    // The row is gone, a file left behind is only wasted space
+    if let Err(e) = storage.delete(&media.storage_key).await {
+        warn!(
+            "Failed to delete the media file {}: {}",
+            media.storage_key, e
+        );
+    }
+
+    Ok(ApiResponse::new(204, "Media deleted".to_string(), None))
+}
+
+fn media_in_use(references: i64) -> ApiResponseError {
+    ApiResponseError::new(
+        409,
+        format!("Media is used by {} content(s)", references),
+        None,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        core::{
+            storage::{local::LocalStorage, Storage},
+            utils::test_utils::{create_test_app_state, generate_test_jwt, USER_ID},
+        },
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::header, http::StatusCode, test, web, App};
+    use fake::{faker::lorem::en::Sentence, Fake};
+
+    #[actix_web::test]
+    async fn test_delete_media_handler() {
+        let app_state = create_test_app_state().await;
+        let root = std::env::temp_dir().join(format!("uploads-{}", uuid::Uuid::new_v4()));
+        let storage: Arc<dyn Storage> = Arc::new(LocalStorage::new(&root));
+
+        // Create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .app_data(web::Data::from(storage.clone()))
+                .service(web::scope("/admin").configure(routes::admin::media::config)),
+        )
+        .await;
+
+        let media_id = uuid::Uuid::new_v4().to_string();
+        let key = format!("media/{}-logo.png", media_id);
+        storage
+            .put(&key, b"image".to_vec(), "image/png")
+            .await
+            .expect("Failed to store test file");
+        sqlx::query(
+            "INSERT INTO media (id, storage_key, file_name, mime_type, size_bytes, width, height, checksum, uploaded_by) VALUES (?, ?, 'logo.png', 'image/png', 5, 1, 1, ?, ?)",
+        )
+        .bind(&media_id)
+        .bind(&key)
+        .bind(format!("{:0>64}", media_id.replace('-', "")))
+        .bind(USER_ID)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test media");
+
+        // A content shows the media in its gallery
+        let content_id = uuid::Uuid::new_v4().to_string();
+        let title: String = Sentence(3..6).fake();
+        sqlx::query(
+            "INSERT INTO contents (id, title, content_type, created_by) VALUES (?, ?, ?, ?)",
+        )
+        .bind(&content_id)
+        .bind(&title)
+        .bind("page")
+        .bind(USER_ID)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test content");
+        sqlx::query(
+            "INSERT INTO content_gallery (content_id, media_id, position) VALUES (?, ?, 0)",
+        )
+        .bind(&content_id)
+        .bind(&media_id)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test gallery");
+
+        let delete = || {
+            test::TestRequest::delete()
+                .uri(&format!("/admin/media/delete/{}", media_id))
+                .insert_header((
+                    header::AUTHORIZATION,
+                    format!("Bearer {}", generate_test_jwt()),
+                ))
+                .to_request()
+        };
+
+        let resp = test::call_service(&app, delete()).await;
+
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        assert!(root.join(&key).exists());
+
+        sqlx::query("DELETE FROM content_gallery WHERE content_id = ?")
+            .bind(&content_id)
+            .execute(&app_state.pool)
+            .await
+            .expect("Failed to clear test gallery");
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, delete()).await;
+
+        assert_eq!(resp.status, 204);
+        assert!(!root.join(&key).exists());
+
+        let resp = test::call_service(&app, delete()).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        std::fs::remove_dir_all(root).ok();
+    }
+}
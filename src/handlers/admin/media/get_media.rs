@@ -0,0 +1,181 @@
+use crate::{
+    core::app_state::AppState,
+    middlewares::permission_middleware::RequirePermission,
+    model::media::{MediaModel, MediaModelResponse},
+    schema::{
+        admin::media::MediaFilterOptions,
+        response::{
+            api_response_collection::ApiResponseCollection, api_response_error::ApiResponseError,
+            api_response_object::ApiResponseObject, Pagination,
+        },
+    },
+};
+use actix_web::{get, web, HttpResponse, Responder};
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    get,
+    path = "/admin/media",
+    tag = "Admin: Media Endpoint",
+    params(
+        MediaFilterOptions
+    ),
+    responses(
+        (status = 200, description= "Get All Media", body = ApiResponse),
+        (status = 401, description= "Unauthorized", body = ApiResponseError),
+        (status = 404, description= "No Data Found", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[get("", wrap = "RequirePermission(\"media.view\")")]
+pub async fn get_media_handler(
+    opts: web::Query<MediaFilterOptions>,
+    app_state: web::Data<AppState>,
+) -> Result<impl Responder, ApiResponseError> {
+    let limit = opts.limit.unwrap_or(10);
+    let page = opts.page.unwrap_or(1);
+    let offset = (page - 1) * limit;
+
+    // The search matches the file name or the alt text
+    let mut conditions = "1 = 1".to_string();
+    let search = opts.search.as_ref().map(|search| format!("%{}%", search));
+    if search.is_some() {
+        conditions.push_str(" AND (file_name LIKE ? OR alt_text LIKE ?)");
+    }
+    if opts.mime_type.is_some() {
+        conditions.push_str(" AND mime_type = ?");
+    }
+
+    let query = format!(
+        "SELECT * FROM media WHERE {} ORDER BY created_at DESC LIMIT ? OFFSET ?",
+        conditions
+    );
+    let mut media_query = sqlx::query_as::<_, MediaModel>(&query);
+    if let Some(ref search) = search {
+        media_query = media_query.bind(search).bind(search);
+    }
+    if let Some(ref mime_type) = opts.mime_type {
+        media_query = media_query.bind(mime_type);
+    }
+    let media = media_query
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&app_state.pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    if media.is_empty() {
+        return Err(ApiResponseError::new(
+            404,
+            "No Data Found".to_string(),
+            None,
+        ));
+    }
+
+    let total_count_query = format!("SELECT COUNT(*) FROM media WHERE {}", conditions);
+    let mut total_count_query = sqlx::query_as::<_, (i64,)>(&total_count_query);
+    if let Some(ref search) = search {
+        total_count_query = total_count_query.bind(search).bind(search);
+    }
+    if let Some(ref mime_type) = opts.mime_type {
+        total_count_query = total_count_query.bind(mime_type);
+    }
+    let total_count = total_count_query
+        .fetch_one(&app_state.pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    let media_response: Vec<MediaModelResponse> =
+        media.iter().map(MediaModelResponse::filter_db).collect();
+
+    let total_items = total_count.0;
+    let total_pages = (total_items as f64 / limit as f64).ceil() as i64;
+    let pagination = Pagination {
+        total_items,
+        total_pages,
+        current_page: page,
+        per_page: limit,
+    };
+
+    let json_response = ApiResponseObject::new(serde_json::json!({
+        "media": media_response,
+    }))
+    .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(HttpResponse::Ok().json(ApiResponseCollection::new(
+        200,
+        "Get All Media".to_string(),
+        Some(json_response),
+        Some(pagination),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::utils::test_utils::{create_test_app_state, generate_test_jwt, USER_ID},
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::header, http::StatusCode, test, web, App};
+
+    #[actix_web::test]
+    async fn test_get_media_handler() {
+        let app_state = create_test_app_state().await;
+
+        // Create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .service(web::scope("/admin").configure(routes::admin::media::config)),
+        )
+        .await;
+
+        // The alt text is unique to this run
+        let media_id = uuid::Uuid::new_v4().to_string();
+        sqlx::query(
+            "INSERT INTO media (id, storage_key, file_name, mime_type, size_bytes, width, height, alt_text, checksum, uploaded_by) VALUES (?, ?, 'banner.gif', 'image/gif', 10, 1, 1, ?, ?, ?)",
+        )
+        .bind(&media_id)
+        .bind(format!("media/{}-banner.gif", media_id))
+        .bind(format!("Banner {}", media_id))
+        .bind(format!("{:0>64}", media_id.replace('-', "")))
+        .bind(USER_ID)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test media");
+
+        let list = |query: String| {
+            test::TestRequest::get()
+                .uri(&format!("/admin/media?{}", query))
+                .insert_header((
+                    header::AUTHORIZATION,
+                    format!("Bearer {}", generate_test_jwt()),
+                ))
+                .to_request()
+        };
+
+        let resp: ApiResponse = test::call_and_read_body_json(
+            &app,
+            list(format!("search={}&mime_type=image%2Fgif", media_id)),
+        )
+        .await;
+
+        assert_eq!(resp.status, 200);
+        assert_eq!(resp.message, "Get All Media");
+        let data = resp.data.expect("Missing response data").properties;
+        assert_eq!(data["media"].as_array().unwrap().len(), 1);
+        assert_eq!(data["media"][0]["id"], media_id);
+
+        let resp = test::call_service(
+            &app,
+            list(format!("search={}&mime_type=image%2Fpng", media_id)),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,104 @@
+use actix_web::{get, web};
+use uuid::Uuid;
+
+use crate::{
+    core::{app_state::AppState, media::find_media},
+    middlewares::permission_middleware::RequirePermission,
+    model::media::MediaModelResponse,
+    schema::response::{
+        api_response::ApiResponse, api_response_error::ApiResponseError,
+        api_response_object::ApiResponseObject,
+    },
+};
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    get,
+    path = "/admin/media/{id}",
+    tag = "Admin: Media Endpoint",
+    params(
+        ("id" = Uuid, Path, description = "UUID of the media to get")
+    ),
+    responses(
+        (status = 200, description= "Get Media By ID", body = ApiResponse),
+        (status = 404, description= "Media not found", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[get("/{id}", wrap = "RequirePermission(\"media.view\")")]
+pub async fn get_media_by_id_handler(
+    path: web::Path<Uuid>,
+    app_state: web::Data<AppState>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let media = find_media(&app_state.pool, &path.into_inner().to_string()).await?;
+
+    let media_response = ApiResponseObject::new(serde_json::json!({
+        "media": MediaModelResponse::filter_db(&media)
+    }))
+    .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        200,
+        "Get Media By Id".to_string(),
+        Some(media_response),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::utils::test_utils::{create_test_app_state, generate_test_jwt, USER_ID},
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::header, http::StatusCode, test, web, App};
+
+    #[actix_web::test]
+    async fn test_get_media_by_id_handler() {
+        let app_state = create_test_app_state().await;
+
+        // Create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .service(web::scope("/admin").configure(routes::admin::media::config)),
+        )
+        .await;
+
+        let media_id = uuid::Uuid::new_v4().to_string();
+        sqlx::query(
+            "INSERT INTO media (id, storage_key, file_name, mime_type, size_bytes, width, height, checksum, uploaded_by) VALUES (?, ?, 'logo.png', 'image/png', 10, 1, 1, ?, ?)",
+        )
+        .bind(&media_id)
+        .bind(format!("media/{}-logo.png", media_id))
+        .bind(format!("{:0>64}", media_id.replace('-', "")))
+        .bind(USER_ID)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test media");
+
+        let get = |media_id: String| {
+            test::TestRequest::get()
+                .uri(&format!("/admin/media/{}", media_id))
+                .insert_header((
+                    header::AUTHORIZATION,
+                    format!("Bearer {}", generate_test_jwt()),
+                ))
+                .to_request()
+        };
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, get(media_id.clone())).await;
+
+        assert_eq!(resp.status, 200);
+        let data = resp.data.expect("Missing response data").properties;
+        assert_eq!(data["media"]["id"], media_id);
+        assert_eq!(data["media"]["fileName"], "logo.png");
+
+        let resp = test::call_service(&app, get(uuid::Uuid::new_v4().to_string())).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+}
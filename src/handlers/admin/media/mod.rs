@@ -0,0 +1,5 @@
+pub mod delete_media;
+pub mod get_media;
+pub mod get_media_by_id;
+pub mod update_media;
+pub mod upload_media;
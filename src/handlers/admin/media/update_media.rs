@@ -0,0 +1,127 @@
+use actix_web::{put, web};
+use uuid::Uuid;
+
+use crate::{
+    core::{
+        app_state::AppState,
+        media::{find_media, validate_alt_text},
+    },
+    middlewares::permission_middleware::RequirePermission,
+    model::media::MediaModelResponse,
+    schema::{
+        admin::media::UpdateMediaSchema,
+        response::{
+            api_response::ApiResponse, api_response_error::ApiResponseError,
+            api_response_object::ApiResponseObject,
+        },
+    },
+};
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    put,
+    path = "/admin/media/update/{id}",
+    tag = "Admin: Media Endpoint",
+    params(
+        ("id" = Uuid, Path, description = "UUID of the media to update"),
+    ),
+    request_body(content = UpdateMediaSchema, description = "Alt text of the media, an empty one clears it", example = json!({"alt_text": "Logo of the company"})),
+    responses(
+        (status = 200, description= "Media Updated", body = ApiResponse),
+        (status = 400, description= "Validation Error", body = ApiResponseError),
+        (status = 404, description= "Media not found", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[put("/update/{id}", wrap = "RequirePermission(\"media.update\")")]
+pub async fn update_media_handler(
+    path: web::Path<Uuid>,
+    body: web::Json<UpdateMediaSchema>,
+    app_state: web::Data<AppState>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let media_id = path.into_inner().to_string();
+    let alt_text = validate_alt_text(body.alt_text.as_deref())?;
+
+    // Fails with 404 before anything is written
+    find_media(&app_state.pool, &media_id).await?;
+
+    sqlx::query("UPDATE media SET alt_text = ?, updated_at = CURRENT_TIMESTAMP WHERE id = ?")
+        .bind(alt_text.as_deref())
+        .bind(&media_id)
+        .execute(&app_state.pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    let media = find_media(&app_state.pool, &media_id).await?;
+    let media_response = ApiResponseObject::new(serde_json::json!({
+        "media": MediaModelResponse::filter_db(&media)
+    }))
+    .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        200,
+        "Media Updated".to_string(),
+        Some(media_response),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::utils::test_utils::{create_test_app_state, generate_test_jwt, USER_ID},
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::header, http::StatusCode, test, web, App};
+    use serde_json::json;
+
+    #[actix_web::test]
+    async fn test_update_media_handler() {
+        let app_state = create_test_app_state().await;
+
+        // Create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .service(web::scope("/admin").configure(routes::admin::media::config)),
+        )
+        .await;
+
+        let media_id = uuid::Uuid::new_v4().to_string();
+        sqlx::query(
+            "INSERT INTO media (id, storage_key, file_name, mime_type, size_bytes, width, height, checksum, uploaded_by) VALUES (?, ?, 'logo.png', 'image/png', 10, 1, 1, ?, ?)",
+        )
+        .bind(&media_id)
+        .bind(format!("media/{}-logo.png", media_id))
+        .bind(format!("{:0>64}", media_id.replace('-', "")))
+        .bind(USER_ID)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test media");
+
+        let update = |data: serde_json::Value| {
+            test::TestRequest::put()
+                .uri(&format!("/admin/media/update/{}", media_id))
+                .insert_header((
+                    header::AUTHORIZATION,
+                    format!("Bearer {}", generate_test_jwt()),
+                ))
+                .set_json(data)
+                .to_request()
+        };
+
+        let resp: ApiResponse =
+            test::call_and_read_body_json(&app, update(json!({"alt_text": " Our logo "}))).await;
+
+        assert_eq!(resp.status, 200);
+        let data = resp.data.expect("Missing response data").properties;
+        assert_eq!(data["media"]["altText"], "Our logo");
+
+        let resp = test::call_service(&app, update(json!({"alt_text": "a".repeat(300)}))).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,175 @@
+use actix_multipart::Multipart;
+use actix_web::{post, web};
+use log::info;
+
+use crate::{
+    config::CONFIG,
+    core::{
+        app_state::AppState,
+        media::{store_media, validate_alt_text},
+        storage::Storage,
+        upload::read_image_form,
+    },
+    middlewares::permission_middleware::RequirePermission,
+    model::media::MediaModelResponse,
+    schema::response::{
+        admin::users::AuthUser, api_response::ApiResponse, api_response_error::ApiResponseError,
+        api_response_object::ApiResponseObject,
+    },
+};
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    post,
+    path = "/admin/media/create",
+    tag = "Admin: Media Endpoint",
+    request_body(content = UploadMediaSchema, description = "Image in the `image` field and its optional `alt_text`", content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description= "Media uploaded", body = ApiResponse),
+        (status = 200, description= "The same image is already in the library, it is returned", body = ApiResponse),
+        (status = 400, description= "Validation Error", body = ApiResponseError),
+        (status = 413, description= "Image is too large", body = ApiResponseError),
+        (status = 415, description= "Image is not a JPEG, PNG, GIF or WebP file", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[post("/create", wrap = "RequirePermission(\"media.create\")")]
+pub async fn upload_media_handler(
+    payload: Multipart,
+    auth: AuthUser,
+    app_state: web::Data<AppState>,
+    storage: web::Data<dyn Storage>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let form = read_image_form(
+        payload,
+        "image",
+        CONFIG.storage.max_upload_bytes,
+        &["alt_text"],
+    )
+    .await?;
+    let alt_text = validate_alt_text(form.fields.get("alt_text").map(String::as_str))?;
+
+    let (media, created) = store_media(
+        &app_state.pool,
+        storage.as_ref(),
+        form.image,
+        alt_text.as_deref(),
+        &auth.id,
+    )
+    .await?;
+    if created {
+        info!("Media {} uploaded by {}", media.id, auth.id);
+    }
+
+    let media_response = ApiResponseObject::new(serde_json::json!({
+        "media": MediaModelResponse::filter_db(&media)
+    }))
+    .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    let (status, message) = if created {
+        (201, "Media Uploaded")
+    } else {
+        (200, "Media Already Exists")
+    };
+    Ok(ApiResponse::new(
+        status,
+        message.to_string(),
+        Some(media_response),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, sync::Arc};
+
+    use crate::{
+        core::{
+            storage::{local::LocalStorage, Storage},
+            utils::test_utils::{create_test_app_state, generate_test_jwt},
+        },
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::header, test, web, App};
+    use image::{ImageFormat, Rgb, RgbImage};
+
+    const BOUNDARY: &str = "media-upload-boundary";
+
+    fn multipart_body(alt_text: &str, bytes: &[u8]) -> Vec<u8> {
+        let mut body = format!(
+            "--{b}\r\nContent-Disposition: form-data; name=\"alt_text\"\r\n\r\n{}\r\n--{b}\r\nContent-Disposition: form-data; name=\"image\"; filename=\"Team Photo.png\"\r\nContent-Type: image/png\r\n\r\n",
+            alt_text,
+            b = BOUNDARY
+        )
+        .into_bytes();
+        body.extend_from_slice(bytes);
+        body.extend_from_slice(format!("\r\n--{}--\r\n", BOUNDARY).as_bytes());
+        body
+    }
+
+    #[actix_web::test]
+    async fn test_upload_media_handler() {
+        let app_state = create_test_app_state().await;
+        let root = std::env::temp_dir().join(format!("uploads-{}", uuid::Uuid::new_v4()));
+        let storage: Arc<dyn Storage> = Arc::new(LocalStorage::new(&root));
+
+        // Create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .app_data(web::Data::from(storage.clone()))
+                .service(web::scope("/admin").configure(routes::admin::media::config)),
+        )
+        .await;
+
+        let upload = |body: Vec<u8>| {
+            test::TestRequest::post()
+                .uri("/admin/media/create")
+                .insert_header((
+                    header::AUTHORIZATION,
+                    format!("Bearer {}", generate_test_jwt()),
+                ))
+                .insert_header((
+                    header::CONTENT_TYPE,
+                    format!("multipart/form-data; boundary={}", BOUNDARY),
+                ))
+                .set_payload(body)
+                .to_request()
+        };
+
+        // The library is shared, every run uploads a new image
+        let seed = uuid::Uuid::new_v4();
+        let bytes = seed.as_bytes();
+        let mut png = Vec::new();
+        RgbImage::from_pixel(3, 2, Rgb([bytes[0], bytes[1], bytes[2]]))
+            .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+            .unwrap();
+
+        let resp: ApiResponse =
+            test::call_and_read_body_json(&app, upload(multipart_body("The team", &png))).await;
+
+        assert_eq!(resp.status, 201);
+        let data = resp.data.expect("Missing response data").properties;
+        let media_id = data["media"]["id"].as_str().unwrap().to_string();
+        assert_eq!(data["media"]["fileName"], "team-photo.png");
+        assert_eq!(data["media"]["mimeType"], "image/png");
+        assert_eq!(data["media"]["altText"], "The team");
+        assert_eq!(data["media"]["width"], 3);
+        assert_eq!(data["media"]["height"], 2);
+
+        // The same file is found by its checksum and not stored again
+        let resp: ApiResponse =
+            test::call_and_read_body_json(&app, upload(multipart_body("Again", &png))).await;
+
+        assert_eq!(resp.status, 200);
+        assert_eq!(resp.message, "Media Already Exists");
+        let data = resp.data.expect("Missing response data").properties;
+        assert_eq!(data["media"]["id"], media_id);
+        assert_eq!(std::fs::read_dir(root.join("media")).unwrap().count(), 1);
+
+        std::fs::remove_dir_all(root).ok();
+    }
+}
@@ -1,3 +1,6 @@
 pub mod applications;
 pub mod contents;
+pub mod files;
+pub mod media;
+pub mod roles;
 pub mod user;
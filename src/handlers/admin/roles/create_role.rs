@@ -0,0 +1,160 @@
+use crate::{
+    core::{
+        app_state::AppState,
+        permission::{
+            find_permissions, find_role, resolve_permission_ids, role_permission_names,
+            set_role_permissions, validate_role_name,
+        },
+    },
+    model::role::RoleModelResponse,
+    schema::{
+        admin::role::CreateRoleSchema,
+        response::{
+            api_response::ApiResponse, api_response_error::ApiResponseError,
+            api_response_object::ApiResponseObject,
+        },
+    },
+};
+use actix_web::{post, web};
+use serde_json::json;
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    post,
+    path = "/admin/roles/create",
+    tag = "Admin: Roles Endpoint",
+    request_body(content = CreateRoleSchema, description = "Role to create with the names of its permissions", example = json!({"name": "editor", "description": "Edits contents", "permissions": ["contents.view", "contents.update"], "requires_two_factor": false})),
+    responses(
+        (status = 201, description= "Role created", body = ApiResponse),
+        (status = 400, description= "Validation Error or Unknown Permission", body = ApiResponseError),
+        (status = 409, description= "Duplicate entry", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[post("/create")]
+pub async fn create_role_handler(
+    data: web::Json<CreateRoleSchema>,
+    app_state: web::Data<AppState>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let name = validate_role_name(&data.name)?;
+    let permission_ids =
+        resolve_permission_ids(&find_permissions(&app_state.pool).await?, &data.permissions)?;
+    let internal_error =
+        |e: sqlx::Error| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None);
+
+    // The role and its permissions are created together
+    let mut tx = app_state.pool.begin().await.map_err(internal_error)?;
+    let insert_result =
+        sqlx::query("INSERT INTO roles (name, description, requires_two_factor) VALUES (?, ?, ?)")
+            .bind(&name)
+            .bind(&data.description)
+            .bind(data.requires_two_factor)
+            .execute(&mut *tx)
+            .await;
+
+    let role_id = match insert_result {
+        Ok(result) => result.last_insert_id() as i32,
+        Err(err) => {
+            if err.to_string().contains("Duplicate entry") {
+                return Err(ApiResponseError::new(
+                    409,
+                    "Role name already exists".to_string(),
+                    None,
+                ));
+            }
+            return Err(internal_error(err));
+        }
+    };
+
+    set_role_permissions(&mut tx, role_id, &permission_ids)
+        .await
+        .map_err(internal_error)?;
+    tx.commit().await.map_err(internal_error)?;
+
+    let role = find_role(&app_state.pool, role_id).await?;
+    let permissions = role_permission_names(&app_state.pool, role_id).await?;
+
+    let role_response = ApiResponseObject::new(json!({
+        "role": RoleModelResponse::filter_db(&role, permissions)
+    }))
+    .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        201,
+        "Role Created".to_string(),
+        Some(role_response),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::utils::test_utils::{create_test_app_state, generate_test_jwt},
+        middlewares::auth_middleware::RequireAuth,
+        routes,
+        schema::{admin::role::CreateRoleSchema, response::api_response::ApiResponse},
+    };
+    use actix_web::{http::StatusCode, test, web, App};
+
+    #[actix_web::test]
+    async fn test_create_role_handler() {
+        let app_state = create_test_app_state().await;
+
+        // create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .wrap(RequireAuth {})
+                .service(web::scope("/admin").configure(routes::admin::roles::config)),
+        )
+        .await;
+
+        let create_role_data = CreateRoleSchema {
+            name: format!("editor.{}", &uuid::Uuid::new_v4().to_string()[..8]),
+            description: Some("Edits contents".to_string()),
+            permissions: vec!["contents.view".to_string(), "contents.update".to_string()],
+            requires_two_factor: false,
+        };
+
+        let req = test::TestRequest::post()
+            .uri("/admin/roles/create")
+            .insert_header((
+                actix_web::http::header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .set_json(&create_role_data)
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 201);
+        let data = resp.data.expect("Missing response data").properties;
+        assert_eq!(data["role"]["name"], create_role_data.name);
+        assert_eq!(
+            data["role"]["permissions"],
+            serde_json::json!(["contents.update", "contents.view"])
+        );
+
+        // Unknown permissions are rejected
+        let req = test::TestRequest::post()
+            .uri("/admin/roles/create")
+            .insert_header((
+                actix_web::http::header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .set_json(&CreateRoleSchema {
+                name: format!("editor.{}", &uuid::Uuid::new_v4().to_string()[..8]),
+                description: None,
+                permissions: vec!["contents.publish.everything".to_string()],
+                requires_two_factor: false,
+            })
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+}
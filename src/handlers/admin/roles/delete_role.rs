@@ -0,0 +1,115 @@
+use actix_web::{delete, web};
+
+use crate::{
+    core::{app_state::AppState, permission::find_role, policy::ensure_unprotected},
+    schema::response::{api_response::ApiResponse, api_response_error::ApiResponseError},
+};
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    delete,
+    path = "/admin/roles/delete/{id}",
+    tag = "Admin: Roles Endpoint",
+    params(
+        ("id" = i32, Path, description = "ID of the role"),
+    ),
+    responses(
+        (status = 204, description= "Role Deleted", body = ApiResponse),
+        (status = 403, description= "Role Is Protected", body = ApiResponseError),
+        (status = 404, description= "Role Not Found", body = ApiResponseError),
+        (status = 409, description= "Role Is Assigned To Users", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[delete("/delete/{id}")]
+pub async fn delete_role_handler(
+    path: web::Path<i32>,
+    app_state: web::Data<AppState>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let role = find_role(&app_state.pool, path.into_inner()).await?;
+
+    ensure_unprotected(&role)?;
+
+    let users_count = sqlx::query_scalar!("SELECT COUNT(*) FROM users WHERE role_id = ?", role.id)
+        .fetch_one(&app_state.pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal server error: {}", e), None))?;
+
+    if users_count > 0 {
+        return Err(ApiResponseError::new(
+            409,
+            format!("Role is assigned to {} users", users_count),
+            None,
+        ));
+    }
+
+    // The permissions of the role are removed by the foreign key
+    sqlx::query("DELETE FROM roles WHERE id = ?")
+        .bind(role.id)
+        .execute(&app_state.pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal server error: {}", e), None))?;
+
+    Ok(ApiResponse::new(204, "Role deleted".to_string(), None))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::utils::test_utils::{create_test_app_state, generate_test_jwt},
+        middlewares::auth_middleware::RequireAuth,
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::StatusCode, test, web, App};
+
+    #[actix_web::test]
+    async fn test_delete_role_handler() {
+        let app_state = create_test_app_state().await;
+
+        // create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .wrap(RequireAuth {})
+                .service(web::scope("/admin").configure(routes::admin::roles::config)),
+        )
+        .await;
+
+        // insert test role data into the database
+        let role_id = sqlx::query("INSERT INTO roles (name) VALUES (?)")
+            .bind(format!("test.{}", &uuid::Uuid::new_v4().to_string()[..8]))
+            .execute(&app_state.pool)
+            .await
+            .expect("Failed to insert test role")
+            .last_insert_id();
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/admin/roles/delete/{}", role_id))
+            .insert_header((
+                actix_web::http::header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 204);
+
+        // The seeded roles cannot be deleted
+        let req = test::TestRequest::delete()
+            .uri("/admin/roles/delete/2")
+            .insert_header((
+                actix_web::http::header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+}
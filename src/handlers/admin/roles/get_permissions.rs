@@ -0,0 +1,83 @@
+use crate::{
+    core::{app_state::AppState, permission::find_permissions},
+    schema::response::{
+        api_response::ApiResponse, api_response_error::ApiResponseError,
+        api_response_object::ApiResponseObject,
+    },
+};
+use actix_web::{get, web};
+use serde_json::json;
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    get,
+    path = "/admin/permissions",
+    tag = "Admin: Roles Endpoint",
+    responses(
+        (status = 200, description= "Get All Permissions That Can Be Granted To Roles", body = ApiResponse),
+        (status = 401, description= "Unauthorized", body = ApiResponseError),
+        (status = 403, description= "Missing Permission", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[get("")]
+pub async fn get_permissions_handler(
+    app_state: web::Data<AppState>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let permissions = find_permissions(&app_state.pool).await?;
+
+    let permissions_response = ApiResponseObject::new(json!({ "permissions": permissions }))
+        .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        200,
+        "Get All Permissions".to_string(),
+        Some(permissions_response),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::utils::test_utils::{create_test_app_state, generate_test_jwt},
+        middlewares::auth_middleware::RequireAuth,
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{test, web, App};
+
+    #[actix_web::test]
+    async fn test_get_permissions_handler() {
+        let app_state = create_test_app_state().await;
+
+        // create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .wrap(RequireAuth {})
+                .service(web::scope("/admin").configure(routes::admin::roles::config)),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/admin/permissions")
+            .insert_header((
+                actix_web::http::header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 200);
+        let data = resp.data.expect("Missing response data").properties;
+        assert!(data["permissions"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|permission| permission["name"] == "contents.update"));
+    }
+}
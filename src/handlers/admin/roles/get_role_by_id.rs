@@ -0,0 +1,105 @@
+use crate::{
+    core::{
+        app_state::AppState,
+        permission::{find_role, role_permission_names},
+    },
+    model::role::RoleModelResponse,
+    schema::response::{
+        api_response::ApiResponse, api_response_error::ApiResponseError,
+        api_response_object::ApiResponseObject,
+    },
+};
+use actix_web::{get, web};
+use serde_json::json;
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    get,
+    path = "/admin/roles/{id}",
+    tag = "Admin: Roles Endpoint",
+    params(
+        ("id" = i32, Path, description = "ID of the role to get")
+    ),
+    responses(
+        (status = 200, description= "Get Role By ID", body = ApiResponse),
+        (status = 404, description= "Role Not Found", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[get("/{id}")]
+pub async fn get_role_by_id_handler(
+    path: web::Path<i32>,
+    app_state: web::Data<AppState>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let role = find_role(&app_state.pool, path.into_inner()).await?;
+    let permissions = role_permission_names(&app_state.pool, role.id).await?;
+
+    let role_response = ApiResponseObject::new(json!({
+        "role": RoleModelResponse::filter_db(&role, permissions)
+    }))
+    .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        200,
+        "Get Role By Id".to_string(),
+        Some(role_response),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::utils::test_utils::{create_test_app_state, generate_test_jwt},
+        middlewares::auth_middleware::RequireAuth,
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::StatusCode, test, web, App};
+
+    #[actix_web::test]
+    async fn test_get_role_by_id_handler() {
+        let app_state = create_test_app_state().await;
+
+        // create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .wrap(RequireAuth {})
+                .service(web::scope("/admin").configure(routes::admin::roles::config)),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/admin/roles/2")
+            .insert_header((
+                actix_web::http::header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 200);
+        let data = resp.data.expect("Missing response data").properties;
+        assert_eq!(data["role"]["name"], "moderator");
+        assert_eq!(
+            data["role"]["permissions"],
+            serde_json::json!(["contents.update", "contents.view"])
+        );
+
+        let req = test::TestRequest::get()
+            .uri("/admin/roles/0")
+            .insert_header((
+                actix_web::http::header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{
+    core::{app_state::AppState, permission::role_permission_names},
+    model::role::{RoleModel, RoleModelResponse},
+    schema::response::{
+        api_response::ApiResponse, api_response_error::ApiResponseError,
+        api_response_object::ApiResponseObject,
+    },
+};
+use actix_web::{get, web};
+use serde_json::json;
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    get,
+    path = "/admin/roles",
+    tag = "Admin: Roles Endpoint",
+    responses(
+        (status = 200, description= "Get All Roles With Their Permissions", body = ApiResponse),
+        (status = 401, description= "Unauthorized", body = ApiResponseError),
+        (status = 403, description= "Missing Permission", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[get("")]
+pub async fn get_roles_handler(
+    app_state: web::Data<AppState>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let roles = sqlx::query_as!(RoleModel, "SELECT * FROM roles ORDER BY id")
+        .fetch_all(&app_state.pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    let mut roles_response = Vec::with_capacity(roles.len());
+    for role in &roles {
+        let permissions = role_permission_names(&app_state.pool, role.id).await?;
+        roles_response.push(RoleModelResponse::filter_db(role, permissions));
+    }
+
+    let roles_response = ApiResponseObject::new(json!({ "roles": roles_response }))
+        .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        200,
+        "Get All Roles".to_string(),
+        Some(roles_response),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::utils::test_utils::{create_test_app_state, generate_test_jwt},
+        middlewares::auth_middleware::RequireAuth,
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{test, web, App};
+
+    #[actix_web::test]
+    async fn test_get_roles_handler() {
+        let app_state = create_test_app_state().await;
+
+        // create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .wrap(RequireAuth {})
+                .service(web::scope("/admin").configure(routes::admin::roles::config)),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/admin/roles")
+            .insert_header((
+                actix_web::http::header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 200);
+        let data = resp.data.expect("Missing response data").properties;
+        let admin = &data["roles"][0];
+        assert_eq!(admin["name"], "admin");
+        assert!(admin["permissions"]
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!("roles.manage")));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod create_role;
+pub mod delete_role;
+pub mod get_permissions;
+pub mod get_role_by_id;
+pub mod get_roles;
+pub mod update_role;
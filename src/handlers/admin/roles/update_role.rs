@@ -0,0 +1,262 @@
+use crate::{
+    core::{
+        app_state::AppState,
+        permission::{
+            find_permissions, find_role, resolve_permission_ids, role_permission_names,
+            set_role_permissions, validate_role_name,
+        },
+        policy::authorize_protected_update,
+    },
+    model::role::RoleModelResponse,
+    schema::{
+        admin::role::UpdateRoleSchema,
+        response::{
+            admin::users::AuthUser, api_response::ApiResponse,
+            api_response_error::ApiResponseError, api_response_object::ApiResponseObject,
+        },
+    },
+};
+use actix_web::{put, web};
+use serde_json::json;
+
+#[utoipa::path(
+    put,
+    path = "/admin/roles/update/{id}",
+    tag = "Admin: Roles Endpoint",
+    params(
+        ("id" = i32, Path, description = "ID of the role to update"),
+    ),
+    request_body(content = UpdateRoleSchema, description = "Role fields to update, permissions replaces all the permissions of the role", example = json!({"description": "Edits and publishes contents", "permissions": ["contents.view", "contents.update", "contents.create"], "requires_two_factor": true})),
+    responses(
+        (status = 200, description= "Role Updated", body = ApiResponse),
+        (status = 400, description= "Validation Error or Unknown Permission", body = ApiResponseError),
+        (status = 403, description= "Role Is Protected Or Missing records.protect To Change It", body = ApiResponseError),
+        (status = 404, description= "Role Not Found", body = ApiResponseError),
+        (status = 409, description= "Duplicate entry", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[put("/update/{id}")]
+pub async fn update_role_handler(
+    path: web::Path<i32>,
+    auth: AuthUser,
+    app_state: web::Data<AppState>,
+    body: web::Json<UpdateRoleSchema>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let role = find_role(&app_state.pool, path.into_inner()).await?;
+
+    // The seeded roles are protected, so admins cannot lock themselves out
+    authorize_protected_update(&app_state.pool, &auth, &role, body.protected).await?;
+
+    let name = body.name.as_deref().map(validate_role_name).transpose()?;
+
+    let permission_ids = match body.permissions {
+        Some(ref permissions) => Some(resolve_permission_ids(
+            &find_permissions(&app_state.pool).await?,
+            permissions,
+        )?),
+        None => None,
+    };
+    let internal_error =
+        |e: sqlx::Error| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None);
+
+    let mut tx = app_state.pool.begin().await.map_err(internal_error)?;
+    let update_result = sqlx::query(
+        "UPDATE roles SET name = ?, description = ?, requires_two_factor = ?, protected = ? WHERE id = ?",
+    )
+    .bind(name.unwrap_or_else(|| role.name.clone()))
+    .bind(
+        body.description
+            .clone()
+            .or_else(|| role.description.clone()),
+    )
+    .bind(
+        body.requires_two_factor
+            .unwrap_or(role.requires_two_factor != 0),
+    )
+    .bind(body.protected.unwrap_or(role.protected != 0))
+    .bind(role.id)
+    .execute(&mut *tx)
+    .await;
+
+    if let Err(err) = update_result {
+        if err.to_string().contains("Duplicate entry") {
+            return Err(ApiResponseError::new(
+                409,
+                "Role name already exists".to_string(),
+                None,
+            ));
+        }
+        return Err(internal_error(err));
+    }
+
+    if let Some(permission_ids) = permission_ids {
+        set_role_permissions(&mut tx, role.id, &permission_ids)
+            .await
+            .map_err(internal_error)?;
+    }
+    tx.commit().await.map_err(internal_error)?;
+
+    let role = find_role(&app_state.pool, role.id).await?;
+    let permissions = role_permission_names(&app_state.pool, role.id).await?;
+
+    let role_response = ApiResponseObject::new(json!({
+        "role": RoleModelResponse::filter_db(&role, permissions)
+    }))
+    .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        200,
+        "Role Updated".to_string(),
+        Some(role_response),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::utils::test_utils::{create_test_app_state, generate_jwt, generate_test_jwt},
+        middlewares::auth_middleware::RequireAuth,
+        routes,
+        schema::{admin::role::UpdateRoleSchema, response::api_response::ApiResponse},
+    };
+    use actix_web::{http::StatusCode, test, web, App};
+    use rand::Rng;
+    use serde_json::json;
+
+    #[actix_web::test]
+    async fn test_update_role_handler() {
+        let app_state = create_test_app_state().await;
+
+        // create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .wrap(RequireAuth {})
+                .service(web::scope("/admin").configure(routes::admin::roles::config)),
+        )
+        .await;
+
+        // insert test role data into the database
+        let role_id = sqlx::query("INSERT INTO roles (name) VALUES (?)")
+            .bind(format!("test.{}", &uuid::Uuid::new_v4().to_string()[..8]))
+            .execute(&app_state.pool)
+            .await
+            .expect("Failed to insert test role")
+            .last_insert_id();
+
+        let update_role_data = UpdateRoleSchema {
+            name: None,
+            description: Some("Views contents".to_string()),
+            permissions: Some(vec!["contents.view".to_string()]),
+            requires_two_factor: Some(true),
+            protected: None,
+        };
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/admin/roles/update/{}", role_id))
+            .insert_header((
+                actix_web::http::header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .set_json(&update_role_data)
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 200);
+        let data = resp.data.expect("Missing response data").properties;
+        assert_eq!(data["role"]["description"], "Views contents");
+        assert_eq!(
+            data["role"]["permissions"],
+            serde_json::json!(["contents.view"])
+        );
+        assert_eq!(data["role"]["requires_two_factor"], true);
+
+        // The seeded admin role is protected, it keeps all of its permissions
+        let req = test::TestRequest::put()
+            .uri("/admin/roles/update/1")
+            .insert_header((
+                actix_web::http::header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .set_json(&update_role_data)
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        // Managing roles is not enough to change a protected role
+        let manager_role_id = sqlx::query("INSERT INTO roles (name) VALUES (?)")
+            .bind(format!("roles.{}", &uuid::Uuid::new_v4().to_string()[..8]))
+            .execute(&app_state.pool)
+            .await
+            .expect("Failed to insert test role")
+            .last_insert_id();
+        sqlx::query(
+            "INSERT INTO role_permissions (role_id, permission_id) SELECT ?, id FROM permissions WHERE name = 'roles.manage'",
+        )
+        .bind(manager_role_id)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to grant test permission");
+
+        let manager_id = uuid::Uuid::new_v4().to_string();
+        sqlx::query("INSERT INTO users (id, name, mobile, role_id) VALUES (?, ?, ?, ?)")
+            .bind(&manager_id)
+            .bind(format!("Roles Manager {}", &manager_id[..8]))
+            .bind(format!(
+                "+96650{}",
+                rand::thread_rng().gen_range(1000000..9999999)
+            ))
+            .bind(manager_role_id)
+            .execute(&app_state.pool)
+            .await
+            .expect("Failed to insert test user");
+
+        sqlx::query("UPDATE roles SET protected = 1 WHERE id = ?")
+            .bind(role_id)
+            .execute(&app_state.pool)
+            .await
+            .expect("Failed to protect test role");
+
+        let update = |jwt: String, data: serde_json::Value| {
+            test::TestRequest::put()
+                .uri(&format!("/admin/roles/update/{}", role_id))
+                .insert_header((
+                    actix_web::http::header::AUTHORIZATION,
+                    format!("Bearer {}", jwt),
+                ))
+                .set_json(data)
+                .to_request()
+        };
+        let permissions = json!(["contents.update", "contents.view"]);
+
+        for data in [
+            json!({ "permissions": permissions }),
+            json!({ "requires_two_factor": false }),
+            json!({ "permissions": permissions, "protected": false }),
+        ] {
+            let resp = test::call_service(&app, update(generate_jwt(&manager_id), data)).await;
+            assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        }
+
+        // Users with records.protect change it in the update that unprotects it
+        let resp: ApiResponse = test::call_and_read_body_json(
+            &app,
+            update(
+                generate_test_jwt(),
+                json!({ "permissions": permissions, "protected": false }),
+            ),
+        )
+        .await;
+        assert_eq!(resp.status, 200);
+        let data = resp.data.expect("Missing response data").properties;
+        assert_eq!(data["role"]["permissions"], permissions);
+        assert_eq!(data["role"]["protected"], false);
+    }
+}
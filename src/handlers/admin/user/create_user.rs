@@ -1,5 +1,5 @@
 use crate::{
-    core::app_state::AppState,
+    core::{app_state::AppState, phone::normalize_mobile},
     model::user::UserModel,
     schema::{
         admin::user::CreateUserSchema,
@@ -18,10 +18,11 @@ use serde_json::json;
     path = "/admin/users/create",
     tag = "Admin: Users Endpoint",
     // Specify request body details
-    request_body(content = CreateUserSchema, description = "Credentials to create user", example = json!({"name": "test name", "mobile": "051234567","email": "test@test.test", "gender": "Male", "configurations": json!({"property1": "value", "property2": json!({"sub-property": "value"})})}) ),
+    request_body(content = CreateUserSchema, description = "Credentials to create user", example = json!({"name": "test name", "mobile": "0501234567","email": "test@test.test", "gender": "Male", "configurations": json!({"property1": "value", "property2": json!({"sub-property": "value"})})}) ),
     // Specify possible responses
     responses(
         (status = 201, description= "User created", body = ApiResponse),       
+        (status = 400, description= "Validation Error", body = ApiResponseError),       
         (status = 409, description= "Duplicate entry", body = ApiResponseError),       
         (status = 500, description= "Internal Server Error", body = ApiResponseError),       
     ),
@@ -36,6 +37,7 @@ pub async fn create_user_handler(
     app_state: web::Data<AppState>,    // Application state containing database pool
 ) -> Result<ApiResponse, ApiResponseError> {
     let user_id = uuid::Uuid::new_v4().to_string(); // Generate a new UUID for the user
+    let mobile = normalize_mobile(&data.mobile)?; // Store the mobile in E.164 format
 
     // Convert configurations to JSON string if present
 
@@ -44,7 +46,7 @@ pub async fn create_user_handler(
         sqlx::query("INSERT INTO users (id, name, mobile, email) VALUES (?, ?, ?, ?, ?)")
             .bind(&user_id)
             .bind(&data.name.as_deref())
-            .bind(&data.mobile)
+            .bind(&mobile)
             .bind(data.email.as_deref())
             .execute(&app_state.pool)
             .await;
@@ -119,6 +121,9 @@ mod tests {
             exp: (now + expire).timestamp() as usize,
             iat: now.timestamp() as usize,
             id: "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e2b".to_owned(),
+            jti: uuid::Uuid::new_v4().to_string(),
+            sid: None,
+            impersonator: None,
         };
         let encoding_key = jsonwebtoken::EncodingKey::from_secret(CONFIG.jwt.secret.as_ref());
         jsonwebtoken::encode(&jsonwebtoken::Header::default(), &claims, &encoding_key).unwrap()
@@ -151,7 +156,7 @@ mod tests {
 
         let jwt = generate_jwt();
 
-        let mobile = format!("9665{}", rand::thread_rng().gen_range(10000000..99999999));
+        let mobile = format!("+96650{}", rand::thread_rng().gen_range(1000000..9999999));
         let create_user_data = CreateUserSchema {
             name: Some(Name().fake()),
             mobile,
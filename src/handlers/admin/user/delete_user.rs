@@ -3,7 +3,8 @@ use log::info;
 use uuid::Uuid;
 
 use crate::{
-    core::app_state::AppState,
+    core::{app_state::AppState, policy::ensure_unprotected, session::revoke_user_sessions},
+    model::user::UserModel,
     schema::response::{api_response::ApiResponse, api_response_error::ApiResponseError},
 };
 
@@ -19,6 +20,7 @@ use crate::{
     // Specify possible responses
     responses(
         (status = 204, description= "User Deleted"),       
+        (status = 403, description= "User Is Protected", body = ApiResponseError),       
         (status = 404, description= "User Not Found", body = ApiResponseError),       
         (status = 500, description= "Internal Server Error", body = ApiResponseError),       
     ),
@@ -34,37 +36,43 @@ pub async fn delete_user_handler(
 ) -> Result<ApiResponse, ApiResponseError> {
     let user_id = path.into_inner().to_string(); // Extract the UUID from the path parameter
 
-    // Execute SQL query to delete user from the database
-    let query_result = sqlx::query!("DELETE FROM users WHERE id = ?", user_id)
-        .execute(&app_state.pool)
-        .await;
-
-    // Handle the result of the SQL query
-    match query_result {
-        Ok(result) => {
-            if result.rows_affected() == 0 {
-                // Return a not found error if no user was deleted
-                return Err(ApiResponseError::new(
-                    404,
-                    format!("No user found with id {}", user_id),
-                    None,
-                ));
-            } else {
-                // Log the number of rows affected by the delete operation
-                info!("Deleted user with id: {}", user_id);
-                // Return a successful API response indicating user deletion
-                return Ok(ApiResponse::new(204, format!("User deleted"), None));
-            }
-        }
-        Err(e) => {
-            // Return a generic internal server error for database errors
-            return Err(ApiResponseError::new(
-                500,
-                format!("Internal server error: {}", e),
-                None,
-            ));
-        }
+    let user = sqlx::query_as!(
+        UserModel,
+        "SELECT * FROM users WHERE id = ? AND deleted_at IS NULL",
+        user_id
+    )
+    .fetch_optional(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal server error: {}", e), None))?
+    .ok_or_else(|| {
+        ApiResponseError::new(404, format!("No user found with id {}", user_id), None)
+    })?;
+
+    ensure_unprotected(&user)?;
+
+    // Soft delete the user, it can be restored or purged later
+    let query_result = sqlx::query(
+        "UPDATE users SET deleted_at = CURRENT_TIMESTAMP WHERE id = ? AND deleted_at IS NULL",
+    )
+    .bind(&user_id)
+    .execute(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal server error: {}", e), None))?;
+
+    if query_result.rows_affected() == 0 {
+        // Return a not found error if no user was deleted
+        return Err(ApiResponseError::new(
+            404,
+            format!("No user found with id {}", user_id),
+            None,
+        ));
     }
+
+    // Sign the user out, the auth middlewares also reject deleted users
+    revoke_user_sessions(&app_state.pool, &user_id).await?;
+    info!("Deleted user with id: {}", user_id);
+
+    Ok(ApiResponse::new(204, "User deleted".to_string(), None))
 }
 
 #[cfg(test)]
@@ -90,6 +98,9 @@ mod tests {
             exp: (now + expire).timestamp() as usize,
             iat: now.timestamp() as usize,
             id: "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e2b".to_owned(),
+            jti: uuid::Uuid::new_v4().to_string(),
+            sid: None,
+            impersonator: None,
         };
         let encoding_key = jsonwebtoken::EncodingKey::from_secret(CONFIG.jwt.secret.as_ref());
         jsonwebtoken::encode(&jsonwebtoken::Header::default(), &claims, &encoding_key).unwrap()
@@ -127,7 +138,7 @@ mod tests {
         let user_id = uuid::Uuid::new_v4().to_string();
         let create_user_data = CreateUserSchema {
             name: Some(Name().fake()),
-            mobile: format!("9665{}", rand::thread_rng().gen_range(10000000..99999999)),
+            mobile: format!("+96650{}", rand::thread_rng().gen_range(1000000..9999999)),
             email: Some(SafeEmail().fake()),
         };
 
@@ -158,15 +169,39 @@ mod tests {
         assert_eq!(resp.status, 204);
         assert_eq!(resp.message, "User deleted");
 
-        // Verify the user was deleted from the database
+        // Verify the user was soft deleted, the row is kept
         let deleted_user = sqlx::query_as!(UserModel, "SELECT * FROM users WHERE id = ?", user_id)
-            .fetch_optional(&app_state.pool)
+            .fetch_one(&app_state.pool)
             .await
             .expect("Failed to fetch deleted user");
 
         assert!(
-            deleted_user.is_none(),
-            "User should not exist in the database"
+            deleted_user.deleted_at.is_some(),
+            "User should be marked as deleted"
         );
+
+        // Deleting the user again finds nothing
+        let req = test::TestRequest::delete()
+            .uri(&format!("/admin/users/delete/{}", user_id))
+            .insert_header((
+                actix_web::http::header::AUTHORIZATION,
+                format!("Bearer {}", jwt),
+            ))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+
+        // Protected users cannot be deleted
+        let req = test::TestRequest::delete()
+            .uri("/admin/users/delete/a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e2b")
+            .insert_header((
+                actix_web::http::header::AUTHORIZATION,
+                format!("Bearer {}", jwt),
+            ))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::FORBIDDEN);
     }
 }
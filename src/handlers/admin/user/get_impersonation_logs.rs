@@ -0,0 +1,141 @@
+use actix_web::{get, web, HttpResponse, Responder};
+use uuid::Uuid;
+
+use crate::{
+    core::app_state::AppState,
+    model::impersonation_log::ImpersonationLogModel,
+    schema::{
+        admin::user::ImpersonationLogsFilterOptions,
+        response::{
+            api_response_collection::ApiResponseCollection, api_response_error::ApiResponseError,
+            api_response_object::ApiResponseObject, Pagination,
+        },
+    },
+};
+
+// Endpoint handler listing the impersonation audit trail of a user, as target or as impersonator
+#[utoipa::path(
+    get,
+    path = "/admin/users/{id}/impersonations",
+    tag = "Admin: Users Endpoint",
+    params(
+        ("id" = Uuid, Path, description = "UUID of the impersonated user or of the impersonating admin"),
+        ImpersonationLogsFilterOptions
+    ),
+    responses(
+        (status = 200, description= "Get Impersonation Logs", body = ApiResponseCollection),
+        (status = 401, description= "Unauthorized", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[get("/{id}/impersonations")]
+pub async fn get_impersonation_logs_handler(
+    path: web::Path<Uuid>,
+    opts: web::Query<ImpersonationLogsFilterOptions>,
+    app_state: web::Data<AppState>,
+) -> Result<impl Responder, ApiResponseError> {
+    let user_id = path.into_inner().to_string();
+    let limit = opts.limit.unwrap_or(10);
+    let page = opts.page.unwrap_or(1);
+    let offset = (page - 1) * limit;
+
+    let logs = sqlx::query_as!(
+        ImpersonationLogModel,
+        "SELECT * FROM impersonation_logs WHERE user_id = ? OR impersonator_id = ? ORDER BY id DESC LIMIT ? OFFSET ?",
+        user_id,
+        user_id,
+        limit,
+        offset
+    )
+    .fetch_all(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    let total_items = sqlx::query_scalar!(
+        "SELECT COUNT(*) FROM impersonation_logs WHERE user_id = ? OR impersonator_id = ?",
+        user_id,
+        user_id
+    )
+    .fetch_one(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    let total_pages = (total_items as f64 / limit as f64).ceil() as i64;
+    let pagination = Pagination {
+        total_items,
+        total_pages,
+        current_page: page,
+        per_page: limit,
+    };
+
+    let json_response = ApiResponseObject::new(serde_json::json!({
+        "impersonations": logs,
+    }))
+    .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(HttpResponse::Ok().json(ApiResponseCollection::new(
+        200,
+        "Get Impersonation Logs".to_string(),
+        Some(json_response),
+        Some(pagination),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::{
+            impersonation::{record_impersonation, ImpersonationEvent, EVENT_ISSUED},
+            utils::test_utils::{create_test_app_state, generate_test_jwt, USER_ID},
+        },
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::header, test, web, App};
+
+    #[actix_web::test]
+    async fn test_get_impersonation_logs_handler() {
+        let app_state = create_test_app_state().await;
+
+        // Create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .service(web::scope("/admin").configure(routes::admin::user::config)),
+        )
+        .await;
+
+        let target_id = "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e24";
+        record_impersonation(
+            &app_state.pool,
+            &ImpersonationEvent {
+                impersonator_id: USER_ID,
+                user_id: target_id,
+                token_id: &uuid::Uuid::new_v4().to_string(),
+                event: EVENT_ISSUED,
+                method: "POST",
+                path: "/admin/users/impersonate",
+                ip_address: None,
+            },
+        )
+        .await
+        .expect("Failed to record impersonation");
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/admin/users/{}/impersonations", target_id))
+            .insert_header((
+                header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 200);
+        let data = resp.data.expect("Missing response data").properties;
+        assert_eq!(data["impersonations"][0]["user_id"], target_id);
+    }
+}
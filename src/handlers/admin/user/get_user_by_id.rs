@@ -86,6 +86,9 @@ mod tests {
             exp: (now + expire).timestamp() as usize,
             iat: now.timestamp() as usize,
             id: "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e2b".to_owned(),
+            jti: uuid::Uuid::new_v4().to_string(),
+            sid: None,
+            impersonator: None,
         };
         let encoding_key = jsonwebtoken::EncodingKey::from_secret(CONFIG.jwt.secret.as_ref());
         jsonwebtoken::encode(&jsonwebtoken::Header::default(), &claims, &encoding_key).unwrap()
@@ -120,7 +123,7 @@ mod tests {
 
         // Insert test user data into the database
         let user_id = uuid::Uuid::new_v4().to_string();
-        let mobile = format!("9665{}", rand::thread_rng().gen_range(10000000..99999999));
+        let mobile = format!("+96650{}", rand::thread_rng().gen_range(1000000..9999999));
         let create_user_data = CreateUserSchema {
             name: Some(Name().fake()),
             mobile: mobile.clone(),
@@ -1,7 +1,9 @@
 use actix_web::{get, web, HttpResponse, Responder};
 
 use crate::{
-    core::{app_state::AppState, utils::export_to_csv::export_to_csv},
+    core::{
+        app_state::AppState, soft_delete::trashed_condition, utils::export_to_csv::export_to_csv,
+    },
     model::user::UserModel,
     schema::{
         admin::user::UsersFilterOptions,
@@ -44,6 +46,11 @@ pub async fn get_users_handler(
     let mut query = "SELECT * FROM users".to_string();
     let mut conditions = Vec::new();
 
+    // Soft deleted users are hidden unless asked for
+    if let Some(condition) = trashed_condition(opts.with_trashed, opts.only_trashed) {
+        conditions.push(condition.to_string());
+    }
+
     // Adds SQL condition for `mobile` parameter if provided
     if let Some(ref mobile) = opts.mobile {
         conditions.push(format!("mobile LIKE '%{}%'", mobile));
@@ -154,6 +161,9 @@ mod tests {
             exp: (now + expire).timestamp() as usize,
             iat: now.timestamp() as usize,
             id: "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e2b".to_owned(),
+            jti: uuid::Uuid::new_v4().to_string(),
+            sid: None,
+            impersonator: None,
         };
         let encoding_key = jsonwebtoken::EncodingKey::from_secret(CONFIG.jwt.secret.as_ref());
         jsonwebtoken::encode(&jsonwebtoken::Header::default(), &claims, &encoding_key).unwrap()
@@ -187,7 +197,7 @@ mod tests {
         let jwt = generate_jwt();
 
         // Insert test data into the database
-        let mobile = format!("9665{}", rand::thread_rng().gen_range(10000000..99999999));
+        let mobile = format!("+96650{}", rand::thread_rng().gen_range(1000000..9999999));
         let create_user_data = CreateUserSchema {
             name: Some(Name().fake()),
             mobile: mobile.clone(),
@@ -0,0 +1,242 @@
+use actix_web::{post, web, HttpRequest};
+use log::info;
+use uuid::Uuid;
+
+use crate::{
+    core::{
+        app_state::AppState,
+        impersonation::{
+            authorize_impersonation, impersonation_token_duration, record_impersonation,
+            ImpersonationEvent, EVENT_ISSUED,
+        },
+        session::ClientInfo,
+        utils::jwt::encode_impersonation_jwt,
+    },
+    model::user::UserModel,
+    schema::response::{
+        admin::users::{AuthUser, UserModelResponse},
+        api_response::ApiResponse,
+        api_response_error::ApiResponseError,
+        api_response_object::ApiResponseObject,
+    },
+};
+
+// Endpoint handler issuing a short-lived token to act as a user
+#[utoipa::path(
+    post,
+    path = "/admin/users/impersonate/{id}",
+    tag = "Admin: Users Endpoint",
+    params(
+        ("id" = Uuid, Path, description = "UUID of the user to impersonate", example = "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e24")
+    ),
+    responses(
+        (status = 200, description= "Impersonation token issued, it can only read and every request made with it is audited", body = ApiResponse),
+        (status = 400, description= "Cannot Impersonate Yourself", body = ApiResponseError),
+        (status = 403, description= "User Is Protected Or Manages Users", body = ApiResponseError),
+        (status = 404, description= "User Not Found", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[post("/impersonate/{id}")]
+pub async fn impersonate_user_handler(
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    auth: AuthUser,
+    app_state: web::Data<AppState>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let user_id = path.into_inner().to_string();
+
+    // Deleted users cannot sign in, so they cannot be impersonated either
+    let mut user = sqlx::query_as!(
+        UserModel,
+        "SELECT * FROM users WHERE id = ? AND deleted_at IS NULL",
+        user_id
+    )
+    .fetch_optional(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal server error: {}", e), None))?
+    .ok_or_else(|| {
+        ApiResponseError::new(404, format!("User with ID: {} not found", user_id), None)
+    })?;
+
+    authorize_impersonation(&app_state.pool, &auth.id, &user).await?;
+
+    let duration = impersonation_token_duration();
+    let (token, claims) = encode_impersonation_jwt(user.id.clone(), auth.id.clone(), duration)
+        .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    record_impersonation(
+        &app_state.pool,
+        &ImpersonationEvent {
+            impersonator_id: &auth.id,
+            user_id: &user.id,
+            token_id: &claims.jti,
+            event: EVENT_ISSUED,
+            method: req.method().as_str(),
+            path: req.path(),
+            ip_address: ClientInfo::from_request(&req).ip_address,
+        },
+    )
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal server error: {}", e), None))?;
+    info!(
+        "Admin with id: {} is impersonating user with id: {}",
+        auth.id, user.id
+    );
+
+    let response_body = ApiResponseObject::new(serde_json::json!({
+        "token": token,
+        "expires_in": duration.num_seconds(),
+        "user": UserModelResponse::filter_db(&mut user),
+    }))
+    .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        200,
+        "Impersonation token issued".to_string(),
+        Some(response_body),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::utils::test_utils::{create_test_app_state, generate_test_jwt, USER_ID},
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::header, http::StatusCode, test, web, App};
+    use rand::Rng;
+
+    // Seeded user that is neither protected nor an admin
+    const TARGET_ID: &str = "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e24";
+
+    #[actix_web::test]
+    async fn test_impersonate_user_handler() {
+        let app_state = create_test_app_state().await;
+
+        // Create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .service(web::scope("/admin").configure(routes::admin::user::config))
+                .service(web::scope("/api").configure(routes::project::profile::config)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/admin/users/impersonate/{}", TARGET_ID))
+            .insert_header((
+                header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 200);
+        let data = resp.data.expect("Missing response data").properties;
+        let token = data["token"].as_str().unwrap().to_string();
+
+        // The token acts as the user and the request is audited
+        let req = test::TestRequest::get()
+            .uri("/api/profile")
+            .insert_header((header::AUTHORIZATION, format!("Bearer {}", token)))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let requests = sqlx::query_scalar!(
+            "SELECT COUNT(*) FROM impersonation_logs WHERE impersonator_id = ? AND user_id = ? AND event = 'request'",
+            USER_ID,
+            TARGET_ID
+        )
+        .fetch_one(&app_state.pool)
+        .await
+        .expect("Failed to count impersonation logs");
+
+        assert!(requests > 0);
+
+        // The token cannot change the user, e.g. the mobile used to log in
+        let req = test::TestRequest::patch()
+            .uri("/api/profile/update")
+            .insert_header((header::AUTHORIZATION, format!("Bearer {}", token)))
+            .set_json(serde_json::json!({"mobile": "+966500000000"}))
+            .to_request();
+
+        let resp = test::try_call_service(&app, req).await.unwrap_err();
+
+        assert_eq!(
+            resp.as_response_error().status_code(),
+            StatusCode::FORBIDDEN
+        );
+
+        // The token cannot be used on the admin endpoints
+        let req = test::TestRequest::get()
+            .uri("/admin/users")
+            .insert_header((header::AUTHORIZATION, format!("Bearer {}", token)))
+            .to_request();
+
+        let resp = test::try_call_service(&app, req).await.unwrap_err();
+
+        assert_eq!(
+            resp.as_response_error().status_code(),
+            StatusCode::FORBIDDEN
+        );
+
+        // Soft deleted users cannot be impersonated
+        let deleted_id = uuid::Uuid::new_v4().to_string();
+        sqlx::query(
+            "INSERT INTO users (id, name, mobile, deleted_at) VALUES (?, ?, ?, CURRENT_TIMESTAMP)",
+        )
+        .bind(&deleted_id)
+        .bind(format!("Deleted User {}", &deleted_id[..8]))
+        .bind(format!(
+            "+96650{}",
+            rand::thread_rng().gen_range(1000000..9999999)
+        ))
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test user");
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/admin/users/impersonate/{}", deleted_id))
+            .insert_header((
+                header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        let issued = sqlx::query_scalar!(
+            "SELECT COUNT(*) FROM impersonation_logs WHERE user_id = ?",
+            deleted_id
+        )
+        .fetch_one(&app_state.pool)
+        .await
+        .expect("Failed to count impersonation logs");
+
+        assert_eq!(issued, 0);
+
+        // Protected users cannot be impersonated
+        let req = test::TestRequest::post()
+            .uri("/admin/users/impersonate/a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e25")
+            .insert_header((
+                header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -1,5 +1,10 @@
 pub mod create_user;
 pub mod delete_user;
+pub mod get_impersonation_logs;
 pub mod get_user_by_id;
 pub mod get_users;
+pub mod impersonate_user;
+pub mod purge_user;
+pub mod restore_user;
+pub mod revoke_user_sessions;
 pub mod update_user;
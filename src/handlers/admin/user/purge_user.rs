@@ -0,0 +1,268 @@
+use actix_web::{delete, web};
+use log::{info, warn};
+use uuid::Uuid;
+
+use crate::{
+    core::{app_state::AppState, policy::ensure_unprotected, storage::Storage},
+    model::user::UserModel,
+    schema::response::{api_response::ApiResponse, api_response_error::ApiResponseError},
+};
+
+// Endpoint handler for permanently deleting a soft deleted user
+#[utoipa::path(
+    delete,
+    path = "/admin/users/purge/{id}",
+    tag = "Admin: Users Endpoint",
+    params(
+        ("id" = Uuid, Path, description = "UUID of the deleted user to purge"),
+    ),
+    responses(
+        (status = 204, description= "User Purged"),
+        (status = 403, description= "User Is Protected", body = ApiResponseError),
+        (status = 404, description= "User Not Found", body = ApiResponseError),
+        (status = 409, description= "User Is Not Deleted Or Still Owns Records", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[delete("/purge/{id}")]
+pub async fn purge_user_handler(
+    path: web::Path<Uuid>,           // Path parameter representing the user's UUID
+    app_state: web::Data<AppState>,  // Application state containing database pool
+    storage: web::Data<dyn Storage>, // Storage of the uploaded files
+) -> Result<ApiResponse, ApiResponseError> {
+    let user_id = path.into_inner().to_string();
+
+    let user = sqlx::query_as!(UserModel, "SELECT * FROM users WHERE id = ?", user_id)
+        .fetch_optional(&app_state.pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal server error: {}", e), None))?
+        .ok_or_else(|| {
+            ApiResponseError::new(404, format!("No user found with id {}", user_id), None)
+        })?;
+
+    ensure_unprotected(&user)?;
+
+    // Only users in the trash can be purged
+    if user.deleted_at.is_none() {
+        return Err(ApiResponseError::new(
+            409,
+            "User must be deleted before it is purged".to_string(),
+            None,
+        ));
+    }
+
+    // Contents and applications keep their creator, they have to be purged first
+    let contents_count = sqlx::query_scalar!(
+        "SELECT COUNT(*) FROM contents WHERE created_by = ?",
+        user_id
+    )
+    .fetch_one(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal server error: {}", e), None))?;
+    let applications_count = sqlx::query_scalar!(
+        "SELECT COUNT(*) FROM applications WHERE created_by = ?",
+        user_id
+    )
+    .fetch_one(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal server error: {}", e), None))?;
+
+    if contents_count > 0 || applications_count > 0 {
+        return Err(ApiResponseError::new(
+            409,
+            format!(
+                "User still owns {} contents and {} applications",
+                contents_count, applications_count
+            ),
+            None,
+        ));
+    }
+
+    // Sessions and tokens of the user are removed by the foreign keys,
+    // the audit trails keep their rows with the user cleared
+    sqlx::query("DELETE FROM users WHERE id = ? AND deleted_at IS NOT NULL")
+        .bind(&user_id)
+        .execute(&app_state.pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal server error: {}", e), None))?;
+    info!("Purged user with id: {}", user_id);
+
+    if let Some(ref avatar) = user.avatar {
+        if let Err(e) = storage.delete(avatar).await {
+            warn!("Failed to delete the avatar {}: {}", avatar, e);
+        }
+    }
+
+    Ok(ApiResponse::new(204, "User purged".to_string(), None))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        core::{
+            storage::{local::LocalStorage, Storage},
+            utils::test_utils::{create_test_app_state, generate_test_jwt, USER_ID},
+        },
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::header, http::StatusCode, test, web, App};
+    use rand::Rng as _;
+
+    // Seeded moderator, active and not protected
+    const MODERATOR_ID: &str = "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e24";
+
+    #[actix_web::test]
+    async fn test_purge_user_handler() {
+        let app_state = create_test_app_state().await;
+        let root = std::env::temp_dir().join(format!("uploads-{}", uuid::Uuid::new_v4()));
+        let storage: Arc<dyn Storage> = Arc::new(LocalStorage::new(&root));
+
+        // Create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .app_data(web::Data::from(storage.clone()))
+                .service(web::scope("/admin").configure(routes::admin::user::config)),
+        )
+        .await;
+
+        let purge = |user_id: &str| {
+            test::TestRequest::delete()
+                .uri(&format!("/admin/users/purge/{}", user_id))
+                .insert_header((
+                    header::AUTHORIZATION,
+                    format!("Bearer {}", generate_test_jwt()),
+                ))
+                .to_request()
+        };
+
+        // Users that are not deleted cannot be purged
+        let resp = test::call_service(&app, purge(MODERATOR_ID)).await;
+
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        // Neither can protected users
+        let resp = test::call_service(&app, purge(USER_ID)).await;
+
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        // Insert a soft deleted user with an avatar
+        let user_id = uuid::Uuid::new_v4().to_string();
+        let avatar = format!("avatars/{}/avatar.jpg", user_id);
+        storage
+            .put(&avatar, b"avatar".to_vec(), "image/jpeg")
+            .await
+            .expect("Failed to store test avatar");
+        sqlx::query(
+            "INSERT INTO users (id, name, mobile, avatar, deleted_at) VALUES (?, ?, ?, ?, CURRENT_TIMESTAMP)",
+        )
+        .bind(&user_id)
+        .bind(format!("Deleted User {}", &user_id[..8]))
+        .bind(format!(
+            "+96650{}",
+            rand::thread_rng().gen_range(1000000..9999999)
+        ))
+        .bind(&avatar)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test user");
+
+        // The user edited and published a content of another user, and was impersonated
+        let content_id = uuid::Uuid::new_v4().to_string();
+        sqlx::query(
+            "INSERT INTO contents (id, title, content_type, created_by) VALUES (?, ?, ?, ?)",
+        )
+        .bind(&content_id)
+        .bind("Purge Audit")
+        .bind("page")
+        .bind(USER_ID)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test content");
+        sqlx::query(
+            "INSERT INTO content_revisions (content_id, revision, title, user_id) VALUES (?, 1, 'Purge Audit', ?), (?, 2, 'Purge Audit 2', ?)",
+        )
+        .bind(&content_id)
+        .bind(USER_ID)
+        .bind(&content_id)
+        .bind(&user_id)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test revisions");
+        sqlx::query(
+            "INSERT INTO content_transitions (content_id, from_state, to_state, user_id) VALUES (?, 0, 1, ?)",
+        )
+        .bind(&content_id)
+        .bind(&user_id)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test transition");
+        sqlx::query(
+            "INSERT INTO impersonation_logs (impersonator_id, user_id, token_id, event, method, path) VALUES (?, ?, ?, 'issued', 'POST', '/admin/users/impersonate')",
+        )
+        .bind(USER_ID)
+        .bind(&user_id)
+        .bind(uuid::Uuid::new_v4().to_string())
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test impersonation log");
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, purge(&user_id)).await;
+
+        assert_eq!(resp.status, 204);
+        assert_eq!(resp.message, "User purged");
+
+        let exists = sqlx::query_scalar!("SELECT COUNT(*) FROM users WHERE id = ?", user_id)
+            .fetch_one(&app_state.pool)
+            .await
+            .expect("Failed to count users");
+
+        assert_eq!(exists, 0);
+        assert!(!root.join(&avatar).exists());
+
+        // The audit trails survive the purge, only the user is cleared
+        let revisions: Vec<(i32, Option<String>)> = sqlx::query_as(
+            "SELECT revision, user_id FROM content_revisions WHERE content_id = ? ORDER BY revision",
+        )
+        .bind(&content_id)
+        .fetch_all(&app_state.pool)
+        .await
+        .expect("Failed to fetch revisions");
+
+        assert_eq!(revisions, vec![(1, Some(USER_ID.to_string())), (2, None)]);
+
+        let transitions = sqlx::query_scalar!(
+            "SELECT COUNT(*) FROM content_transitions WHERE content_id = ? AND user_id IS NULL",
+            content_id
+        )
+        .fetch_one(&app_state.pool)
+        .await
+        .expect("Failed to count transitions");
+
+        assert_eq!(transitions, 1);
+
+        let impersonations = sqlx::query_scalar!(
+            "SELECT COUNT(*) FROM impersonation_logs WHERE impersonator_id = ? AND user_id IS NULL",
+            USER_ID
+        )
+        .fetch_one(&app_state.pool)
+        .await
+        .expect("Failed to count impersonation logs");
+
+        assert!(impersonations > 0);
+
+        sqlx::query("DELETE FROM contents WHERE id = ?")
+            .bind(&content_id)
+            .execute(&app_state.pool)
+            .await
+            .expect("Failed to delete test content");
+
+        std::fs::remove_dir_all(root).ok();
+    }
+}
@@ -0,0 +1,163 @@
+use crate::schema::response::{
+    admin::users::UserModelResponse, api_response::ApiResponse,
+    api_response_error::ApiResponseError, api_response_object::ApiResponseObject,
+};
+use actix_web::{post, web};
+use log::info;
+use uuid::Uuid;
+
+use crate::{core::app_state::AppState, model::user::UserModel};
+
+// Endpoint handler for restoring a soft deleted user
+#[utoipa::path(
+    post,
+    path = "/admin/users/restore/{id}",
+    tag = "Admin: Users Endpoint",
+    params(
+        ("id" = Uuid, Path, description = "UUID of the deleted user to restore")
+    ),
+    responses(
+        (status = 200, description= "User Restored", body = ApiResponse),
+        (status = 404, description= "Deleted User Not Found", body = ApiResponseError),
+        (status = 409, description= "Name, Mobile Or Email Taken By Another User", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[post("/restore/{id}")]
+pub async fn restore_user_handler(
+    path: web::Path<Uuid>,          // Path parameter representing the user's UUID
+    app_state: web::Data<AppState>, // Application state containing database pool
+) -> Result<ApiResponse, ApiResponseError> {
+    let user_id = path.into_inner().to_string();
+
+    // Another user may have registered with the same name, mobile or email in the meantime
+    let query_result =
+        sqlx::query("UPDATE users SET deleted_at = NULL WHERE id = ? AND deleted_at IS NOT NULL")
+            .bind(&user_id)
+            .execute(&app_state.pool)
+            .await
+            .map_err(|e| {
+                if e.to_string().contains("Duplicate entry") {
+                    return ApiResponseError::new(
+                        409,
+                        "Another user has the same name, mobile or email".to_string(),
+                        None,
+                    );
+                }
+                ApiResponseError::new(500, format!("Internal server error: {}", e), None)
+            })?;
+
+    if query_result.rows_affected() == 0 {
+        return Err(ApiResponseError::new(
+            404,
+            format!("No deleted user found with id {}", user_id),
+            None,
+        ));
+    }
+    info!("Restored user with id: {}", user_id);
+
+    let mut user = sqlx::query_as!(UserModel, "SELECT * FROM users WHERE id = ?", user_id)
+        .fetch_one(&app_state.pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal server error: {}", e), None))?;
+
+    let user_response = ApiResponseObject::new(
+        serde_json::json!({"user": UserModelResponse::filter_db(&mut user)}),
+    )
+    .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        200,
+        "User restored".to_string(),
+        Some(user_response),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::utils::test_utils::{create_test_app_state, generate_test_jwt},
+        model::user::UserModel,
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::header, http::StatusCode, test, web, App};
+    use rand::Rng as _;
+
+    #[actix_web::test]
+    async fn test_restore_user_handler() {
+        let app_state = create_test_app_state().await;
+
+        // Create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .service(web::scope("/admin").configure(routes::admin::user::config)),
+        )
+        .await;
+
+        // Insert a soft deleted user
+        let user_id = uuid::Uuid::new_v4().to_string();
+        let mobile = format!("+96650{}", rand::thread_rng().gen_range(1000000..9999999));
+        sqlx::query(
+            "INSERT INTO users (id, name, mobile, deleted_at) VALUES (?, ?, ?, CURRENT_TIMESTAMP)",
+        )
+        .bind(&user_id)
+        .bind(format!("Deleted User {}", &user_id[..8]))
+        .bind(&mobile)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test user");
+
+        // The mobile of a deleted user is free for a new account
+        let new_user_id = uuid::Uuid::new_v4().to_string();
+        sqlx::query("INSERT INTO users (id, name, mobile) VALUES (?, ?, ?)")
+            .bind(&new_user_id)
+            .bind(format!("New User {}", &new_user_id[..8]))
+            .bind(&mobile)
+            .execute(&app_state.pool)
+            .await
+            .expect("Failed to insert a user with the mobile of a deleted user");
+
+        let restore = || {
+            test::TestRequest::post()
+                .uri(&format!("/admin/users/restore/{}", user_id))
+                .insert_header((
+                    header::AUTHORIZATION,
+                    format!("Bearer {}", generate_test_jwt()),
+                ))
+                .to_request()
+        };
+
+        // It cannot be restored while the new account has its mobile
+        let resp = test::call_service(&app, restore()).await;
+
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        sqlx::query("DELETE FROM users WHERE id = ?")
+            .bind(&new_user_id)
+            .execute(&app_state.pool)
+            .await
+            .expect("Failed to delete test user");
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, restore()).await;
+
+        assert_eq!(resp.status, 200);
+        assert_eq!(resp.message, "User restored");
+
+        let user = sqlx::query_as!(UserModel, "SELECT * FROM users WHERE id = ?", user_id)
+            .fetch_one(&app_state.pool)
+            .await
+            .expect("Failed to fetch restored user");
+
+        assert!(user.deleted_at.is_none());
+
+        // Users that are not deleted cannot be restored
+        let resp = test::call_service(&app, restore()).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+}
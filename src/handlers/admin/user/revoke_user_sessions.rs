@@ -0,0 +1,115 @@
+use crate::schema::response::{
+    api_response::ApiResponse, api_response_error::ApiResponseError,
+    api_response_object::ApiResponseObject,
+};
+use actix_web::{post, web};
+use log::info;
+use uuid::Uuid;
+
+use crate::core::{app_state::AppState, session::revoke_user_sessions};
+
+// Endpoint handler for signing a user out of every device
+#[utoipa::path(
+    post,
+    path = "/admin/users/revoke-sessions/{id}",
+    tag = "Admin: Users Endpoint",
+    params(
+        ("id" = Uuid, Path, description = "UUID of the user whose sessions are revoked", example = "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e2b")
+    ),
+    responses(
+        (status = 200, description= "User Sessions Revoked", body = ApiResponse),
+        (status = 404, description= "User Not Found", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[post("/revoke-sessions/{id}")]
+pub async fn revoke_user_sessions_handler(
+    path: web::Path<Uuid>,          // Path parameter representing the user's UUID
+    app_state: web::Data<AppState>, // Application state containing database pool
+) -> Result<ApiResponse, ApiResponseError> {
+    let user_id = path.into_inner().to_string();
+
+    let user_exists = sqlx::query_scalar!(
+        "SELECT EXISTS(SELECT 1 FROM users WHERE id = ?) AS user_exists",
+        user_id
+    )
+    .fetch_one(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal server error: {}", e), None))?;
+
+    if user_exists == 0 {
+        return Err(ApiResponseError::new(
+            404,
+            format!("User with ID: {} not found", user_id),
+            None,
+        ));
+    }
+
+    // Revoking the sessions also rejects every access token issued for them
+    let revoked_sessions = revoke_user_sessions(&app_state.pool, &user_id).await?;
+    info!(
+        "Revoked {} sessions for user with id: {}",
+        revoked_sessions, user_id
+    );
+
+    let response_body = ApiResponseObject::new(serde_json::json!({
+        "revoked_sessions": revoked_sessions,
+    }))
+    .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        200,
+        "User sessions revoked".to_string(),
+        Some(response_body),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::{
+            session::{start_session, ClientInfo},
+            utils::test_utils::{create_test_app_state, generate_test_jwt, USER_ID},
+        },
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::header, test, web, App};
+
+    #[actix_web::test]
+    async fn test_revoke_user_sessions_handler() {
+        let app_state = create_test_app_state().await;
+
+        // Create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .service(web::scope("/admin").configure(routes::admin::user::config)),
+        )
+        .await;
+
+        let client = ClientInfo {
+            user_agent: None,
+            ip_address: None,
+        };
+        start_session(&app_state.pool, USER_ID, &client)
+            .await
+            .expect("Failed to start session");
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/admin/users/revoke-sessions/{}", USER_ID))
+            .insert_header((
+                header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 200);
+        assert_eq!(resp.message, "User sessions revoked");
+    }
+}
@@ -2,13 +2,18 @@ use actix_web::{put, web};
 use uuid::Uuid;
 
 use crate::{
-    core::app_state::AppState,
+    core::{
+        app_state::AppState, permission::find_role, phone::normalize_mobile,
+        policy::authorize_protected_update,
+    },
     model::user::UserModel,
     schema::{
         admin::user::UpdateUserSchema,
         response::{
-            admin::users::UserModelResponse, api_response::ApiResponse,
-            api_response_error::ApiResponseError, api_response_object::ApiResponseObject,
+            admin::users::{AuthUser, UserModelResponse},
+            api_response::ApiResponse,
+            api_response_error::{ApiResponseError, ValidationErrorDetail},
+            api_response_object::ApiResponseObject,
         },
     },
 };
@@ -20,11 +25,12 @@ use crate::{
     params(
         ("id" = Uuid, Path, description = "UUID of the user to update"),
     ),
-    request_body(content = UpdateUserSchema, description = "Credentials to update user", example = json!({"name": "Ahmed","mobile": "0123456789","email": "ahmed@example.com","gender": "Male", "active": true, "protected": false })),
+    request_body(content = UpdateUserSchema, description = "Credentials to update user", example = json!({"name": "Ahmed","mobile": "0501234567","email": "ahmed@example.com","gender": "Male", "active": true, "protected": false })),
     responses(
         (status = 204, description= "User Updated", body = ApiResponse),       
+        (status = 400, description= "Validation Error", body = ApiResponseError),       
         (status = 401, description= "Unauthorized", body = ApiResponseError),       
-        (status = 403, description= "User Is Protected", body = ApiResponseError),       
+        (status = 403, description= "User Is Protected Or Missing records.protect To Change It", body = ApiResponseError),       
         (status = 404, description= "User Not Found", body = ApiResponseError),       
         (status = 500, description= "Internal Server Error", body = ApiResponseError),       
     ),
@@ -35,14 +41,20 @@ use crate::{
 #[put("/update/{id}")]
 pub async fn update_user_handler(
     path: web::Path<Uuid>,
+    auth: AuthUser,
     app_state: web::Data<AppState>,
     body: web::Json<UpdateUserSchema>,
 ) -> Result<ApiResponse, ApiResponseError> {
     let user_id = path.into_inner().to_string();
 
-    let user = match sqlx::query_as!(UserModel, "SELECT * FROM users WHERE id = ?", user_id)
-        .fetch_one(&app_state.pool)
-        .await
+    // Trashed users have to be restored before they are updated
+    let user = match sqlx::query_as!(
+        UserModel,
+        "SELECT * FROM users WHERE id = ? AND deleted_at IS NULL",
+        user_id
+    )
+    .fetch_one(&app_state.pool)
+    .await
     {
         Ok(user) => user,
         Err(e) => {
@@ -54,14 +66,26 @@ pub async fn update_user_handler(
         }
     };
 
-    if user.protected == 1 {
-        return Err(ApiResponseError::new(
-            403,
-            "User is protected".to_string(),
-            None,
-        ));
+    authorize_protected_update(&app_state.pool, &auth, &user, body.protected).await?;
+
+    // The role must exist in the roles table
+    if let Some(role_id) = body.role_id {
+        find_role(&app_state.pool, role_id).await.map_err(|e| {
+            if e.status != 404 {
+                return e;
+            }
+            ApiResponseError::new(
+                400,
+                "Validation Error".to_string(),
+                Some(vec![ValidationErrorDetail {
+                    field: "role_id".to_string(),
+                    error: e.message,
+                }]),
+            )
+        })?;
     }
 
+    let mobile = body.mobile.as_deref().map(normalize_mobile).transpose()?;
     let active = body.active.unwrap_or(user.active != 0);
     let i8_active = active as i8;
     let protected = body.protected.unwrap_or(user.protected != 0);
@@ -69,7 +93,7 @@ pub async fn update_user_handler(
 
     let update_result = sqlx::query("UPDATE users SET name = ?, mobile = ?, email = ?, gender = ?, role_id = ?, active = ?, protected = ? WHERE id = ?")
         .bind(body.name.to_owned().unwrap_or_else(|| user.name.clone())) // Binds name
-        .bind(body.mobile.to_owned().unwrap_or_else(|| user.mobile.clone())) // Binds mobile
+        .bind(mobile.unwrap_or_else(|| user.mobile.clone())) // Binds mobile
         .bind(body.email.to_owned().unwrap_or_else(|| user.email.clone().unwrap_or_default())) // Binds email
         .bind(body.gender.to_owned().unwrap_or_else(|| user.gender.clone().unwrap_or_default())) // Binds gender
         .bind(body.role_id.to_owned().unwrap_or_else(|| user.role_id.clone())) // Binds role_id
@@ -160,7 +184,7 @@ mod tests {
         let jwt = generate_test_jwt();
 
         // insert test user data into the database
-        let mobile = format!("9665{}", rand::thread_rng().gen_range(10000000..99999999));
+        let mobile = format!("+96650{}", rand::thread_rng().gen_range(1000000..9999999));
         let create_user_data = CreateUserSchema {
             name: Some(Name().fake()),
             mobile: mobile.clone(),
@@ -181,8 +205,8 @@ mod tests {
 
         let name: Option<String> = Some(Name().fake());
         let mobile = Some(format!(
-            "9665{}",
-            rand::thread_rng().gen_range(10000000..99999999)
+            "+96650{}",
+            rand::thread_rng().gen_range(1000000..9999999)
         ));
         let email: Option<String> = Some(SafeEmail().fake());
         // Update user data
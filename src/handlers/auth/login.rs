@@ -1,22 +1,24 @@
 use crate::{
     config::CONFIG,
-    core::mail::email_queue::{EmailJob, EmailQueue},
+    core::mail::email_queue::EmailQueue,
     schema::response::{
-        api_response::ApiResponse,
-        api_response_error::{ApiResponseError, ValidationErrorDetail},
+        api_response::ApiResponse, api_response_error::ApiResponseError,
         api_response_object::ApiResponseObject,
     },
 };
-use actix_web::{post, web};
-use chrono::{Duration, Utc};
-use log::error;
+use actix_web::{post, web, HttpRequest};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 use crate::core::{
     app_state::AppState,
-    sms::sms_queue::{SmsJob, SmsQueue},
-    utils::{generate_opt::generate_otp, transform_mobile::validate_and_transform_mobile},
+    otp::{send_otp, store_otp},
+    otp_limit::{check_otp_limits, record_otp_attempt, OtpAction},
+    phone::normalize_mobile,
+    registration::check_account_status,
+    session::ClientInfo,
+    sms::sms_queue::SmsQueue,
+    utils::generate_opt::generate_otp,
 };
 
 // Structure representing the request body for login
@@ -32,6 +34,8 @@ struct LoginUserQueryResult {
     mobile: String,
     email: Option<String>,
     active: i8,
+    #[serde(skip_serializing)]
+    verification_expires_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 // Endpoint definition and documentation for the login API
@@ -43,37 +47,37 @@ struct LoginUserQueryResult {
     responses(
         (status = 200, description= "OTP Generated and sms is sent", body = ApiResponse),       
         (status = 400, description= "Validation Error", body = ApiResponseError),       
-        (status = 403, description= "User not active", body = ApiResponseError),       
+        (status = 403, description= "User not active or registration expired", body = ApiResponseError),       
         (status = 404, description= "User not found", body = ApiResponseError),       
+        (status = 429, description= "OTP requested too often, retry after the `Retry-After` header", body = ApiResponseError),       
         (status = 500, description= "Internal Server Error", body = ApiResponseError),       
     )
 )]
 #[post("/login")]
 pub async fn login_user_handler(
+    req: HttpRequest,
     app_state: web::Data<AppState>,
     sms_queue: web::Data<SmsQueue>,
     email_queue: web::Data<EmailQueue>,
     data: web::Json<LoginUserRequest>,
 ) -> Result<ApiResponse, ApiResponseError> {
-    let user_mobile = match validate_and_transform_mobile(data.mobile.as_str()) {
-        Ok(mobile) => mobile,
-        Err(e) => {
-            return Err(ApiResponseError::new(
-                400,
-                "Validation Error".to_string(),
-                Some(vec![ValidationErrorDetail {
-                    field: "mobile".to_string(),
-                    error: format!("{:?}", e),
-                }]),
-            ));
-        }
-    };
+    let user_mobile = normalize_mobile(&data.mobile)?;
 
     let pool = app_state.clone().pool.clone();
+    let client = ClientInfo::from_request(&req);
+
+    // Enforce the resend cooldown and lockout before generating a new OTP
+    check_otp_limits(
+        &pool,
+        OtpAction::Resend,
+        &user_mobile,
+        client.ip_address.as_deref(),
+    )
+    .await?;
 
     let query_result = sqlx::query_as!(
         LoginUserQueryResult,
-        "SELECT id, mobile, email, active FROM users WHERE mobile = ?",
+        "SELECT id, mobile, email, active, verification_expires_at FROM users WHERE mobile = ? AND deleted_at IS NULL",
         user_mobile
     )
     .fetch_one(&pool)
@@ -81,64 +85,40 @@ pub async fn login_user_handler(
 
     match query_result {
         Ok(user) => {
-            if user.active != 1 {
-                return Err(ApiResponseError::new(
-                    403,
-                    "User is not active".to_string(),
-                    None,
-                ));
-            }
+            // Pending registrations login to receive their activation OTP
+            check_account_status(
+                user.active,
+                user.verification_expires_at,
+                chrono::Utc::now(),
+            )?;
             let user_response = ApiResponseObject::new(serde_json::json!({"user": user}))
                 .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
 
             // Generate OTP
             let otp = generate_otp(&CONFIG);
 
-            // Calculate expiry time (one minute from now)
-            let one_minute_from_now = Utc::now() + Duration::minutes(1);
-
-            // Update mobile_token and mobile_token_expire_at columns
-            let update_query = sqlx::query(
-                "UPDATE users SET mobile_token = ?, mobile_token_expire_at = ? WHERE id = ?",
-            )
-            .bind(&otp)
-            .bind(&one_minute_from_now)
-            .bind(&user.id)
-            .execute(&pool)
-            .await;
+            // Store the OTP hashed, it expires after `otp.code_ttl_seconds`
+            let update_query = store_otp(&pool, &user.id, &otp).await;
 
             match update_query {
                 // Handle the case where the database update is successful
                 Ok(_) => {
-                    if CONFIG.env.eq_ignore_ascii_case("production") {
-                        let job = SmsJob {
-                            to: user_mobile.clone(),
-                            body: format!("Login OTP: {}", otp),
-                        };
-                        if let Err(e) = sms_queue.sender.send(job).await {
-                            error!("Failed to queue SMS to: {}. Error: {:?}", user_mobile, e);
-                            return Err(ApiResponseError::new(
-                                500,
-                                "Server Error".to_string(),
-                                None,
-                            ));
-                        }
-                        if user.email.is_some() {
-                            let job = EmailJob {
-                                subject: "Login OTP".to_string(),
-                                to: user.email.clone().unwrap(),
-                                body: format!("Login OTP: {}", otp),
-                            };
-                            if let Err(e) = email_queue.sender.send(job).await {
-                                error!("Failed to queue Email: {:?}", e);
-                                return Err(ApiResponseError::new(
-                                    500,
-                                    "Server Error".to_string(),
-                                    None,
-                                ));
-                            }
-                        }
-                    }
+                    send_otp(
+                        &sms_queue,
+                        &email_queue,
+                        &user_mobile,
+                        user.email.as_deref(),
+                        "Login OTP",
+                        &otp,
+                    )
+                    .await?;
+                    record_otp_attempt(
+                        &pool,
+                        OtpAction::Resend,
+                        &user_mobile,
+                        client.ip_address.as_deref(),
+                    )
+                    .await?;
                     return Ok(ApiResponse::new(
                         200,
                         "OTP generated for this mobile... Sending SMS".to_string(),
@@ -177,8 +157,10 @@ mod tests {
         routes,
         schema::{admin::user::CreateUserSchema, response::api_response::ApiResponse},
     };
-    use actix_web::{test, web, App};
-    use chrono::{Duration, Utc};
+    use actix_web::{
+        http::{header, StatusCode},
+        test, web, App,
+    };
     use fake::{
         faker::{internet::en::SafeEmail, name::en::Name},
         Fake,
@@ -202,11 +184,8 @@ mod tests {
 
         // insert test user data into the database
         let user_id = uuid::Uuid::new_v4().to_string();
-        let mobile_token = "12345"; // Set a test mobile token
-        let mobile_token_expire_at = Utc::now() + Duration::minutes(5); // Set an expiration time
-
-        let user_mobile = rand::thread_rng().gen_range(10000000..99999999);
-        let mobile = format!("9665{}", user_mobile);
+        let user_mobile = rand::thread_rng().gen_range(1000000..9999999);
+        let mobile = format!("+96650{}", user_mobile);
         let create_user_data = CreateUserSchema {
             name: Some(Name().fake()),
             mobile: mobile.clone(),
@@ -214,14 +193,12 @@ mod tests {
         };
 
         let _insert_result = sqlx::query(
-            "insert into users (id, name, mobile, gender, mobile_token, mobile_token_expire_at, email, configurations, protected, active) values (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            "insert into users (id, name, mobile, gender, email, protected, active) values (?, ?, ?, ?, ?, ?, ?)",
         )
         .bind(&user_id)
         .bind(&create_user_data.name.as_deref())
         .bind(&create_user_data.mobile)
         .bind("Male".to_string())
-        .bind(&mobile_token)
-        .bind(&mobile_token_expire_at)
         .bind(&create_user_data.email.as_deref())
         .bind(0)
         .bind(1)
@@ -240,5 +217,16 @@ mod tests {
 
         assert_eq!(resp.status, 200);
         assert_eq!(resp.message, "OTP generated for this mobile... Sending SMS");
+
+        // Requesting another OTP right away hits the resend cooldown
+        let req = test::TestRequest::post()
+            .uri("/api/auth/login")
+            .set_json(&login_data)
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert!(resp.headers().contains_key(header::RETRY_AFTER));
     }
 }
@@ -1,6 +1,12 @@
-use crate::schema::response::{api_response::ApiResponse, api_response_error::ApiResponseError};
-use actix_web::cookie::{time, Cookie};
-use actix_web::{HttpResponse, Responder};
+use crate::core::app_state::AppState;
+use crate::core::session::revocation::revoke_token;
+use crate::core::session::{build_logout_cookies, end_session, revoke_user_sessions};
+use crate::core::utils::jwt::Claims;
+use crate::schema::response::{
+    api_response::ApiResponse, api_response_error::ApiResponseError,
+    api_response_object::ApiResponseObject,
+};
+use actix_web::{web, HttpResponse, Responder};
 
 #[utoipa::path(
     post,
@@ -8,6 +14,7 @@ use actix_web::{HttpResponse, Responder};
     tag = "Auth Endpoint",
     responses(
         (status = 200, description= "User logged out successfully", body = ApiResponse),
+        (status = 401, description= "Invalid or revoked token", body = ApiResponseError),
         (status = 500, description= "Internal Server Error", body = ApiResponseError),
     ),
     security(
@@ -15,19 +22,124 @@ use actix_web::{HttpResponse, Responder};
    )
 )]
 // #[post("/logout")]
-pub async fn logout_user_handler() -> Result<impl Responder, ApiResponseError> {
-    let logout_cookie = Cookie::build("auth_token", "")
-        .path("/")
-        .secure(false)
-        .http_only(true)
-        .max_age(time::Duration::seconds_f32(0.5))
-        .finish();
+pub async fn logout_user_handler(
+    claims: Claims,
+    app_state: web::Data<AppState>,
+) -> Result<impl Responder, ApiResponseError> {
+    // Revoke the presented token and the session it belongs to
+    revoke_token(&app_state.pool, &claims).await?;
+    if let Some(session_id) = &claims.sid {
+        end_session(&app_state.pool, session_id).await?;
+    }
+
+    let (access_cookie, refresh_cookie) = build_logout_cookies();
 
     Ok(HttpResponse::Ok()
-        .cookie(logout_cookie)
+        .cookie(access_cookie)
+        .cookie(refresh_cookie)
         .json(ApiResponse::new(
             200,
             "User logged out successfully".to_string(),
             None,
         )))
 }
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/logout/all",
+    tag = "Auth Endpoint",
+    responses(
+        (status = 200, description= "User logged out from all devices", body = ApiResponse),
+        (status = 401, description= "Invalid or revoked token", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+// #[post("/logout/all")]
+pub async fn logout_all_user_handler(
+    claims: Claims,
+    app_state: web::Data<AppState>,
+) -> Result<impl Responder, ApiResponseError> {
+    // Access tokens of the other devices are rejected once their sessions are revoked
+    revoke_token(&app_state.pool, &claims).await?;
+    let revoked_sessions = revoke_user_sessions(&app_state.pool, &claims.id).await?;
+
+    let response_body = ApiResponseObject::new(serde_json::json!({
+        "revoked_sessions": revoked_sessions,
+    }))
+    .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    let (access_cookie, refresh_cookie) = build_logout_cookies();
+
+    Ok(HttpResponse::Ok()
+        .cookie(access_cookie)
+        .cookie(refresh_cookie)
+        .json(ApiResponse::new(
+            200,
+            "User logged out from all devices".to_string(),
+            Some(response_body),
+        )))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::{
+            session::{start_session, ClientInfo},
+            utils::test_utils::{create_test_app_state, USER_ID},
+        },
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::header, test, web, App};
+
+    #[actix_web::test]
+    async fn test_logout_user_handler_revokes_token() {
+        let app_state = create_test_app_state().await;
+
+        // create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .service(web::scope("/api").configure(routes::auth::config)),
+        )
+        .await;
+
+        let client = ClientInfo {
+            user_agent: None,
+            ip_address: None,
+        };
+        let tokens = start_session(&app_state.pool, USER_ID, &client)
+            .await
+            .expect("Failed to start session");
+
+        let req = test::TestRequest::post()
+            .uri("/api/auth/logout")
+            .insert_header((
+                header::AUTHORIZATION,
+                format!("Bearer {}", tokens.access_token),
+            ))
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 200);
+
+        // The same bearer token must not be accepted after logout
+        let req = test::TestRequest::post()
+            .uri("/api/auth/logout")
+            .insert_header((
+                header::AUTHORIZATION,
+                format!("Bearer {}", tokens.access_token),
+            ))
+            .to_request();
+
+        let err = test::try_call_service(&app, req)
+            .await
+            .expect_err("Revoked token should be rejected");
+
+        assert_eq!(err.as_response_error().status_code(), 401);
+    }
+}
@@ -1,4 +1,6 @@
 pub mod login;
 pub mod logout;
+pub mod refresh;
 pub mod register;
+pub mod two_factor;
 pub mod verify;
@@ -0,0 +1,180 @@
+use crate::schema::response::{
+    api_response::ApiResponse, api_response_error::ApiResponseError,
+    api_response_object::ApiResponseObject, project::profile::ProfileResponse,
+};
+use actix_web::{post, web, HttpRequest, HttpResponse, Responder};
+use humantime::format_duration;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    core::{
+        app_state::AppState,
+        session::{
+            build_auth_cookies, revoke_family, rotate_session, ClientInfo, REFRESH_TOKEN_COOKIE,
+        },
+    },
+    model::user::UserModel,
+};
+
+// representing the request body for refreshing the access token
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct RefreshTokenRequest {
+    // Falls back to the `refresh_token` cookie when omitted
+    pub refresh_token: Option<String>,
+}
+
+// Endpoint definition and documentation for the token refresh API
+#[utoipa::path(
+    post,
+    path = "/api/auth/refresh",
+    tag = "Auth Endpoint",
+    request_body(content = RefreshTokenRequest, description = "Refresh token issued by verify or a previous refresh", example = json!({"refresh_token": "Xo3k...9sQ"})),
+    responses(
+        (status = 200, description= "Token pair rotated", body = ApiResponse),
+        (status = 400, description= "Refresh token is missing", body = ApiResponseError),
+        (status = 401, description= "Invalid, expired, revoked or reused refresh token", body = ApiResponseError),
+        (status = 403, description= "User not active", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    )
+)]
+#[post("/refresh")]
+pub async fn refresh_token_handler(
+    req: HttpRequest,
+    data: Option<web::Json<RefreshTokenRequest>>,
+    app_state: web::Data<AppState>,
+) -> Result<impl Responder, ApiResponseError> {
+    // Take the refresh token from the body, or from the cookie set on verify
+    let refresh_token = data
+        .and_then(|body| body.into_inner().refresh_token)
+        .or_else(|| {
+            req.cookie(REFRESH_TOKEN_COOKIE)
+                .map(|c| c.value().to_string())
+        });
+
+    let refresh_token = match refresh_token {
+        Some(token) if !token.is_empty() => token,
+        _ => {
+            return Err(ApiResponseError::new(
+                400,
+                "Please provide refresh token".to_string(),
+                None,
+            ));
+        }
+    };
+
+    let (session, tokens) = rotate_session(
+        &app_state.pool,
+        &refresh_token,
+        &ClientInfo::from_request(&req),
+    )
+    .await?;
+
+    let user = sqlx::query_as!(
+        UserModel,
+        "SELECT * FROM users WHERE id = ?",
+        session.user_id
+    )
+    .fetch_one(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Failed to fetch user: {:?}", e), None))?;
+
+    // Users deactivated after login lose their sessions on the next refresh
+    if user.active != 1 {
+        revoke_family(&app_state.pool, &session.family_id).await?;
+        return Err(ApiResponseError::new(
+            403,
+            "User is not active".to_string(),
+            None,
+        ));
+    }
+
+    let (access_cookie, refresh_cookie) = build_auth_cookies(&tokens);
+
+    // Convert token durations to a human-readable format
+    let human_readable_duration = format_duration(std::time::Duration::from_secs(
+        tokens.access_token_duration.num_seconds() as u64,
+    ))
+    .to_string();
+    let refresh_human_readable_duration = format_duration(std::time::Duration::from_secs(
+        tokens.refresh_token_duration.num_seconds() as u64,
+    ))
+    .to_string();
+
+    let response_body = ApiResponseObject::new(serde_json::json!({
+        "auth_token": tokens.access_token,
+        "token_expires_in": human_readable_duration,
+        "refresh_token": tokens.refresh_token,
+        "refresh_token_expires_in": refresh_human_readable_duration,
+        "user": ProfileResponse::new(&user),
+    }))
+    .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(HttpResponse::Ok()
+        .cookie(access_cookie)
+        .cookie(refresh_cookie)
+        .json(ApiResponse::new(
+            200,
+            "Token Refreshed".to_string(),
+            Some(response_body),
+        )))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::{
+            session::{start_session, ClientInfo},
+            utils::test_utils::{create_test_app_state, USER_ID},
+        },
+        handlers::auth::refresh::RefreshTokenRequest,
+        routes,
+        schema::response::{api_response::ApiResponse, api_response_error::ApiResponseError},
+    };
+    use actix_web::{test, web, App};
+
+    #[actix_web::test]
+    async fn test_refresh_token_handler() {
+        let app_state = create_test_app_state().await;
+
+        // create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .service(web::scope("/api").configure(routes::auth::config)),
+        )
+        .await;
+
+        let client = ClientInfo {
+            user_agent: None,
+            ip_address: None,
+        };
+        let tokens = start_session(&app_state.pool, USER_ID, &client)
+            .await
+            .expect("Failed to start session");
+
+        let refresh_data = RefreshTokenRequest {
+            refresh_token: Some(tokens.refresh_token.clone()),
+        };
+
+        let req = test::TestRequest::post()
+            .uri("/api/auth/refresh")
+            .set_json(&refresh_data)
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 200);
+        assert_eq!(resp.message, "Token Refreshed");
+
+        // Reusing the rotated refresh token must be rejected
+        let req = test::TestRequest::post()
+            .uri("/api/auth/refresh")
+            .set_json(&refresh_data)
+            .to_request();
+
+        let resp: ApiResponseError = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 401);
+    }
+}
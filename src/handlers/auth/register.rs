@@ -2,12 +2,23 @@ use crate::schema::response::{
     api_response::ApiResponse, api_response_error::ApiResponseError,
     api_response_object::ApiResponseObject,
 };
-use actix_web::{post, web};
+use actix_web::{post, web, HttpRequest};
 use serde::Deserialize;
 use utoipa::ToSchema;
 
 use crate::{
-    core::{app_state::AppState, utils::transform_mobile::validate_and_transform_mobile},
+    config::CONFIG,
+    core::{
+        app_state::AppState,
+        mail::email_queue::EmailQueue,
+        otp::{send_otp, store_otp},
+        otp_limit::{check_otp_limits, record_otp_attempt, OtpAction},
+        phone::normalize_mobile,
+        registration::verification_expires_at,
+        session::ClientInfo,
+        sms::sms_queue::SmsQueue,
+        utils::generate_opt::generate_otp,
+    },
     model::user::UserModel,
 };
 
@@ -27,33 +38,60 @@ pub struct RegisterUserRequest {
     tag = "Auth Endpoint",
     request_body(content = RegisterUserRequest, description = "Credentials to create new user", example = json!({"name": "Ahmed", "mobile": "+201018898522", "email": "ahmed@example.com", "gender": "Male"})),
     responses(
-        (status = 201, description= "User Created", body = ApiResponse),       
+        (status = 201, description= "User Created, the OTP sent to the mobile activates the account", body = ApiResponse),       
+        (status = 400, description= "Invalid mobile", body = ApiResponseError),       
         (status = 409, description= "Duplicate entry", body = ApiResponseError),       
+        (status = 429, description= "OTP requested too often, retry after the `Retry-After` header", body = ApiResponseError),       
         (status = 500, description= "Internal Server Error", body = ApiResponseError),       
     )
 )]
 #[post("/register")]
 pub async fn register_user_handler(
+    req: HttpRequest,
     app_state: web::Data<AppState>,
+    sms_queue: web::Data<SmsQueue>,
+    email_queue: web::Data<EmailQueue>,
     data: web::Json<RegisterUserRequest>,
 ) -> Result<ApiResponse, ApiResponseError> {
     let user_id = uuid::Uuid::new_v4().to_string();
 
-    let mobile = match validate_and_transform_mobile(data.mobile.as_str()) {
-        Ok(mobile) => mobile,
-        Err(e) => return Err(ApiResponseError::new(400, format!("{:?}", e), None)),
-    };
+    let mobile = normalize_mobile(&data.mobile)?;
+    let email = data.email.to_owned().unwrap_or_default();
+    let client = ClientInfo::from_request(&req);
 
-    let query_result =
-        sqlx::query("INSERT INTO users (id,name,mobile,email, gender) VALUES (?, ?, ?, ?, ?)")
-            .bind(user_id.clone())
-            .bind(data.name.to_owned().unwrap_or_default())
-            .bind(mobile)
-            .bind(data.email.to_owned().unwrap_or_default())
-            .bind(data.gender.to_owned().unwrap_or_default())
-            .execute(&app_state.pool)
-            .await
-            .map_err(|err: sqlx::Error| err.to_string());
+    // Registration sends an OTP, so it shares the resend limits with login
+    check_otp_limits(
+        &app_state.pool,
+        OtpAction::Resend,
+        &mobile,
+        client.ip_address.as_deref(),
+    )
+    .await?;
+
+    // An expired registration does not keep its mobile or email reserved
+    sqlx::query(
+        "DELETE FROM users WHERE (mobile = ? OR (email = ? AND email <> '')) AND active = 0 AND verification_expires_at < ?",
+    )
+    .bind(&mobile)
+    .bind(&email)
+    .bind(chrono::Utc::now())
+    .execute(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    // The account stays inactive until the first successful OTP verification
+    let query_result = sqlx::query(
+        "INSERT INTO users (id, name, mobile, email, gender, active, verification_expires_at) VALUES (?, ?, ?, ?, ?, 0, ?)",
+    )
+    .bind(user_id.clone())
+    .bind(data.name.to_owned().unwrap_or_default())
+    .bind(&mobile)
+    .bind(&email)
+    .bind(data.gender.to_owned().unwrap_or_default())
+    .bind(verification_expires_at())
+    .execute(&app_state.pool)
+    .await
+    .map_err(|err: sqlx::Error| err.to_string());
 
     if let Err(err) = query_result {
         if err.contains("Duplicate entry") {
@@ -66,6 +104,27 @@ pub async fn register_user_handler(
         return Err(ApiResponseError::new(500, format!("{:?}", err), None));
     }
 
+    let otp = generate_otp(&CONFIG);
+    store_otp(&app_state.pool, &user_id, &otp)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Failed to store OTP: {:?}", e), None))?;
+    send_otp(
+        &sms_queue,
+        &email_queue,
+        &mobile,
+        data.email.as_deref(),
+        "Registration OTP",
+        &otp,
+    )
+    .await?;
+    record_otp_attempt(
+        &app_state.pool,
+        OtpAction::Resend,
+        &mobile,
+        client.ip_address.as_deref(),
+    )
+    .await?;
+
     let query_result = sqlx::query_as!(UserModel, "SELECT * FROM users WHERE id = ?", user_id)
         .fetch_one(&app_state.pool)
         .await
@@ -79,10 +138,101 @@ pub async fn register_user_handler(
                 .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
             return Ok(ApiResponse::new(
                 201,
-                "User Created. Now you can login with your mobile".to_string(),
+                "User Created. Verify the OTP sent to your mobile to activate your account"
+                    .to_string(),
                 Some(user_response),
             ));
         }
         Err(e) => return Err(ApiResponseError::new(500, format!("{:?}", e), None)),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::{
+            mail::email_queue::EmailQueue, sms::sms_queue::SmsQueue,
+            utils::test_utils::create_test_app_state,
+        },
+        handlers::auth::verify::VerifyOtpRequest,
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{test, web, App};
+    use fake::{
+        faker::{internet::en::SafeEmail, name::en::Name},
+        Fake,
+    };
+    use rand::Rng;
+
+    #[actix_web::test]
+    async fn test_register_then_verify_activates_user() {
+        let app_state = create_test_app_state().await;
+
+        let (sms_queue, _) = SmsQueue::new();
+        let (email_queue, _) = EmailQueue::new();
+        // create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .app_data(web::Data::new(email_queue.clone()))
+                .app_data(web::Data::new(sms_queue.clone()))
+                .service(web::scope("/api").configure(routes::auth::config)),
+        )
+        .await;
+
+        let user_mobile = rand::thread_rng().gen_range(1000000..9999999);
+        let mobile = format!("+96650{}", user_mobile);
+        let email: String = SafeEmail().fake();
+
+        let req = test::TestRequest::post()
+            .uri("/api/auth/register")
+            .set_json(serde_json::json!({
+                "name": Name().fake::<String>(),
+                "mobile": mobile,
+                "email": email,
+                "gender": "Male",
+            }))
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 201);
+        assert_eq!(
+            resp.message,
+            "User Created. Verify the OTP sent to your mobile to activate your account"
+        );
+
+        let active: i8 = sqlx::query_scalar("SELECT active FROM users WHERE mobile = ?")
+            .bind(&mobile)
+            .fetch_one(&app_state.pool)
+            .await
+            .expect("Failed to fetch registered user");
+        assert_eq!(active, 0);
+
+        // The fixed OTP of non-production environments activates the account
+        let verify_data = VerifyOtpRequest {
+            mobile: mobile.clone(),
+            otp: "12345".to_string(),
+            totp_code: None,
+            recovery_code: None,
+        };
+
+        let req = test::TestRequest::post()
+            .uri("/api/auth/verify")
+            .set_json(&verify_data)
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 200);
+        assert_eq!(resp.message, "User Verified");
+
+        let active: i8 = sqlx::query_scalar("SELECT active FROM users WHERE mobile = ?")
+            .bind(&mobile)
+            .fetch_one(&app_state.pool)
+            .await
+            .expect("Failed to fetch verified user");
+        assert_eq!(active, 1);
+    }
+}
@@ -0,0 +1,286 @@
+use crate::schema::response::{
+    admin::users::AuthUser, api_response::ApiResponse, api_response_error::ApiResponseError,
+    api_response_object::ApiResponseObject,
+};
+use actix_web::{post, web};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    config::CONFIG,
+    core::{
+        app_state::AppState,
+        permission::role_requires_two_factor,
+        two_factor::{
+            enrollment, find_two_factor, generate_recovery_codes, generate_secret,
+            store_recovery_codes, verify_second_factor, verify_totp,
+        },
+    },
+};
+
+// representing the request body for confirming the authenticator app
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ConfirmTwoFactorRequest {
+    pub code: String,
+}
+
+// representing the request body for disabling two-factor authentication
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct DisableTwoFactorRequest {
+    pub code: Option<String>,
+    pub recovery_code: Option<String>,
+}
+
+// Endpoint definition and documentation for starting the 2FA enrollment
+#[utoipa::path(
+    post,
+    path = "/api/auth/2fa/enroll",
+    tag = "Auth Endpoint",
+    responses(
+        (status = 200, description= "Secret generated, scan the QR code then confirm with a code", body = ApiResponse),
+        (status = 403, description= "Two-factor authentication is not available for the role of the user", body = ApiResponseError),
+        (status = 409, description= "Two-factor authentication is already enabled", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[post("/enroll")]
+pub async fn enroll_two_factor_handler(
+    user: AuthUser,
+    app_state: web::Data<AppState>,
+) -> Result<ApiResponse, ApiResponseError> {
+    // Enrollment is offered to the roles that require it on the admin endpoints
+    let available = role_requires_two_factor(&app_state.pool, user.role_id)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+    if !available {
+        return Err(ApiResponseError::new(
+            403,
+            "Two-factor authentication is not available for your role".to_string(),
+            None,
+        ));
+    }
+
+    if let Some(two_factor) = find_two_factor(&app_state.pool, &user.id).await? {
+        if two_factor.confirmed_at.is_some() {
+            return Err(ApiResponseError::new(
+                409,
+                "Two-factor authentication is already enabled".to_string(),
+                None,
+            ));
+        }
+    }
+
+    // Starting again replaces a pending enrollment that was never confirmed
+    let secret = generate_secret();
+    sqlx::query(
+        "INSERT INTO two_factor_auth (user_id, secret) VALUES (?, ?) ON DUPLICATE KEY UPDATE secret = VALUES(secret), last_used_step = NULL",
+    )
+    .bind(&user.id)
+    .bind(&secret)
+    .execute(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    let enrollment = enrollment(&secret, &user.mobile)?;
+
+    let response_body = ApiResponseObject::new(serde_json::json!({
+        "secret": enrollment.secret,
+        "provisioning_uri": enrollment.provisioning_uri,
+        "qr_code": format!("data:image/png;base64,{}", enrollment.qr_code),
+    }))
+    .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        200,
+        "Two-Factor Enrollment Started".to_string(),
+        Some(response_body),
+    ))
+}
+
+// Endpoint definition and documentation for confirming the 2FA enrollment
+#[utoipa::path(
+    post,
+    path = "/api/auth/2fa/confirm",
+    tag = "Auth Endpoint",
+    request_body(content = ConfirmTwoFactorRequest, description = "Code from the authenticator app", example = json!({"code": "123456"})),
+    responses(
+        (status = 200, description= "Two-factor authentication enabled, recovery codes are shown once", body = ApiResponse),
+        (status = 400, description= "Enrollment not started or invalid code", body = ApiResponseError),
+        (status = 409, description= "Two-factor authentication is already enabled", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[post("/confirm")]
+pub async fn confirm_two_factor_handler(
+    user: AuthUser,
+    data: web::Json<ConfirmTwoFactorRequest>,
+    app_state: web::Data<AppState>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let two_factor = find_two_factor(&app_state.pool, &user.id)
+        .await?
+        .ok_or_else(|| {
+            ApiResponseError::new(
+                400,
+                "Two-factor enrollment has not been started".to_string(),
+                None,
+            )
+        })?;
+
+    if two_factor.confirmed_at.is_some() {
+        return Err(ApiResponseError::new(
+            409,
+            "Two-factor authentication is already enabled".to_string(),
+            None,
+        ));
+    }
+
+    let now = chrono::Utc::now().timestamp() as u64;
+    let step = verify_totp(&two_factor.secret, &user.mobile, &data.code, now)?
+        .ok_or_else(|| ApiResponseError::new(400, "Invalid two-factor code".to_string(), None))?;
+
+    let recovery_codes = generate_recovery_codes(CONFIG.two_factor.recovery_codes);
+
+    let mut tx =
+        app_state.pool.begin().await.map_err(|e| {
+            ApiResponseError::new(500, format!("Internal Server Error: {}", e), None)
+        })?;
+
+    sqlx::query(
+        "UPDATE two_factor_auth SET confirmed_at = CURRENT_TIMESTAMP, last_used_step = ? WHERE user_id = ?",
+    )
+    .bind(step as i64)
+    .bind(&user.id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    store_recovery_codes(&mut tx, &user.id, &recovery_codes).await?;
+
+    tx.commit()
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    let response_body = ApiResponseObject::new(serde_json::json!({
+        "recovery_codes": recovery_codes,
+    }))
+    .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        200,
+        "Two-Factor Authentication Enabled".to_string(),
+        Some(response_body),
+    ))
+}
+
+// Endpoint definition and documentation for disabling 2FA
+#[utoipa::path(
+    post,
+    path = "/api/auth/2fa/disable",
+    tag = "Auth Endpoint",
+    request_body(content = DisableTwoFactorRequest, description = "Code from the authenticator app or a recovery code", example = json!({"code": "123456"})),
+    responses(
+        (status = 200, description= "Two-factor authentication disabled", body = ApiResponse),
+        (status = 400, description= "Two-factor authentication is not enabled", body = ApiResponseError),
+        (status = 401, description= "Invalid two-factor code or recovery code", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[post("/disable")]
+pub async fn disable_two_factor_handler(
+    user: AuthUser,
+    data: web::Json<DisableTwoFactorRequest>,
+    app_state: web::Data<AppState>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let two_factor = find_two_factor(&app_state.pool, &user.id)
+        .await?
+        .filter(|two_factor| two_factor.confirmed_at.is_some())
+        .ok_or_else(|| {
+            ApiResponseError::new(
+                400,
+                "Two-factor authentication is not enabled".to_string(),
+                None,
+            )
+        })?;
+
+    verify_second_factor(
+        &app_state.pool,
+        &two_factor,
+        &user.mobile,
+        data.code.as_deref(),
+        data.recovery_code.as_deref(),
+    )
+    .await?;
+
+    // Remove the secret together with any unused recovery codes
+    let mut tx =
+        app_state.pool.begin().await.map_err(|e| {
+            ApiResponseError::new(500, format!("Internal Server Error: {}", e), None)
+        })?;
+
+    sqlx::query("DELETE FROM two_factor_recovery_codes WHERE user_id = ?")
+        .bind(&user.id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    sqlx::query("DELETE FROM two_factor_auth WHERE user_id = ?")
+        .bind(&user.id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    Ok(ApiResponse::new(
+        200,
+        "Two-Factor Authentication Disabled".to_string(),
+        None,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::utils::test_utils::{create_test_app_state, generate_test_jwt},
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::header, test, web, App};
+
+    #[actix_web::test]
+    async fn test_enroll_two_factor_handler() {
+        let app_state = create_test_app_state().await;
+
+        // create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .service(web::scope("/api").configure(routes::auth::config)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/auth/2fa/enroll")
+            .insert_header((
+                header::AUTHORIZATION,
+                format!("Bearer {}", generate_test_jwt()),
+            ))
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 200);
+        assert_eq!(resp.message, "Two-Factor Enrollment Started");
+    }
+}
@@ -2,17 +2,21 @@ use crate::schema::response::{
     api_response::ApiResponse, api_response_error::ApiResponseError,
     api_response_object::ApiResponseObject, project::profile::ProfileResponse,
 };
-use actix_web::{
-    cookie::{time, Cookie, SameSite},
-    post, web, HttpResponse, Responder,
-};
-use chrono::{Duration, Utc};
+use actix_web::{post, web, HttpRequest, HttpResponse, Responder};
 use humantime::format_duration;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 use crate::{
-    core::{app_state::AppState, utils::jwt::encode_jwt},
+    core::{
+        app_state::AppState,
+        otp::{check_otp, delete_otp, OtpCheck},
+        otp_limit::{check_otp_limits, clear_otp_attempts, record_otp_attempt, OtpAction},
+        phone::normalize_mobile,
+        registration::{activate_pending_user, check_account_status},
+        session::{build_auth_cookies, start_session, ClientInfo},
+        two_factor::{find_two_factor, verify_second_factor},
+    },
     model::user::UserModel,
 };
 
@@ -21,6 +25,11 @@ use crate::{
 pub struct VerifyOtpRequest {
     pub mobile: String,
     pub otp: String,
+    // Second step for accounts with two-factor authentication enabled
+    #[serde(default)]
+    pub totp_code: Option<String>,
+    #[serde(default)]
+    pub recovery_code: Option<String>,
 }
 
 // Endpoint definition and documentation for the OTP verification API
@@ -28,114 +37,163 @@ pub struct VerifyOtpRequest {
     post,
     path = "/api/auth/verify",
     tag = "Auth Endpoint",
-    request_body(content = VerifyOtpRequest, description = "Credentials to verify OTP and generate auth_token", example = json!({"mobile": "+201018898522", "otp": "12345"})),
+    request_body(content = VerifyOtpRequest, description = "Credentials to verify OTP and generate auth_token, accounts with two-factor authentication also send totp_code or recovery_code", example = json!({"mobile": "+201018898522", "otp": "12345"})),
     responses(
-        (status = 200, description= "OTP verified and auth_token is generated", body = ApiResponse),       
+        (status = 200, description= "OTP verified, auth_token and refresh_token are generated", body = ApiResponse),       
+        (status = 400, description= "Validation Error or incorrect OTP", body = ApiResponseError),       
+        (status = 401, description= "Two-factor code required or invalid", body = ApiResponseError),       
+        (status = 403, description= "User not active or registration expired", body = ApiResponseError),       
+        (status = 429, description= "Too many failed attempts, retry after the `Retry-After` header", body = ApiResponseError),       
         (status = 500, description= "Internal Server Error", body = ApiResponseError),       
     )
 )]
 #[post("/verify")]
 pub async fn verify_otp_handler(
+    req: HttpRequest,
     data: web::Json<VerifyOtpRequest>,
     app_state: web::Data<AppState>,
 ) -> Result<impl Responder, ApiResponseError> {
-    let mobile = data.mobile.to_owned();
+    let mobile = normalize_mobile(&data.mobile)?;
     let otp = data.otp.to_owned();
+    let client = ClientInfo::from_request(&req);
 
-    // Query the user from the database
-    let query_result = sqlx::query_as!(UserModel, "SELECT * FROM users WHERE mobile = ?", mobile)
-        .fetch_one(&app_state.pool)
-        .await;
+    // Reject guesses while the mobile or the IP is locked out
+    check_otp_limits(
+        &app_state.pool,
+        OtpAction::FailedVerify,
+        &mobile,
+        client.ip_address.as_deref(),
+    )
+    .await?;
+
+    // Query the user from the database, deleted users cannot sign in
+    let query_result = sqlx::query_as!(
+        UserModel,
+        "SELECT * FROM users WHERE mobile = ? AND deleted_at IS NULL",
+        mobile
+    )
+    .fetch_one(&app_state.pool)
+    .await;
 
     match query_result {
-        Ok(user) => {
-            // Check if mobile token exists
-            if user.mobile_token.is_none() {
-                return Err(ApiResponseError::new(
-                    400,
-                    "Please provide OTP".to_string(),
-                    None,
-                ));
-            }
+        Ok(mut user) => {
+            check_account_status(
+                user.active,
+                user.verification_expires_at,
+                chrono::Utc::now(),
+            )?;
 
-            // Verify OTP
-            if user.mobile_token != Some(otp) {
-                return Err(ApiResponseError::new(
-                    400,
-                    "Incorrect OTP".to_string(),
-                    None,
-                ));
-            }
+            // Compare the OTP with the stored hash
+            let otp_check = check_otp(&app_state.pool, &user.id, &otp)
+                .await
+                .map_err(|e| {
+                    ApiResponseError::new(500, format!("Failed to check OTP: {:?}", e), None)
+                })?;
 
-            // Check mobile token expiration
-            if let Some(expiry) = user.mobile_token_expire_at {
-                if expiry < chrono::Utc::now() {
+            match otp_check {
+                OtpCheck::Valid => {}
+                OtpCheck::Missing => {
+                    return Err(ApiResponseError::new(
+                        400,
+                        "Please request a new OTP".to_string(),
+                        None,
+                    ));
+                }
+                OtpCheck::Expired => {
                     return Err(ApiResponseError::new(
                         400,
                         "Mobile token has expired".to_string(),
                         None,
                     ));
                 }
-            } else {
-                return Err(ApiResponseError::new(
-                    400,
-                    "Mobile token expiration not set".to_string(),
-                    None,
-                ));
+                OtpCheck::Invalid => {
+                    record_failed_verify(&app_state, &user.id, &mobile, &client).await?;
+                    return Err(ApiResponseError::new(
+                        400,
+                        "Incorrect OTP".to_string(),
+                        None,
+                    ));
+                }
+            }
+
+            // The OTP is kept until the second factor succeeds so the client can retry with a code
+            if let Some(two_factor) = find_two_factor(&app_state.pool, &user.id).await? {
+                if two_factor.confirmed_at.is_some() {
+                    let second_factor = verify_second_factor(
+                        &app_state.pool,
+                        &two_factor,
+                        &user.mobile,
+                        data.totp_code.as_deref(),
+                        data.recovery_code.as_deref(),
+                    )
+                    .await;
+
+                    if let Err(e) = second_factor {
+                        // A missing code is the expected first call, only wrong codes count
+                        if data.totp_code.is_some() || data.recovery_code.is_some() {
+                            record_failed_verify(&app_state, &user.id, &mobile, &client).await?;
+                        }
+                        return Err(e);
+                    }
+                }
             }
 
-            let one_minute_from_now = Utc::now() - Duration::hours(1);
-            let update_query_result = sqlx::query(
-                "UPDATE users SET mobile_token = ?, mobile_token_expire_at = ? WHERE id = ?",
-            )
-            .bind(Option::<String>::None)
-            .bind(&one_minute_from_now)
-            .bind(&user.id)
-            .execute(&app_state.pool)
-            .await;
+            // The OTP can be used only once
+            let update_query_result = delete_otp(&app_state.pool, &user.id).await;
 
             match update_query_result {
                 Ok(_) => {
-                    // Generate JWT token
-                    let token_duration = Duration::days(100);
-                    let token = match encode_jwt(user.id.clone(), token_duration) {
-                        Ok(token) => token,
-                        Err(e) => {
-                            return Err(ApiResponseError::new(
-                                500,
-                                format!("Failed to generate JWT: {:?}", e),
-                                None,
-                            ));
-                        }
-                    };
+                    clear_otp_attempts(&app_state.pool, OtpAction::FailedVerify, &mobile).await?;
+
+                    // The first successful verify of a registration activates the account
+                    if user.verification_expires_at.is_some() {
+                        activate_pending_user(&app_state.pool, &user.id)
+                            .await
+                            .map_err(|e| {
+                                ApiResponseError::new(
+                                    500,
+                                    format!("Failed to activate user: {:?}", e),
+                                    None,
+                                )
+                            })?;
+                        user.active = 1;
+                        user.verification_expires_at = None;
+                    }
 
-                    // Convert token duration to a human-readable format
+                    // Start a new session: short-lived access token plus a rotating refresh token
+                    let tokens = start_session(&app_state.pool, &user.id, &client).await?;
+                    let (access_cookie, refresh_cookie) = build_auth_cookies(&tokens);
+
+                    // Convert token durations to a human-readable format
                     let human_readable_duration = format_duration(std::time::Duration::from_secs(
-                        token_duration.num_seconds() as u64,
+                        tokens.access_token_duration.num_seconds() as u64,
                     ))
                     .to_string();
-                    let cookie = Cookie::build("auth_token", token.clone())
-                        .path("/")
-                        .secure(false)
-                        .http_only(true)
-                        .same_site(SameSite::Strict)
-                        .max_age(time::Duration::days(100))
-                        .finish();
+                    let refresh_human_readable_duration =
+                        format_duration(std::time::Duration::from_secs(
+                            tokens.refresh_token_duration.num_seconds() as u64,
+                        ))
+                        .to_string();
 
                     // Construct success response with JWT token
                     let response_body = ApiResponseObject::new(serde_json::json!({
-                        "auth_token": token,
+                        "auth_token": tokens.access_token,
                         "token_expires_in": human_readable_duration,
+                        "refresh_token": tokens.refresh_token,
+                        "refresh_token_expires_in": refresh_human_readable_duration,
                         "user": ProfileResponse::new(&user),
                     }))
                     .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
 
-                    // Return the HTTP response with the cookie
-                    Ok(HttpResponse::Ok().cookie(cookie).json(ApiResponse::new(
-                        200,
-                        "User Verified".to_string(),
-                        Some(response_body),
-                    )))
+                    // Return the HTTP response with the cookies
+                    Ok(HttpResponse::Ok()
+                        .cookie(access_cookie)
+                        .cookie(refresh_cookie)
+                        .json(ApiResponse::new(
+                            200,
+                            "User Verified".to_string(),
+                            Some(response_body),
+                        )))
                 }
                 Err(e) => {
                     return Err(ApiResponseError::new(
@@ -157,16 +215,40 @@ pub async fn verify_otp_handler(
     }
 }
 
+// Count a failed verify, once the mobile is locked out the current OTP is dropped
+// so a new one has to be requested after the lockout
+async fn record_failed_verify(
+    app_state: &web::Data<AppState>,
+    user_id: &str,
+    mobile: &str,
+    client: &ClientInfo,
+) -> Result<(), ApiResponseError> {
+    let mobile_locked = record_otp_attempt(
+        &app_state.pool,
+        OtpAction::FailedVerify,
+        mobile,
+        client.ip_address.as_deref(),
+    )
+    .await?;
+
+    if mobile_locked {
+        delete_otp(&app_state.pool, user_id).await.map_err(|e| {
+            ApiResponseError::new(500, format!("Failed to update user: {:?}", e), None)
+        })?;
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        core::utils::test_utils::create_test_app_state,
+        core::{otp::store_otp, utils::test_utils::create_test_app_state},
         handlers::auth::verify::VerifyOtpRequest,
         routes,
         schema::{admin::user::CreateUserSchema, response::api_response::ApiResponse},
     };
     use actix_web::{test, web, App};
-    use chrono::{Duration, Utc};
     use fake::{
         faker::{internet::en::SafeEmail, name::en::Name},
         Fake,
@@ -187,10 +269,9 @@ mod tests {
         // insert test user data into the database
         let user_id = uuid::Uuid::new_v4().to_string();
         let mobile_token = "12345"; // Set a test mobile token
-        let mobile_token_expire_at = Utc::now() + Duration::minutes(5); // Set an expiration time
 
-        let user_mobile = rand::thread_rng().gen_range(10000000..99999999);
-        let mobile = format!("9665{}", user_mobile);
+        let user_mobile = rand::thread_rng().gen_range(1000000..9999999);
+        let mobile = format!("+96650{}", user_mobile);
         let create_user_data = CreateUserSchema {
             name: Some(Name().fake()),
             mobile: mobile.clone(),
@@ -198,14 +279,12 @@ mod tests {
         };
 
         let _insert_result = sqlx::query(
-            "insert into users (id, name, mobile, gender, mobile_token, mobile_token_expire_at, email, protected, active) values (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            "insert into users (id, name, mobile, gender, email, protected, active) values (?, ?, ?, ?, ?, ?, ?)",
         )
         .bind(&user_id)
         .bind(&create_user_data.name.as_deref())
         .bind(&create_user_data.mobile)
         .bind("Male".to_string())
-        .bind(&mobile_token)
-        .bind(&mobile_token_expire_at)
         .bind(&create_user_data.email.as_deref())
         .bind(0)
         .bind(1)
@@ -213,9 +292,15 @@ mod tests {
         .await
         .expect("Failed to insert test user");
 
+        store_otp(&app_state.pool, &user_id, mobile_token)
+            .await
+            .expect("Failed to store test OTP");
+
         let login_data = VerifyOtpRequest {
             mobile,
             otp: mobile_token.to_string(),
+            totp_code: None,
+            recovery_code: None,
         };
 
         let req = test::TestRequest::post()
@@ -0,0 +1,91 @@
+use actix_web::{route, web, HttpRequest, HttpResponse};
+
+use crate::{
+    config::CONFIG, core::files::serve_file, schema::response::api_response_error::ApiResponseError,
+};
+
+// Endpoint to serve the files of the public directory, uploads included
+#[utoipa::path(
+    get,
+    path = "/public/{path}",
+    tag = "Files Endpoint",
+    params(
+        ("path" = String, Path, description = "Path of the file in the public directory"),
+        ("Range" = Option<String>, Header, description = "Part of the file to send, such as bytes=0-1023"),
+    ),
+    responses(
+        (status = 200, description= "File"),
+        (status = 206, description= "Part of the file"),
+        (status = 304, description= "Not Modified"),
+        (status = 404, description= "File Not Found", body = ApiResponseError),
+    )
+)]
+#[route("/{path:.*}", method = "GET", method = "HEAD")]
+pub async fn get_public_file_handler(
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiResponseError> {
+    let cache_control = format!("public, max-age={}", CONFIG.files.max_age_seconds);
+
+    serve_file(&req, &CONFIG.files.public_root, &path, &cache_control).await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{config::CONFIG, routes};
+
+    use actix_web::{http::header, test, App};
+    use std::path::Path;
+
+    #[actix_web::test]
+    async fn test_get_public_file() {
+        let dir = format!("test-{}", uuid::Uuid::new_v4());
+        let root = Path::new(&CONFIG.files.public_root).join(&dir);
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join("app.js"), "console.log(1);").unwrap();
+
+        let app = test::init_service(App::new().configure(routes::files::config)).await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/public/{}/app.js", dir))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        assert_eq!(
+            resp.headers().get(header::CACHE_CONTROL).unwrap(),
+            format!("public, max-age={}", CONFIG.files.max_age_seconds).as_str()
+        );
+        let etag = resp.headers().get(header::ETAG).unwrap().clone();
+        assert_eq!(test::read_body(resp).await, "console.log(1);");
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/public/{}/app.js", dir))
+            .insert_header((header::IF_NONE_MATCH, etag))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 304);
+
+        let req = test::TestRequest::default()
+            .method(actix_web::http::Method::HEAD)
+            .uri(&format!("/public/{}/app.js", dir))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        assert!(resp.headers().contains_key(header::ETAG));
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/public/{}/missing.js", dir))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 404);
+
+        // Nothing outside the public directory is served
+        let req = test::TestRequest::get()
+            .uri("/public/%2E%2E/Cargo.toml")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 404);
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,101 @@
+use actix_web::{route, web, HttpRequest, HttpResponse};
+use chrono::Utc;
+
+use crate::{
+    config::CONFIG,
+    core::files::{serve_file, verify_signed_url},
+    schema::{response::api_response_error::ApiResponseError, SignedFileOptions},
+};
+
+// Endpoint to serve a private file through a signed URL, until the URL expires
+#[utoipa::path(
+    get,
+    path = "/files/{path}",
+    tag = "Files Endpoint",
+    params(
+        ("path" = String, Path, description = "Path of the file in the private directory"),
+        SignedFileOptions,
+        ("Range" = Option<String>, Header, description = "Part of the file to send, such as bytes=0-1023"),
+    ),
+    responses(
+        (status = 200, description= "File"),
+        (status = 206, description= "Part of the file"),
+        (status = 304, description= "Not Modified"),
+        (status = 403, description= "Invalid Signature", body = ApiResponseError),
+        (status = 404, description= "File Not Found", body = ApiResponseError),
+    )
+)]
+#[route("/{path:.*}", method = "GET", method = "HEAD")]
+pub async fn get_signed_file_handler(
+    req: HttpRequest,
+    path: web::Path<String>,
+    opts: web::Query<SignedFileOptions>,
+) -> Result<HttpResponse, ApiResponseError> {
+    let remaining = verify_signed_url(&path, opts.expires, &opts.signature, Utc::now())?;
+
+    // Caches must not keep the file after the URL expires
+    let cache_control = format!("private, max-age={}", remaining);
+
+    serve_file(&req, &CONFIG.files.private_root, &path, &cache_control).await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{config::CONFIG, core::files::signed_url, routes};
+
+    use actix_web::{http::header, test, App};
+    use chrono::{Duration, Utc};
+    use std::path::Path;
+
+    #[actix_web::test]
+    async fn test_get_signed_file() {
+        let dir = format!("test-{}", uuid::Uuid::new_v4());
+        let root = Path::new(&CONFIG.files.private_root).join(&dir);
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join("report.csv"), "id,name\n1,Ahmed\n").unwrap();
+
+        let app = test::init_service(App::new().configure(routes::files::config)).await;
+
+        let url = signed_url(&format!("{}/report.csv", dir), Utc::now());
+        let req = test::TestRequest::get().uri(&url).to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        let cache_control = resp.headers().get(header::CACHE_CONTROL).unwrap();
+        assert!(cache_control
+            .to_str()
+            .unwrap()
+            .starts_with("private, max-age="));
+        assert_eq!(test::read_body(resp).await, "id,name\n1,Ahmed\n");
+
+        // Ranges work through signed URLs too
+        let req = test::TestRequest::get()
+            .uri(&url)
+            .insert_header((header::RANGE, "bytes=3-6"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 206);
+        assert_eq!(test::read_body(resp).await, "name");
+
+        // Without a signature, with another one or once expired the file is refused
+        let req = test::TestRequest::get()
+            .uri(&format!("/files/{}/report.csv", dir))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 400);
+
+        let tampered = url.replace("report.csv", "other.csv");
+        let req = test::TestRequest::get().uri(&tampered).to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 403);
+
+        let expired = signed_url(
+            &format!("{}/report.csv", dir),
+            Utc::now() - Duration::seconds(CONFIG.files.signed_url_seconds + 1),
+        );
+        let req = test::TestRequest::get().uri(&expired).to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 403);
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
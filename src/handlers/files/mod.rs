@@ -0,0 +1,2 @@
+pub mod get_public_file;
+pub mod get_signed_file;
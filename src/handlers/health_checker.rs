@@ -34,6 +34,26 @@ pub async fn health_checker_auth_handler() -> impl Responder {
     ApiResponse::new(200, message, None)
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/check/signed",
+    tag = "Health Checker Endpoint",
+    params(
+        ("x-timestamp" = i64, Header, description = "Unix timestamp of the request in seconds"),
+        ("x-nonce" = String, Header, description = "Unique value per request, at most 128 characters"),
+        ("x-signature" = String, Header, description = "Hex HMAC-SHA256 of the canonical request with the app secret"),
+    ),
+    responses(
+        (status = 200, description= "Signed Health Checker", body = ApiResponse),       
+        (status = 401, description= "Unauthorized", body = ApiResponseError),       
+    )
+)]
+pub async fn health_checker_signed_handler() -> impl Responder {
+    let message = "API with Rust, SQLX, MySQL, and Actix web - Signed".to_string();
+
+    ApiResponse::new(200, message, None)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::core::app_state::AppState;
@@ -55,6 +75,9 @@ mod tests {
             exp: (now + expire).timestamp() as usize,
             iat: now.timestamp() as usize,
             id: "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e2b".to_owned(),
+            jti: uuid::Uuid::new_v4().to_string(),
+            sid: None,
+            impersonator: None,
         };
         let encoding_key = EncodingKey::from_secret(CONFIG.jwt.secret.as_ref());
         encode(&Header::default(), &claims, &encoding_key).unwrap()
@@ -0,0 +1,157 @@
+use crate::{
+    core::{
+        app_state::AppState, enums::ContentState, image_variant::content_image_variants,
+        media::content_galleries,
+    },
+    model::content::PublishedContentModel,
+    schema::response::{
+        api_response::ApiResponse, api_response_error::ApiResponseError,
+        api_response_object::ApiResponseObject, project::contents::PublicContentResponse,
+    },
+};
+use actix_web::{get, http::header, web, Either, HttpResponse};
+use serde_json::json;
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    get,
+    path = "/api/contents/{slug}",
+    tag = "Contents Endpoint",
+    params(
+        ("slug" = String, Path, description = "Slug of the published content"),
+    ),
+    responses(
+        (status = 200, description= "Get Published Content", body = ApiResponse),
+        (status = 301, description= "Previous slug, the Location header has the current one"),
+        (status = 404, description= "Content not found", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    )
+)]
+#[get("/{slug}")]
+pub async fn get_public_content_by_slug_handler(
+    slug: web::Path<String>,
+    app_state: web::Data<AppState>,
+) -> Result<Either<ApiResponse, HttpResponse>, ApiResponseError> {
+    let slug = slug.into_inner();
+
+    // Contents that are not published are reported as missing
+    let published = sqlx::query_as::<_, PublishedContentModel>(
+        "SELECT c.*, u.name AS author_name FROM contents c INNER JOIN users u ON u.id = c.created_by WHERE c.slug = ? AND c.record_state = ? AND c.deleted_at IS NULL",
+    )
+    .bind(&slug)
+    .bind(ContentState::Published.to_i8())
+    .fetch_optional(&app_state.pool)
+    .await
+    .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    let content = match published {
+        Some(content) => content,
+        None => {
+            // Links to a previous slug are sent to the current one
+            let current_slug = sqlx::query_scalar::<_, String>(
+                "SELECT c.slug FROM content_slug_redirects r INNER JOIN contents c ON c.id = r.content_id WHERE r.old_slug = ? AND c.slug IS NOT NULL AND c.record_state = ? AND c.deleted_at IS NULL",
+            )
+            .bind(&slug)
+            .bind(ContentState::Published.to_i8())
+            .fetch_optional(&app_state.pool)
+            .await
+            .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?
+            .ok_or_else(|| ApiResponseError::new(404, "Content not found".to_string(), None))?;
+
+            return Ok(Either::Right(
+                HttpResponse::MovedPermanently()
+                    .insert_header((header::LOCATION, format!("/api/contents/{}", current_slug)))
+                    .finish(),
+            ));
+        }
+    };
+
+    let variants = content_image_variants(&app_state.pool, &[&content.content.id]).await?;
+    let gallery = content_galleries(&app_state.pool, &[&content.content.id]).await?;
+    let response = PublicContentResponse::new(&content, &variants).with_gallery(&gallery);
+    let content_response = ApiResponseObject::new(json!({"content": response}))
+        .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(Either::Left(ApiResponse::new(
+        200,
+        "Get Published Content".to_string(),
+        Some(content_response),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::utils::test_utils::{create_test_app_state, USER_ID},
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::header, http::StatusCode, test, web, App};
+    use fake::{faker::lorem::en::Sentence, Fake};
+
+    #[actix_web::test]
+    async fn test_get_public_content_by_slug_handler() {
+        let app_state = create_test_app_state().await;
+
+        // Create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .service(web::scope("/api").configure(routes::project::contents::config)),
+        )
+        .await;
+
+        // Seeded published content
+        let req = test::TestRequest::get()
+            .uri("/api/contents/content-2")
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 200);
+        let data = resp.data.expect("Missing response data").properties;
+        assert_eq!(data["content"]["title"], "content-2");
+        assert!(data["content"].get("protected").is_none());
+
+        let req = test::TestRequest::get()
+            .uri("/api/contents/missing-content")
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        // A published content whose slug was changed
+        let content_id = uuid::Uuid::new_v4().to_string();
+        let title: String = Sentence(3..6).fake();
+        sqlx::query(
+            "INSERT INTO contents (id, title, slug, content_type, record_state, created_by) VALUES (?, ?, ?, ?, 1, ?)",
+        )
+        .bind(&content_id)
+        .bind(&title)
+        .bind(format!("new-{}", content_id))
+        .bind("page")
+        .bind(USER_ID)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test content");
+        sqlx::query("INSERT INTO content_slug_redirects (old_slug, content_id) VALUES (?, ?)")
+            .bind(format!("old-{}", content_id))
+            .bind(&content_id)
+            .execute(&app_state.pool)
+            .await
+            .expect("Failed to insert test redirect");
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/api/contents/old-{}", content_id))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::MOVED_PERMANENTLY);
+        assert_eq!(
+            resp.headers().get(header::LOCATION).unwrap(),
+            &format!("/api/contents/new-{}", content_id)
+        );
+    }
+}
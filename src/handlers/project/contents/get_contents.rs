@@ -0,0 +1,183 @@
+use crate::{
+    core::{app_state::AppState, enums::ContentState, image_variant::content_image_variants},
+    model::content::PublishedContentModel,
+    schema::{
+        project::contents::PublicContentsFilterOptions,
+        response::{
+            api_response_collection::ApiResponseCollection, api_response_error::ApiResponseError,
+            api_response_object::ApiResponseObject, project::contents::PublicContentResponse,
+            Pagination,
+        },
+    },
+};
+use actix_web::{get, web, HttpResponse, Responder};
+
+const MAX_LIMIT: i64 = 100;
+
+// Endpoint metadata using `utoipa` attributes for API documentation
+#[utoipa::path(
+    get,
+    path = "/api/contents",
+    tag = "Contents Endpoint",
+    params(
+        PublicContentsFilterOptions
+    ),
+    responses(
+        (status = 200, description= "Get Published Contents", body = ApiResponseCollection),
+        (status = 404, description= "No Data Found", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    )
+)]
+#[get("")]
+pub async fn get_public_contents_handler(
+    opts: web::Query<PublicContentsFilterOptions>,
+    app_state: web::Data<AppState>,
+) -> Result<impl Responder, ApiResponseError> {
+    let limit = opts.limit.unwrap_or(10).clamp(1, MAX_LIMIT);
+    let page = opts.page.unwrap_or(1).max(1);
+    let offset = (page - 1) * limit;
+
+    // Drafts, contents in review, archived and deleted contents are never public
+    let mut conditions = vec![
+        "c.record_state = ?",
+        "c.deleted_at IS NULL",
+        "c.slug IS NOT NULL",
+    ];
+    if opts.content_type.is_some() {
+        conditions.push("c.content_type = ?");
+    }
+    let conditions = conditions.join(" AND ");
+
+    let query = format!(
+        "SELECT c.*, u.name AS author_name FROM contents c INNER JOIN users u ON u.id = c.created_by WHERE {} ORDER BY c.created_at DESC, c.id LIMIT ? OFFSET ?",
+        conditions
+    );
+    let mut contents_query =
+        sqlx::query_as::<_, PublishedContentModel>(&query).bind(ContentState::Published.to_i8());
+    if let Some(ref content_type) = opts.content_type {
+        contents_query = contents_query.bind(content_type);
+    }
+    let contents = contents_query
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&app_state.pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    if contents.is_empty() {
+        return Err(ApiResponseError::new(
+            404,
+            "No Data Found".to_string(),
+            None,
+        ));
+    }
+
+    let total_count_query = format!("SELECT COUNT(*) FROM contents c WHERE {}", conditions);
+    let mut count_query =
+        sqlx::query_as::<_, (i64,)>(&total_count_query).bind(ContentState::Published.to_i8());
+    if let Some(ref content_type) = opts.content_type {
+        count_query = count_query.bind(content_type);
+    }
+    let (total_items,) = count_query
+        .fetch_one(&app_state.pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    let content_ids = contents
+        .iter()
+        .map(|published| published.content.id.as_str())
+        .collect::<Vec<&str>>();
+    let variants = content_image_variants(&app_state.pool, &content_ids).await?;
+
+    let content_response = contents
+        .iter()
+        .map(|published| PublicContentResponse::new(published, &variants))
+        .collect::<Vec<PublicContentResponse>>();
+
+    let pagination = Pagination {
+        total_items,
+        total_pages: (total_items as f64 / limit as f64).ceil() as i64,
+        current_page: page,
+        per_page: limit,
+    };
+
+    let json_response = ApiResponseObject::new(serde_json::json!({
+        "contents": content_response,
+    }))
+    .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(HttpResponse::Ok().json(ApiResponseCollection::new(
+        200,
+        "Get Published Contents".to_string(),
+        Some(json_response),
+        Some(pagination),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::utils::test_utils::{create_test_app_state, USER_ID},
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{test, web, App};
+    use fake::{faker::lorem::en::Sentence, Fake};
+
+    #[actix_web::test]
+    async fn test_get_public_contents_handler() {
+        let app_state = create_test_app_state().await;
+
+        // Create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .service(web::scope("/api").configure(routes::project::contents::config)),
+        )
+        .await;
+
+        // A draft of a dedicated type, hidden until it is published
+        let content_type = format!("t{}", &uuid::Uuid::new_v4().simple().to_string()[..8]);
+        let content_id = uuid::Uuid::new_v4().to_string();
+        let title: String = Sentence(3..6).fake();
+        sqlx::query(
+            "INSERT INTO contents (id, title, slug, content_type, created_by) VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(&content_id)
+        .bind(&title)
+        .bind(&content_id)
+        .bind(&content_type)
+        .bind(USER_ID)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to insert test content");
+
+        let list = || {
+            test::TestRequest::get()
+                .uri(&format!("/api/contents?content_type={}", content_type))
+                .to_request()
+        };
+
+        let resp = test::call_service(&app, list()).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+
+        sqlx::query("UPDATE contents SET record_state = 1 WHERE id = ?")
+            .bind(&content_id)
+            .execute(&app_state.pool)
+            .await
+            .expect("Failed to publish test content");
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, list()).await;
+
+        assert_eq!(resp.status, 200);
+        let data = resp.data.expect("Missing response data").properties;
+        let contents = data["contents"].as_array().expect("Missing contents");
+        assert_eq!(contents.len(), 1);
+        assert_eq!(contents[0]["slug"], content_id);
+
+        // Internal fields stay in the admin API
+        assert!(contents[0].get("protected").is_none());
+        assert!(contents[0].get("createdBy").is_none());
+        assert!(contents[0].get("recordState").is_none());
+    }
+}
@@ -0,0 +1,2 @@
+pub mod get_content_by_slug;
+pub mod get_contents;
@@ -0,0 +1,144 @@
+use actix_web::{delete, web};
+use log::{info, warn};
+
+use crate::{
+    core::{app_state::AppState, policy::ensure_unprotected, storage::Storage},
+    model::user::UserModel,
+    schema::response::{
+        admin::users::AuthUser, api_response::ApiResponse, api_response_error::ApiResponseError,
+        api_response_object::ApiResponseObject, project::profile::ProfileResponse,
+    },
+};
+
+// Endpoint to delete the avatar of the user, the initials are shown instead
+#[utoipa::path(
+    delete,
+    path = "/api/profile/avatar",
+    tag = "Profile Endpoint",
+    responses(
+        (status = 200, description= "Avatar Deleted", body = ApiResponse),
+        (status = 401, description= "Unauthorized", body = ApiResponseError),
+        (status = 403, description= "Profile Is Protected", body = ApiResponseError),
+        (status = 404, description= "User Not Found", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[delete("/avatar")]
+pub async fn delete_avatar_handler(
+    auth: AuthUser,
+    app_state: web::Data<AppState>,
+    storage: web::Data<dyn Storage>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let user = sqlx::query_as!(UserModel, "SELECT * FROM users WHERE id = ?", auth.id)
+        .fetch_optional(&app_state.pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?
+        .ok_or_else(|| ApiResponseError::new(404, "User not found".to_string(), None))?;
+
+    // Protected users cannot update their profile
+    ensure_unprotected(&user)?;
+
+    if let Some(ref avatar) = user.avatar {
+        sqlx::query("UPDATE users SET avatar = NULL WHERE id = ?")
+            .bind(&user.id)
+            .execute(&app_state.pool)
+            .await
+            .map_err(|e| {
+                ApiResponseError::new(500, format!("Internal Server Error: {}", e), None)
+            })?;
+        info!("Avatar of user {} deleted", user.id);
+
+        if let Err(e) = storage.delete(avatar).await {
+            warn!("Failed to delete the avatar {}: {}", avatar, e);
+        }
+    }
+
+    let updated_user = UserModel {
+        avatar: None,
+        ..user
+    };
+    let user_response = ApiResponseObject::new(serde_json::json!({
+        "profile": ProfileResponse::new(&updated_user)
+    }))
+    .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        200,
+        "Avatar Deleted".to_string(),
+        Some(user_response),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        core::{
+            storage::{local::LocalStorage, Storage},
+            utils::test_utils::{create_test_app_state, generate_jwt},
+        },
+        middlewares::auth_middleware::RequireAuth,
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::header, test, web, App};
+
+    // Seeded moderator, not protected
+    const USER_ID: &str = "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e24";
+
+    #[actix_web::test]
+    async fn test_delete_avatar_handler() {
+        let app_state = create_test_app_state().await;
+        let root = std::env::temp_dir().join(format!("uploads-{}", uuid::Uuid::new_v4()));
+        let storage: Arc<dyn Storage> = Arc::new(LocalStorage::new(&root));
+
+        // Create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .app_data(web::Data::from(storage.clone()))
+                .wrap(RequireAuth {})
+                .service(web::scope("/api").configure(routes::project::profile::config)),
+        )
+        .await;
+
+        let key = format!("avatars/{}/{}.jpg", USER_ID, uuid::Uuid::new_v4());
+        storage
+            .put(&key, b"avatar".to_vec(), "image/jpeg")
+            .await
+            .expect("Failed to store test avatar");
+        sqlx::query("UPDATE users SET avatar = ? WHERE id = ?")
+            .bind(&key)
+            .bind(USER_ID)
+            .execute(&app_state.pool)
+            .await
+            .expect("Failed to set test avatar");
+
+        let req = test::TestRequest::delete()
+            .uri("/api/profile/avatar")
+            .insert_header((
+                header::AUTHORIZATION,
+                format!("Bearer {}", generate_jwt(USER_ID)),
+            ))
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 200);
+        assert_eq!(resp.message, "Avatar Deleted");
+        assert!(!root.join(&key).exists());
+
+        // The initials are shown instead
+        let data = resp.data.expect("Missing response data").properties;
+        assert!(data["profile"]["avatarUrl"]
+            .as_str()
+            .unwrap()
+            .starts_with("data:image/svg+xml,"));
+
+        std::fs::remove_dir_all(root).ok();
+    }
+}
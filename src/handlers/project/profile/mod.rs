@@ -1,3 +1,5 @@
+pub mod delete_avatar;
 pub mod get_profile;
 
 pub mod update_profile;
+pub mod upload_avatar;
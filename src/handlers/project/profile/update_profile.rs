@@ -1,7 +1,7 @@
 use actix_web::{patch, web};
 
 use crate::{
-    core::app_state::AppState,
+    core::{app_state::AppState, phone::normalize_mobile, policy::ensure_unprotected},
     model::user::UserModel,
     schema::{
         project::profile::update_profile::UpdateProfileSchema,
@@ -18,11 +18,12 @@ use crate::{
     patch,
     path = "/api/profile/update",
     tag = "Profile Endpoint",
-    request_body(content = UpdateProfileSchema, description = "Credentials to update profile", example = json!({"name": "Ahmed","mobile": "0123456789","email": "ahmed@example.com","gender": "Male"})),
+    request_body(content = UpdateProfileSchema, description = "Credentials to update profile", example = json!({"name": "Ahmed","mobile": "0501234567","email": "ahmed@example.com","gender": "Male"})),
     responses(
         (status = 204, description= "Profile Updated", body = ApiResponse),       
-        (status = 210, description= "Profile Is Protected", body = ApiResponseError),       
+        (status = 400, description= "Validation Error", body = ApiResponseError),       
         (status = 401, description= "Unauthorized", body = ApiResponseError),       
+        (status = 403, description= "Profile Is Protected", body = ApiResponseError),       
         (status = 404, description= "User Not Found", body = ApiResponseError),       
         (status = 500, description= "Internal Server Error", body = ApiResponseError),       
     ),
@@ -56,24 +57,16 @@ pub async fn update_profile_handler(
         }
     };
 
-    // Check if the user is protected (cannot be updated)
-    if user.protected == 1 {
-        return Err(ApiResponseError::new(
-            210,
-            "User is protected".to_string(),
-            None,
-        ));
-    }
+    // Protected users cannot update their profile
+    ensure_unprotected(&user)?;
+
+    let mobile = body.mobile.as_deref().map(normalize_mobile).transpose()?;
 
     // Update user profile in the database
     let update_result =
         sqlx::query("UPDATE users SET name = ?, mobile = ?, email = ?, gender = ? WHERE id = ?")
             .bind(body.name.to_owned().unwrap_or_else(|| user.name.clone()))
-            .bind(
-                body.mobile
-                    .to_owned()
-                    .unwrap_or_else(|| user.mobile.clone()),
-            )
+            .bind(mobile.unwrap_or_else(|| user.mobile.clone()))
             .bind(
                 body.email
                     .to_owned()
@@ -157,15 +150,20 @@ mod tests {
     use rand::Rng;
     use sqlx::MySqlPool;
 
-    const USER_ID: &str = "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e2b";
+    // Seeded moderator, the seeded admin is protected and cannot update its profile
+    const USER_ID: &str = "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e24";
+    const PROTECTED_USER_ID: &str = "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e2b";
 
-    fn generate_jwt() -> String {
+    fn generate_jwt(user_id: &str) -> String {
         let expire = chrono::Duration::minutes(60);
         let now = chrono::Utc::now();
         let claims = crate::core::utils::jwt::Claims {
             exp: (now + expire).timestamp() as usize,
             iat: now.timestamp() as usize,
-            id: USER_ID.to_owned(),
+            id: user_id.to_owned(),
+            jti: uuid::Uuid::new_v4().to_string(),
+            sid: None,
+            impersonator: None,
         };
         let encoding_key = jsonwebtoken::EncodingKey::from_secret(CONFIG.jwt.secret.as_ref());
         jsonwebtoken::encode(&jsonwebtoken::Header::default(), &claims, &encoding_key).unwrap()
@@ -196,12 +194,12 @@ mod tests {
         )
         .await;
 
-        let jwt = generate_jwt();
+        let jwt = generate_jwt(USER_ID);
 
         let name: Option<String> = Some(Name().fake());
         let mobile = Some(format!(
-            "9665{}",
-            rand::thread_rng().gen_range(10000000..99999999)
+            "+96650{}",
+            rand::thread_rng().gen_range(1000000..9999999)
         ));
         let email: Option<String> = Some(SafeEmail().fake());
         // Update profile data
@@ -218,7 +216,7 @@ mod tests {
                 actix_web::http::header::AUTHORIZATION,
                 format!("Bearer {}", jwt),
             ))
-            .set_json(update_profile_data)
+            .set_json(&update_profile_data)
             .to_request();
 
         let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
@@ -236,5 +234,19 @@ mod tests {
         assert_eq!(updated_user.mobile, mobile.unwrap());
         assert_eq!(updated_user.email, Some(email.unwrap()));
         assert_eq!(updated_user.gender, Some("Male".to_string()));
+
+        // Protected users cannot update their profile
+        let req = test::TestRequest::patch()
+            .uri("/api/profile/update")
+            .insert_header((
+                actix_web::http::header::AUTHORIZATION,
+                format!("Bearer {}", generate_jwt(PROTECTED_USER_ID)),
+            ))
+            .set_json(&update_profile_data)
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), actix_web::http::StatusCode::FORBIDDEN);
     }
 }
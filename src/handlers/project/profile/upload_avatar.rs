@@ -0,0 +1,253 @@
+use actix_multipart::Multipart;
+use actix_web::{post, web};
+use log::{info, warn};
+
+use crate::{
+    config::CONFIG,
+    core::{
+        app_state::AppState,
+        avatar::{parse_crop, render_avatar},
+        image_variant::image_dimensions,
+        policy::ensure_unprotected,
+        storage::Storage,
+        upload::read_image_form,
+    },
+    model::user::UserModel,
+    schema::response::{
+        admin::users::AuthUser, api_response::ApiResponse, api_response_error::ApiResponseError,
+        api_response_object::ApiResponseObject, project::profile::ProfileResponse,
+    },
+};
+
+// Endpoint to upload the avatar of the user
+#[utoipa::path(
+    post,
+    path = "/api/profile/avatar",
+    tag = "Profile Endpoint",
+    request_body(content = UploadAvatarSchema, description = "Image in the `avatar` field, cropped to the square `x`, `y`, `size` or to the centered square when they are not sent", content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description= "Avatar Uploaded", body = ApiResponse),
+        (status = 400, description= "Validation Error", body = ApiResponseError),
+        (status = 401, description= "Unauthorized", body = ApiResponseError),
+        (status = 403, description= "Profile Is Protected", body = ApiResponseError),
+        (status = 404, description= "User Not Found", body = ApiResponseError),
+        (status = 413, description= "Image is too large", body = ApiResponseError),
+        (status = 415, description= "Image is not a JPEG, PNG, GIF or WebP file", body = ApiResponseError),
+        (status = 500, description= "Internal Server Error", body = ApiResponseError),
+    ),
+    security(
+       ("auth_token" = [])
+   )
+)]
+#[post("/avatar")]
+pub async fn upload_avatar_handler(
+    payload: Multipart,
+    auth: AuthUser,
+    app_state: web::Data<AppState>,
+    storage: web::Data<dyn Storage>,
+) -> Result<ApiResponse, ApiResponseError> {
+    let user = sqlx::query_as!(UserModel, "SELECT * FROM users WHERE id = ?", auth.id)
+        .fetch_optional(&app_state.pool)
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?
+        .ok_or_else(|| ApiResponseError::new(404, "User not found".to_string(), None))?;
+
+    // Protected users cannot update their profile
+    ensure_unprotected(&user)?;
+
+    let form = read_image_form(
+        payload,
+        "avatar",
+        CONFIG.storage.max_upload_bytes,
+        &["x", "y", "size"],
+    )
+    .await?;
+    let (width, height) = image_dimensions(&form.image.bytes)
+        .map_err(|_| ApiResponseError::new(400, "Image could not be read".to_string(), None))?;
+    let crop = parse_crop(&form.fields, width, height)?;
+
+    let bytes = form.image.bytes;
+    let avatar = tokio::task::spawn_blocking(move || render_avatar(&bytes, crop))
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?
+        .map_err(|_| ApiResponseError::new(400, "Image could not be read".to_string(), None))?;
+
+    // A new key for every upload, cached copies of the previous avatar are never served
+    let key = format!("avatars/{}/{}.jpg", user.id, uuid::Uuid::new_v4());
+    storage
+        .put(&key, avatar, "image/jpeg")
+        .await
+        .map_err(|e| ApiResponseError::new(500, format!("Internal Server Error: {}", e), None))?;
+
+    let update_result = sqlx::query("UPDATE users SET avatar = ? WHERE id = ?")
+        .bind(&key)
+        .bind(&user.id)
+        .execute(&app_state.pool)
+        .await;
+
+    if let Err(e) = update_result {
+        if let Err(delete_error) = storage.delete(&key).await {
+            warn!(
+                "Failed to delete the unused avatar {}: {}",
+                key, delete_error
+            );
+        }
+        return Err(ApiResponseError::new(
+            500,
+            format!("Internal Server Error: {}", e),
+            None,
+        ));
+    }
+    info!("Avatar of user {} uploaded", user.id);
+
+    if let Some(ref previous) = user.avatar {
+        if let Err(e) = storage.delete(previous).await {
+            warn!("Failed to delete the previous avatar {}: {}", previous, e);
+        }
+    }
+
+    let updated_user = UserModel {
+        avatar: Some(key),
+        ..user
+    };
+    let user_response = ApiResponseObject::new(serde_json::json!({
+        "profile": ProfileResponse::new(&updated_user)
+    }))
+    .map_err(|e| ApiResponseError::new(500, e.to_string(), None))?;
+
+    Ok(ApiResponse::new(
+        200,
+        "Avatar Uploaded".to_string(),
+        Some(user_response),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, sync::Arc};
+
+    use crate::{
+        core::{
+            avatar::AVATAR_SIZE,
+            storage::{local::LocalStorage, Storage},
+            utils::test_utils::{create_test_app_state, generate_jwt},
+        },
+        middlewares::auth_middleware::RequireAuth,
+        routes,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::header, http::StatusCode, test, web, App};
+    use image::{ImageFormat, Rgb, RgbImage};
+
+    // Seeded moderator, the seeded admin is protected and cannot update its profile
+    const USER_ID: &str = "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e24";
+    const PROTECTED_USER_ID: &str = "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e2b";
+    const BOUNDARY: &str = "avatar-upload-boundary";
+
+    fn multipart_body(fields: &[(&str, &str)], bytes: &[u8]) -> Vec<u8> {
+        let mut body = Vec::new();
+        for (name, value) in fields {
+            body.extend_from_slice(
+                format!(
+                    "--{}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n{}\r\n",
+                    BOUNDARY, name, value
+                )
+                .as_bytes(),
+            );
+        }
+        body.extend_from_slice(
+            format!(
+                "--{}\r\nContent-Disposition: form-data; name=\"avatar\"; filename=\"me.png\"\r\nContent-Type: image/png\r\n\r\n",
+                BOUNDARY
+            )
+            .as_bytes(),
+        );
+        body.extend_from_slice(bytes);
+        body.extend_from_slice(format!("\r\n--{}--\r\n", BOUNDARY).as_bytes());
+        body
+    }
+
+    #[actix_web::test]
+    async fn test_upload_avatar_handler() {
+        let app_state = create_test_app_state().await;
+        let root = std::env::temp_dir().join(format!("uploads-{}", uuid::Uuid::new_v4()));
+        let storage: Arc<dyn Storage> = Arc::new(LocalStorage::new(&root));
+
+        // Create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .app_data(web::Data::from(storage.clone()))
+                .wrap(RequireAuth {})
+                .service(web::scope("/api").configure(routes::project::profile::config)),
+        )
+        .await;
+
+        let upload = |user_id: &str, body: Vec<u8>| {
+            test::TestRequest::post()
+                .uri("/api/profile/avatar")
+                .insert_header((
+                    header::AUTHORIZATION,
+                    format!("Bearer {}", generate_jwt(user_id)),
+                ))
+                .insert_header((
+                    header::CONTENT_TYPE,
+                    format!("multipart/form-data; boundary={}", BOUNDARY),
+                ))
+                .set_payload(body)
+                .to_request()
+        };
+        let mut png = Vec::new();
+        RgbImage::from_pixel(600, 400, Rgb([30, 120, 200]))
+            .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+            .unwrap();
+
+        let resp = test::call_service(
+            &app,
+            upload(
+                USER_ID,
+                multipart_body(&[("x", "300"), ("y", "0"), ("size", "400")], &png),
+            ),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let resp: ApiResponse = test::call_and_read_body_json(
+            &app,
+            upload(
+                USER_ID,
+                multipart_body(&[("x", "100"), ("y", "50"), ("size", "300")], &png),
+            ),
+        )
+        .await;
+        assert_eq!(resp.status, 200);
+        let data = resp.data.expect("Missing response data").properties;
+
+        let key: String = sqlx::query_scalar("SELECT avatar FROM users WHERE id = ?")
+            .bind(USER_ID)
+            .fetch_one(&app_state.pool)
+            .await
+            .expect("Failed to fetch the avatar");
+        assert!(key.starts_with(&format!("avatars/{}/", USER_ID)));
+        assert!(data["profile"]["avatarUrl"]
+            .as_str()
+            .unwrap()
+            .ends_with(&key));
+
+        let avatar = image::load_from_memory(&std::fs::read(root.join(&key)).unwrap()).unwrap();
+        assert_eq!(avatar.width(), AVATAR_SIZE);
+        assert_eq!(avatar.height(), AVATAR_SIZE);
+
+        // A new avatar replaces the stored file
+        let resp = test::call_service(&app, upload(USER_ID, multipart_body(&[], &png))).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert!(!root.join(&key).exists());
+
+        // Protected users cannot update their profile
+        let resp =
+            test::call_service(&app, upload(PROTECTED_USER_ID, multipart_body(&[], &png))).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        std::fs::remove_dir_all(root).ok();
+    }
+}
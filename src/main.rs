@@ -1,5 +1,8 @@
 use core::{
-    api_doc::ApiDoc, app_state::AppState, mail::email_queue::EmailQueue, sms::sms_queue::SmsQueue,
+    api_doc::ApiDoc, app_state::AppState, image_variant::variant_queue::VariantQueue,
+    mail::email_queue::EmailQueue,
+    registration::process_registration_cleanup, schedule::process_content_schedule,
+    sms::sms_queue::SmsQueue, storage::storage_from_config,
 };
 use std::{error::Error, fmt::Display, sync::Arc};
 
@@ -9,6 +12,8 @@ use config::CONFIG;
 use dotenv::dotenv;
 use env_logger::Env;
 use log::{error, info};
+use middlewares::app_key_middleware::{RequireAppKey, APP_KEY_HEADER};
+use middlewares::signature_middleware::{NONCE_HEADER, SIGNATURE_HEADER, TIMESTAMP_HEADER};
 use openssl::ssl::{SslAcceptor, SslFiletype, SslMethod};
 use sqlx::mysql::MySqlPoolOptions;
 use utoipa::OpenApi;
@@ -61,6 +66,32 @@ async fn main() -> Result<(), MainError> {
         SmsQueue::process_queue(sms_receiver).await;
     });
 
+    // Spawn the cleanup of registrations that were never verified
+    let cleanup_pool = app_state.pool.clone();
+    tokio::spawn(async move {
+        info!("Starting registration cleanup task");
+        process_registration_cleanup(cleanup_pool).await;
+    });
+
+    // Spawn the scheduler that publishes and unpublishes contents on time
+    let schedule_pool = app_state.pool.clone();
+    tokio::spawn(async move {
+        info!("Starting content schedule task");
+        process_content_schedule(schedule_pool).await;
+    });
+
+    // Backend of the uploaded files, selected by the storage driver
+    let storage = storage_from_config();
+
+    // Initialize the image variants queue and spawn its processing task
+    let (variant_queue, variant_receiver) = VariantQueue::new();
+    let variant_pool = app_state.pool.clone();
+    let variant_storage = storage.clone();
+    tokio::spawn(async move {
+        info!("Starting image variants processing task");
+        VariantQueue::process_queue(variant_receiver, variant_pool, variant_storage).await;
+    });
+
     let mut builder = SslAcceptor::mozilla_intermediate(SslMethod::tls()).unwrap();
     builder
         .set_private_key_file("certs/key.pem", SslFiletype::PEM)
@@ -80,6 +111,10 @@ async fn main() -> Result<(), MainError> {
                 header::CONTENT_TYPE,
                 header::AUTHORIZATION,
                 header::ACCEPT,
+                header::HeaderName::from_static(APP_KEY_HEADER),
+                header::HeaderName::from_static(TIMESTAMP_HEADER),
+                header::HeaderName::from_static(NONCE_HEADER),
+                header::HeaderName::from_static(SIGNATURE_HEADER),
             ])
             .supports_credentials();
 
@@ -87,17 +122,26 @@ async fn main() -> Result<(), MainError> {
             .app_data(web::Data::from(app_state.clone()))
             .app_data(web::Data::new(email_queue.clone()))
             .app_data(web::Data::new(sms_queue.clone()))
+            .app_data(web::Data::from(storage.clone()))
+            .app_data(web::Data::new(variant_queue.clone()))
             .service(web::scope("/seed").configure(core::faker::config))
+            .configure(routes::files::config)
             .service(
                 web::scope("/api")
+                    .wrap(RequireAppKey {})
                     .configure(routes::health_checker::config)
                     .configure(routes::project::profile::config)
+                    .configure(routes::project::contents::config)
                     .configure(routes::auth::config),
             )
             .service(
                 web::scope("/admin")
                     .configure(routes::admin::user::config)
-                    .configure(routes::admin::content::config),
+                    .configure(routes::admin::content::config)
+                    .configure(routes::admin::media::config)
+                    .configure(routes::admin::files::config)
+                    .configure(routes::admin::applications::config)
+                    .configure(routes::admin::roles::config),
             )
             .service(Redoc::with_url("/redoc", openapi.clone()))
             .service(RapiDoc::new("/api-docs/openapi.json").path("/rapidoc"))
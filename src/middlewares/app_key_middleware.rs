@@ -0,0 +1,171 @@
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::error::{ErrorInternalServerError, ErrorUnauthorized};
+use actix_web::{web, HttpMessage};
+use futures_util::future::{ready, LocalBoxFuture, Ready};
+use futures_util::FutureExt;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+
+use crate::core::app_state::AppState;
+use crate::core::application::find_active_application_by_key;
+use crate::schema::response::admin::applications::AuthApplication;
+use crate::schema::response::api_response_error::ApiResponseError;
+
+// Header carrying the key of the calling application
+pub const APP_KEY_HEADER: &str = "x-app-key";
+
+pub struct RequireAppKey {}
+
+impl<S> Transform<S, ServiceRequest> for RequireAppKey
+where
+    S: Service<
+            ServiceRequest,
+            Response = ServiceResponse<actix_web::body::BoxBody>,
+            Error = actix_web::Error,
+        > + 'static,
+{
+    type Response = ServiceResponse<actix_web::body::BoxBody>;
+    type Error = actix_web::Error;
+    type Transform = AppKeyMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(AppKeyMiddleware {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+/// Middleware responsible for authenticating the calling application by its app key.
+pub struct AppKeyMiddleware<S> {
+    service: Rc<S>,
+}
+
+impl<S> Service<ServiceRequest> for AppKeyMiddleware<S>
+where
+    S: Service<
+            ServiceRequest,
+            Response = ServiceResponse<actix_web::body::BoxBody>,
+            Error = actix_web::Error,
+        > + 'static,
+{
+    type Response = ServiceResponse<actix_web::body::BoxBody>;
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, actix_web::Error>>;
+
+    /// Polls the readiness of the wrapped service.
+    fn poll_ready(&self, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(ctx)
+    }
+
+    /// Handles incoming requests.
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let app_key = req
+            .headers()
+            .get(APP_KEY_HEADER)
+            .and_then(|h| h.to_str().ok())
+            .map(|key| key.trim().to_string())
+            .filter(|key| !key.is_empty());
+
+        // If the app key is missing, return unauthorized error
+        let Some(app_key) = app_key else {
+            return Box::pin(ready(Err(ErrorUnauthorized(ApiResponseError::new(
+                401,
+                "App key is required".to_string(),
+                None,
+            )))));
+        };
+
+        let app_state = req.app_data::<web::Data<AppState>>().unwrap().clone();
+        let srv = Rc::clone(&self.service);
+
+        async move {
+            let application = find_active_application_by_key(&app_state.pool, &app_key)
+                .await
+                .map_err(actix_web::Error::from)?;
+
+            // Count the request against the application
+            sqlx::query("UPDATE applications SET app_requests = app_requests + 1 WHERE id = ?")
+                .bind(&application.id)
+                .execute(&app_state.pool)
+                .await
+                .map_err(|e| {
+                    ErrorInternalServerError(ApiResponseError::new(500, e.to_string(), None))
+                })?;
+
+            // Insert application information into request extensions
+            let mut auth_application = AuthApplication::filter_db(&application);
+            auth_application.app_requests += 1;
+            req.extensions_mut()
+                .insert::<AuthApplication>(auth_application);
+
+            // Call the wrapped service to handle the request
+            srv.call(req).await
+        }
+        .boxed_local()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RequireAppKey, APP_KEY_HEADER};
+    use crate::{
+        core::utils::test_utils::create_test_app_state,
+        schema::response::{admin::applications::AuthApplication, api_response::ApiResponse},
+    };
+    use actix_web::{get, http::StatusCode, test, web, App};
+
+    // Seeded by the applications migration
+    const TEST_APP_KEY: &str = "fe1zev3u5aubxn46j71aijfy5h44wgq882hjunw6qc";
+
+    #[get("/application")]
+    async fn application_handler(application: AuthApplication) -> ApiResponse {
+        ApiResponse::new(200, application.app_name, None)
+    }
+
+    #[actix_web::test]
+    async fn test_require_app_key() {
+        let app_state = create_test_app_state().await;
+
+        // create and configure the test app
+        let app = test::init_service(
+            App::new().app_data(app_state.clone()).service(
+                web::scope("/api")
+                    .wrap(RequireAppKey {})
+                    .service(application_handler),
+            ),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/api/application")
+            .insert_header((APP_KEY_HEADER, TEST_APP_KEY))
+            .to_request();
+
+        let resp: ApiResponse = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp.status, 200);
+        assert_eq!(resp.message, "actix.test");
+
+        // Requests without a key or with an unknown key are rejected
+        let req = test::TestRequest::get()
+            .uri("/api/application")
+            .to_request();
+        let resp = test::try_call_service(&app, req).await.unwrap_err();
+        assert_eq!(
+            resp.as_response_error().status_code(),
+            StatusCode::UNAUTHORIZED
+        );
+
+        let req = test::TestRequest::get()
+            .uri("/api/application")
+            .insert_header((APP_KEY_HEADER, "unknown-key"))
+            .to_request();
+        let resp = test::try_call_service(&app, req).await.unwrap_err();
+        assert_eq!(
+            resp.as_response_error().status_code(),
+            StatusCode::UNAUTHORIZED
+        );
+    }
+}
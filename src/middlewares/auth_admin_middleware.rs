@@ -1,5 +1,5 @@
 use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform};
-use actix_web::error::{ErrorInternalServerError, ErrorUnauthorized};
+use actix_web::error::{ErrorForbidden, ErrorInternalServerError, ErrorUnauthorized};
 use actix_web::{http, web, HttpMessage};
 use futures_util::future::{ready, LocalBoxFuture, Ready};
 use futures_util::FutureExt;
@@ -8,23 +8,33 @@ use std::rc::Rc;
 use std::task::{Context, Poll};
 
 use crate::core::app_state::AppState;
-use crate::core::constants::roles::ADMIN_ROLE;
-use crate::core::enums::UserRole;
-use crate::core::utils::jwt::decode_jwt;
+use crate::core::permission::role_requires_two_factor;
+use crate::core::session::revocation::is_token_revoked;
+use crate::core::two_factor::is_two_factor_enabled;
+use crate::core::utils::jwt::{decode_jwt, Claims};
 use crate::model::user::UserModel;
 use crate::schema::response::admin::users::AuthUser;
 use crate::schema::response::api_response_error::ApiResponseError;
 
+/// Authenticates the users of the admin endpoints, whatever their role.
+///
+/// Access to each endpoint is decided by the permissions of the role, so the scopes
+/// wrap `RequirePermission` inside of it.
+#[derive(Default)]
 pub struct RequireAdminAuth {
-    pub allowed_roles: Rc<Vec<UserRole>>,
+    pub enforce_two_factor: bool,
 }
 
 impl RequireAdminAuth {
     /// Create a new instance of `RequireAdminAuth` middleware.
-    pub fn allowed_roles(allowed_roles: Vec<UserRole>) -> Self {
-        RequireAdminAuth {
-            allowed_roles: Rc::new(allowed_roles),
-        }
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reject members of the roles requiring two-factor authentication that have not enabled it.
+    pub fn enforce_two_factor(mut self, enforce: bool) -> Self {
+        self.enforce_two_factor = enforce;
+        self
     }
 }
 
@@ -45,7 +55,7 @@ where
     fn new_transform(&self, service: S) -> Self::Future {
         ready(Ok(AuthAdminMiddleware {
             service: Rc::new(service),
-            allowed_roles: self.allowed_roles.clone(),
+            enforce_two_factor: self.enforce_two_factor,
         }))
     }
 }
@@ -53,7 +63,7 @@ where
 /// Middleware responsible for handling authentication and user information extraction.
 pub struct AuthAdminMiddleware<S> {
     service: Rc<S>,
-    allowed_roles: Rc<Vec<UserRole>>,
+    enforce_two_factor: bool,
 }
 
 impl<S> Service<ServiceRequest> for AuthAdminMiddleware<S>
@@ -92,7 +102,7 @@ where
         }
 
         let app_state = req.app_data::<web::Data<AppState>>().unwrap().clone();
-        let allowed_roles = self.allowed_roles.clone();
+        let enforce_two_factor = self.enforce_two_factor;
         let srv = Rc::clone(&self.service);
         let token = token.unwrap();
         let token = token.replace("Bearer ", "");
@@ -124,31 +134,49 @@ where
             }
         };
 
+        // Impersonation tokens only act as the user on the /api endpoints
+        if claim.claims.impersonator.is_some() {
+            return Box::pin(ready(Err(ErrorForbidden(ApiResponseError::new(
+                403,
+                "Impersonation tokens cannot be used on admin endpoints".to_string(),
+                None,
+            )))));
+        }
+
         // Handle user extraction and request processing
         async move {
-            // Query user from database based on decoded user ID
+            // Reject tokens revoked by a logout or whose session has been ended
+            match is_token_revoked(&app_state.pool, &claim.claims).await {
+                Ok(false) => {}
+                Ok(true) => {
+                    return Err(ErrorUnauthorized(ApiResponseError::new(
+                        401,
+                        "Token has been revoked".to_string(),
+                        None,
+                    )))
+                }
+                Err(e) => {
+                    return Err(ErrorInternalServerError(ApiResponseError::new(
+                        500,
+                        e.to_string(),
+                        None,
+                    )))
+                }
+            }
+
+            // Query user from database based on decoded user ID, deleted users are signed out
             let query_result = sqlx::query_as!(
                 UserModel,
-                "SELECT * FROM users WHERE id = ?",
+                "SELECT * FROM users WHERE id = ? AND deleted_at IS NULL",
                 claim.claims.id
             )
-            .fetch_one(&app_state.pool)
+            .fetch_optional(&app_state.pool)
             .await;
 
             // Handle query result
             let auth_data = match query_result {
-                Ok(mut user) => {
-                    // Check if the user has admin role
-                    let role_id = user.role_id;
+                Ok(Some(mut user)) => {
                     let is_active = user.active != 0;
-                    if role_id != ADMIN_ROLE {
-                        return Err(ErrorUnauthorized(ApiResponseError::new(
-                            401,
-                            "Unauthorized: You are not an admin".to_string(),
-                            None,
-                        )));
-                    }
-
                     if !is_active {
                         return Err(ErrorUnauthorized(ApiResponseError::new(
                             401,
@@ -156,17 +184,46 @@ where
                             None,
                         )));
                     }
-                    // Convert role_id to UserRole and check if it is in allowed_roles
-                    let user_role = UserRole::from_i32(role_id);
-                    if user_role.is_none() || !allowed_roles.contains(&user_role.unwrap()) {
-                        return Err(ErrorUnauthorized(ApiResponseError::new(
-                            401,
-                            "Unauthorized: You don't have correct role".to_string(),
-                            None,
-                        )));
+                    // Members of the flagged roles must enroll an authenticator app before using admin endpoints
+                    let requires_two_factor = enforce_two_factor
+                        && role_requires_two_factor(&app_state.pool, user.role_id)
+                            .await
+                            .map_err(|e| {
+                                ErrorInternalServerError(ApiResponseError::new(
+                                    500,
+                                    e.to_string(),
+                                    None,
+                                ))
+                            })?;
+                    if requires_two_factor {
+                        match is_two_factor_enabled(&app_state.pool, &user.id).await {
+                            Ok(true) => {}
+                            Ok(false) => {
+                                return Err(ErrorForbidden(ApiResponseError::new(
+                                    403,
+                                    "Two-factor authentication is required for your role"
+                                        .to_string(),
+                                    None,
+                                )))
+                            }
+                            Err(e) => {
+                                return Err(ErrorInternalServerError(ApiResponseError::new(
+                                    500,
+                                    e.to_string(),
+                                    None,
+                                )))
+                            }
+                        }
                     }
                     AuthUser::filter_db(&mut user)
                 }
+                Ok(None) => {
+                    return Err(ErrorUnauthorized(ApiResponseError::new(
+                        401,
+                        "Unauthorized: User not found".to_string(),
+                        None,
+                    )))
+                }
                 Err(e) => {
                     return Err(ErrorInternalServerError(ApiResponseError::new(
                         500,
@@ -178,6 +235,8 @@ where
 
             // Insert user information into request extensions
             req.extensions_mut().insert::<AuthUser>(auth_data);
+            // Insert token claims so handlers can revoke the current token
+            req.extensions_mut().insert::<Claims>(claim.claims);
 
             // Call the wrapped service to handle the request
             let res = srv.call(req).await.map_err(|e| {
@@ -188,3 +247,118 @@ where
         .boxed_local()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::RequireAdminAuth;
+    use crate::{
+        core::utils::test_utils::{create_test_app_state, generate_jwt},
+        middlewares::permission_middleware::RequirePermission,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{get, http::header, http::StatusCode, test, web, App};
+    use rand::Rng;
+
+    #[get("")]
+    async fn admin_handler() -> ApiResponse {
+        ApiResponse::new(200, "Allowed".to_string(), None)
+    }
+
+    #[actix_web::test]
+    async fn test_require_admin_auth_custom_role() {
+        let app_state = create_test_app_state().await;
+
+        // create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .service(
+                    web::scope("/media")
+                        .wrap(RequirePermission("media.view"))
+                        .wrap(RequireAdminAuth::new().enforce_two_factor(true))
+                        .service(admin_handler),
+                )
+                .service(
+                    web::scope("/users")
+                        .wrap(RequirePermission("users.manage"))
+                        .wrap(RequireAdminAuth::new().enforce_two_factor(true))
+                        .service(admin_handler),
+                ),
+        )
+        .await;
+
+        // A role created through the roles endpoints, unknown to the code
+        let role_id = sqlx::query("INSERT INTO roles (name) VALUES (?)")
+            .bind(format!(
+                "support.{}",
+                &uuid::Uuid::new_v4().to_string()[..8]
+            ))
+            .execute(&app_state.pool)
+            .await
+            .expect("Failed to insert test role")
+            .last_insert_id();
+        sqlx::query(
+            "INSERT INTO role_permissions (role_id, permission_id) SELECT ?, id FROM permissions WHERE name = 'media.view'",
+        )
+        .bind(role_id)
+        .execute(&app_state.pool)
+        .await
+        .expect("Failed to grant test permission");
+
+        let user_id = uuid::Uuid::new_v4().to_string();
+        sqlx::query("INSERT INTO users (id, name, mobile, role_id) VALUES (?, ?, ?, ?)")
+            .bind(&user_id)
+            .bind(format!("Support User {}", &user_id[..8]))
+            .bind(format!(
+                "+96650{}",
+                rand::thread_rng().gen_range(1000000..9999999)
+            ))
+            .bind(role_id)
+            .execute(&app_state.pool)
+            .await
+            .expect("Failed to insert test user");
+
+        let get = |uri: &str| {
+            test::TestRequest::get()
+                .uri(uri)
+                .insert_header((
+                    header::AUTHORIZATION,
+                    format!("Bearer {}", generate_jwt(&user_id)),
+                ))
+                .to_request()
+        };
+
+        // The permissions of the role decide, not its id
+        let resp = test::call_service(&app, get("/media")).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let resp = test::call_service(&app, get("/users")).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        // Members of a role requiring two-factor authentication have to enable it first
+        sqlx::query("UPDATE roles SET requires_two_factor = 1 WHERE id = ?")
+            .bind(role_id)
+            .execute(&app_state.pool)
+            .await
+            .expect("Failed to update test role");
+
+        let resp = test::try_call_service(&app, get("/media"))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            resp.as_response_error().status_code(),
+            StatusCode::FORBIDDEN
+        );
+
+        sqlx::query("DELETE FROM users WHERE id = ?")
+            .bind(&user_id)
+            .execute(&app_state.pool)
+            .await
+            .expect("Failed to delete test user");
+        sqlx::query("DELETE FROM roles WHERE id = ?")
+            .bind(role_id)
+            .execute(&app_state.pool)
+            .await
+            .expect("Failed to delete test role");
+    }
+}
@@ -1,5 +1,5 @@
 use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform};
-use actix_web::error::{ErrorInternalServerError, ErrorUnauthorized};
+use actix_web::error::{ErrorForbidden, ErrorInternalServerError, ErrorUnauthorized};
 use actix_web::{http, web, HttpMessage};
 use futures_util::future::{ready, LocalBoxFuture, Ready};
 use futures_util::FutureExt;
@@ -8,7 +8,11 @@ use std::rc::Rc;
 use std::task::{Context, Poll};
 
 use crate::core::app_state::AppState;
-use crate::core::utils::jwt::decode_jwt;
+use crate::core::impersonation::{
+    check_impersonation_method, record_impersonation, ImpersonationEvent, EVENT_REQUEST,
+};
+use crate::core::session::revocation::is_token_revoked;
+use crate::core::utils::jwt::{decode_jwt, Claims};
 use crate::model::user::UserModel;
 use crate::schema::response::admin::users::AuthUser;
 use crate::schema::response::api_response_error::ApiResponseError;
@@ -124,18 +128,44 @@ where
 
         // Handle user extraction and request processing
         async move {
-            // Query user from database based on decoded user ID
+            // Reject tokens revoked by a logout or whose session has been ended
+            match is_token_revoked(&app_state.pool, &claim.claims).await {
+                Ok(false) => {}
+                Ok(true) => {
+                    return Err(ErrorUnauthorized(ApiResponseError::new(
+                        401,
+                        "Token has been revoked".to_string(),
+                        None,
+                    )))
+                }
+                Err(e) => {
+                    return Err(ErrorInternalServerError(ApiResponseError::new(
+                        500,
+                        e.to_string(),
+                        None,
+                    )))
+                }
+            }
+
+            // Query user from database based on decoded user ID, deleted users are signed out
             let query_result = sqlx::query_as!(
                 UserModel,
-                "SELECT * FROM users WHERE id = ?",
+                "SELECT * FROM users WHERE id = ? AND deleted_at IS NULL",
                 claim.claims.id
             )
-            .fetch_one(&app_state.pool)
+            .fetch_optional(&app_state.pool)
             .await;
 
             // Handle query result
-            let auth_data = match query_result {
-                Ok(mut user) => AuthUser::filter_db(&mut user),
+            let mut auth_data = match query_result {
+                Ok(Some(mut user)) => AuthUser::filter_db(&mut user),
+                Ok(None) => {
+                    return Err(ErrorUnauthorized(ApiResponseError::new(
+                        401,
+                        "Unauthorized: User not found".to_string(),
+                        None,
+                    )))
+                }
                 Err(e) => {
                     return Err(ErrorInternalServerError(ApiResponseError::new(
                         500,
@@ -145,8 +175,39 @@ where
                 }
             };
 
+            // Every request made with an impersonation token is audited, the refused ones too
+            if let Some(impersonator_id) = claim.claims.impersonator.as_deref() {
+                let event = ImpersonationEvent {
+                    impersonator_id,
+                    user_id: &auth_data.id,
+                    token_id: &claim.claims.jti,
+                    event: EVENT_REQUEST,
+                    method: req.method().as_str(),
+                    path: req
+                        .uri()
+                        .path_and_query()
+                        .map(|p| p.as_str())
+                        .unwrap_or_else(|| req.path()),
+                    ip_address: req
+                        .connection_info()
+                        .realip_remote_addr()
+                        .map(|ip| ip.to_string()),
+                };
+                if let Err(e) = record_impersonation(&app_state.pool, &event).await {
+                    return Err(ErrorInternalServerError(ApiResponseError::new(
+                        500,
+                        e.to_string(),
+                        None,
+                    )));
+                }
+                check_impersonation_method(req.method()).map_err(ErrorForbidden)?;
+                auth_data.impersonator = Some(impersonator_id.to_string());
+            }
+
             // Insert user information into request extensions
             req.extensions_mut().insert::<AuthUser>(auth_data);
+            // Insert token claims so handlers can revoke the current token
+            req.extensions_mut().insert::<Claims>(claim.claims);
 
             // Call the wrapped service to handle the request
             let res = srv.call(req).await.map_err(|e| {
@@ -1,2 +1,5 @@
+pub mod app_key_middleware;
 pub mod auth_admin_middleware;
 pub mod auth_middleware;
+pub mod permission_middleware;
+pub mod signature_middleware;
@@ -0,0 +1,165 @@
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::error::{ErrorForbidden, ErrorInternalServerError, ErrorUnauthorized};
+use actix_web::{web, HttpMessage};
+use futures_util::future::{ready, LocalBoxFuture, Ready};
+use futures_util::FutureExt;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+
+use crate::core::app_state::AppState;
+use crate::core::permission::has_permission;
+use crate::schema::response::admin::users::AuthUser;
+use crate::schema::response::api_response_error::ApiResponseError;
+
+/// Requires the role of the authenticated user to be granted a permission, e.g.
+/// `RequirePermission("contents.update")`.
+///
+/// It reads the `AuthUser` set by `RequireAuth` or `RequireAdminAuth`, so it has to be
+/// wrapped inside one of them.
+pub struct RequirePermission(pub &'static str);
+
+impl<S> Transform<S, ServiceRequest> for RequirePermission
+where
+    S: Service<
+            ServiceRequest,
+            Response = ServiceResponse<actix_web::body::BoxBody>,
+            Error = actix_web::Error,
+        > + 'static,
+{
+    type Response = ServiceResponse<actix_web::body::BoxBody>;
+    type Error = actix_web::Error;
+    type Transform = PermissionMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(PermissionMiddleware {
+            service: Rc::new(service),
+            permission: self.0,
+        }))
+    }
+}
+
+/// Middleware responsible for checking the permissions of the authenticated user.
+pub struct PermissionMiddleware<S> {
+    service: Rc<S>,
+    permission: &'static str,
+}
+
+impl<S> Service<ServiceRequest> for PermissionMiddleware<S>
+where
+    S: Service<
+            ServiceRequest,
+            Response = ServiceResponse<actix_web::body::BoxBody>,
+            Error = actix_web::Error,
+        > + 'static,
+{
+    type Response = ServiceResponse<actix_web::body::BoxBody>;
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, actix_web::Error>>;
+
+    /// Polls the readiness of the wrapped service.
+    fn poll_ready(&self, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(ctx)
+    }
+
+    /// Handles incoming requests.
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let permission = self.permission;
+        let user_id = req
+            .extensions()
+            .get::<AuthUser>()
+            .map(|user| user.id.clone());
+
+        // The outer auth middlewares map errors of the inner services to 500,
+        // so rejections are sent as responses instead
+        let Some(user_id) = user_id else {
+            let json_error = ApiResponseError::new(401, "Unauthorized".to_string(), None);
+            return Box::pin(ready(Ok(req.error_response(ErrorUnauthorized(json_error)))));
+        };
+
+        let app_state = req.app_data::<web::Data<AppState>>().unwrap().clone();
+        let srv = Rc::clone(&self.service);
+
+        async move {
+            match has_permission(&app_state.pool, &user_id, permission).await {
+                Ok(true) => srv.call(req).await,
+                Ok(false) => Ok(req.error_response(ErrorForbidden(ApiResponseError::new(
+                    403,
+                    format!("Forbidden: missing permission {}", permission),
+                    None,
+                )))),
+                Err(e) => Ok(
+                    req.error_response(ErrorInternalServerError(ApiResponseError::new(
+                        500,
+                        e.to_string(),
+                        None,
+                    ))),
+                ),
+            }
+        }
+        .boxed_local()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RequirePermission;
+    use crate::{
+        core::utils::test_utils::{create_test_app_state, generate_jwt, generate_test_jwt},
+        middlewares::auth_middleware::RequireAuth,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{get, http::StatusCode, test, web, App};
+
+    // Seeded moderator, granted contents.update but not roles.manage
+    const MODERATOR_ID: &str = "a3f45b67-8c3d-4f8b-9e1f-2b7a3e1c7e24";
+
+    #[get("")]
+    async fn protected_handler() -> ApiResponse {
+        ApiResponse::new(200, "Allowed".to_string(), None)
+    }
+
+    #[actix_web::test]
+    async fn test_require_permission() {
+        let app_state = create_test_app_state().await;
+
+        // create and configure the test app
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state.clone())
+                .service(
+                    web::scope("/contents")
+                        .wrap(RequirePermission("contents.update"))
+                        .wrap(RequireAuth {})
+                        .service(protected_handler),
+                )
+                .service(
+                    web::scope("/roles")
+                        .wrap(RequirePermission("roles.manage"))
+                        .wrap(RequireAuth {})
+                        .service(protected_handler),
+                ),
+        )
+        .await;
+
+        let get = |uri: &str, jwt: String| {
+            test::TestRequest::get()
+                .uri(uri)
+                .insert_header((
+                    actix_web::http::header::AUTHORIZATION,
+                    format!("Bearer {}", jwt),
+                ))
+                .to_request()
+        };
+
+        let resp = test::call_service(&app, get("/contents", generate_jwt(MODERATOR_ID))).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let resp = test::call_service(&app, get("/roles", generate_jwt(MODERATOR_ID))).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        let resp = test::call_service(&app, get("/roles", generate_test_jwt())).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+}
@@ -0,0 +1,277 @@
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::error::{ErrorInternalServerError, ErrorUnauthorized, PayloadError};
+use actix_web::{dev, web, HttpMessage};
+use futures_util::future::{ready, LocalBoxFuture, Ready};
+use futures_util::stream::LocalBoxStream;
+use futures_util::FutureExt;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+
+use crate::config::CONFIG;
+use crate::core::app_state::AppState;
+use crate::core::application::{find_active_application_by_key, valid_secrets};
+use crate::core::signature::{
+    canonical_request, check_nonce, check_timestamp, store_nonce, verify_signature,
+};
+use crate::middlewares::app_key_middleware::APP_KEY_HEADER;
+use crate::schema::response::admin::applications::AuthApplication;
+use crate::schema::response::api_response_error::ApiResponseError;
+
+// Headers sent by signing clients together with the app key, allowed by CORS for browsers
+pub const TIMESTAMP_HEADER: &str = "x-timestamp";
+pub const NONCE_HEADER: &str = "x-nonce";
+pub const SIGNATURE_HEADER: &str = "x-signature";
+
+// Headers of a signed request
+struct SignedHeaders {
+    app_key: String,
+    timestamp: i64,
+    nonce: String,
+    signature: String,
+}
+
+impl SignedHeaders {
+    fn from_request(req: &ServiceRequest) -> Result<Self, ApiResponseError> {
+        let header = |name: &str| {
+            req.headers()
+                .get(name)
+                .and_then(|h| h.to_str().ok())
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+                .ok_or_else(|| ApiResponseError::new(401, format!("Missing {} header", name), None))
+        };
+
+        let timestamp = header(TIMESTAMP_HEADER)?.parse::<i64>().map_err(|_| {
+            ApiResponseError::new(401, "Timestamp must be in unix seconds".to_string(), None)
+        })?;
+
+        Ok(Self {
+            app_key: header(APP_KEY_HEADER)?,
+            timestamp,
+            nonce: header(NONCE_HEADER)?,
+            signature: header(SIGNATURE_HEADER)?,
+        })
+    }
+}
+
+/// Requires requests signed with the application secret, see `core::signature`.
+///
+/// Only `/api/check/signed` is signed for now, so partners can test their signing.
+/// Wrap the routes of the server-to-server clients with it as they are added.
+pub struct RequireSignature {}
+
+impl<S> Transform<S, ServiceRequest> for RequireSignature
+where
+    S: Service<
+            ServiceRequest,
+            Response = ServiceResponse<actix_web::body::BoxBody>,
+            Error = actix_web::Error,
+        > + 'static,
+{
+    type Response = ServiceResponse<actix_web::body::BoxBody>;
+    type Error = actix_web::Error;
+    type Transform = SignatureMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(SignatureMiddleware {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+/// Middleware responsible for verifying the signature, timestamp and nonce of a request.
+pub struct SignatureMiddleware<S> {
+    service: Rc<S>,
+}
+
+impl<S> Service<ServiceRequest> for SignatureMiddleware<S>
+where
+    S: Service<
+            ServiceRequest,
+            Response = ServiceResponse<actix_web::body::BoxBody>,
+            Error = actix_web::Error,
+        > + 'static,
+{
+    type Response = ServiceResponse<actix_web::body::BoxBody>;
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, actix_web::Error>>;
+
+    /// Polls the readiness of the wrapped service.
+    fn poll_ready(&self, ctx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(ctx)
+    }
+
+    /// Handles incoming requests.
+    fn call(&self, mut req: ServiceRequest) -> Self::Future {
+        let skew_seconds = CONFIG.applications.signature_skew_seconds;
+
+        // Cheap checks first, before reading the body or the database
+        let signed = SignedHeaders::from_request(&req).and_then(|signed| {
+            check_timestamp(signed.timestamp, chrono::Utc::now(), skew_seconds)?;
+            check_nonce(&signed.nonce)?;
+            Ok(signed)
+        });
+        let signed = match signed {
+            Ok(signed) => signed,
+            Err(e) => return Box::pin(ready(Err(ErrorUnauthorized(e)))),
+        };
+
+        let app_state = req.app_data::<web::Data<AppState>>().unwrap().clone();
+        let srv = Rc::clone(&self.service);
+
+        async move {
+            // The body is hashed, then put back for the handler
+            let body = req.extract::<web::Bytes>().await?;
+            req.set_payload(bytes_to_payload(body.clone()));
+
+            let application = find_active_application_by_key(&app_state.pool, &signed.app_key)
+                .await
+                .map_err(actix_web::Error::from)?;
+
+            let path_and_query = req
+                .uri()
+                .path_and_query()
+                .map(|p| p.as_str())
+                .unwrap_or_else(|| req.path());
+            let canonical = canonical_request(
+                req.method().as_str(),
+                path_and_query,
+                signed.timestamp,
+                &signed.nonce,
+                &body,
+            );
+
+            let secrets = valid_secrets(&application, chrono::Utc::now());
+            if !verify_signature(&secrets, &canonical, &signed.signature) {
+                return Err(ErrorUnauthorized(ApiResponseError::new(
+                    401,
+                    "Invalid signature".to_string(),
+                    None,
+                )));
+            }
+
+            // Only a valid signature can use up a nonce
+            let is_new_nonce = store_nonce(
+                &app_state.pool,
+                &application.id,
+                &signed.nonce,
+                signed.timestamp,
+                skew_seconds,
+            )
+            .await
+            .map_err(|e| {
+                ErrorInternalServerError(ApiResponseError::new(500, e.to_string(), None))
+            })?;
+            if !is_new_nonce {
+                return Err(ErrorUnauthorized(ApiResponseError::new(
+                    401,
+                    "Nonce has already been used".to_string(),
+                    None,
+                )));
+            }
+
+            // Expose the application when the app key middleware did not run
+            if req.extensions().get::<AuthApplication>().is_none() {
+                req.extensions_mut()
+                    .insert::<AuthApplication>(AuthApplication::filter_db(&application));
+            }
+
+            // Call the wrapped service to handle the request
+            srv.call(req).await
+        }
+        .boxed_local()
+    }
+}
+
+fn bytes_to_payload(body: web::Bytes) -> dev::Payload {
+    let stream: LocalBoxStream<'static, Result<web::Bytes, PayloadError>> =
+        Box::pin(futures_util::stream::once(async move { Ok(body) }));
+    dev::Payload::from(stream)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RequireSignature, NONCE_HEADER, SIGNATURE_HEADER, TIMESTAMP_HEADER};
+    use crate::{
+        core::{
+            signature::{canonical_request, sign_request},
+            utils::test_utils::create_test_app_state,
+        },
+        middlewares::app_key_middleware::APP_KEY_HEADER,
+        schema::response::api_response::ApiResponse,
+    };
+    use actix_web::{http::StatusCode, post, test, web, App};
+
+    // Seeded by the applications migration
+    const TEST_APP_KEY: &str = "fe1zev3u5aubxn46j71aijfy5h44wgq882hjunw6qc";
+    const TEST_APP_SECRET: &str = "k1iw4eh4yeswu96eplhzv3gsviydawbos3rorvuz33j";
+
+    #[post("/signed")]
+    async fn signed_handler(body: String) -> ApiResponse {
+        ApiResponse::new(200, body, None)
+    }
+
+    #[actix_web::test]
+    async fn test_require_signature() {
+        let app_state = create_test_app_state().await;
+
+        // create and configure the test app
+        let app = test::init_service(
+            App::new().app_data(app_state.clone()).service(
+                web::scope("/api")
+                    .wrap(RequireSignature {})
+                    .service(signed_handler),
+            ),
+        )
+        .await;
+
+        let body = r#"{"amount":10}"#;
+        let timestamp = chrono::Utc::now().timestamp();
+        let nonce = uuid::Uuid::new_v4().to_string();
+        let signature = sign_request(
+            TEST_APP_SECRET,
+            &canonical_request("POST", "/api/signed", timestamp, &nonce, body.as_bytes()),
+        );
+        let signed_request = || {
+            test::TestRequest::post()
+                .uri("/api/signed")
+                .insert_header((APP_KEY_HEADER, TEST_APP_KEY))
+                .insert_header((TIMESTAMP_HEADER, timestamp.to_string()))
+                .insert_header((NONCE_HEADER, nonce.clone()))
+                .insert_header((SIGNATURE_HEADER, signature.clone()))
+                .set_payload(body)
+                .to_request()
+        };
+
+        // The handler still receives the body
+        let resp: ApiResponse = test::call_and_read_body_json(&app, signed_request()).await;
+        assert_eq!(resp.status, 200);
+        assert_eq!(resp.message, body);
+
+        // Replaying the same request is rejected
+        let resp = test::try_call_service(&app, signed_request())
+            .await
+            .unwrap_err();
+        assert_eq!(
+            resp.as_response_error().status_code(),
+            StatusCode::UNAUTHORIZED
+        );
+
+        // A tampered body breaks the signature
+        let req = test::TestRequest::post()
+            .uri("/api/signed")
+            .insert_header((APP_KEY_HEADER, TEST_APP_KEY))
+            .insert_header((TIMESTAMP_HEADER, timestamp.to_string()))
+            .insert_header((NONCE_HEADER, uuid::Uuid::new_v4().to_string()))
+            .insert_header((SIGNATURE_HEADER, signature.clone()))
+            .set_payload(r#"{"amount":1000}"#)
+            .to_request();
+        let resp = test::try_call_service(&app, req).await.unwrap_err();
+        assert_eq!(
+            resp.as_response_error().status_code(),
+            StatusCode::UNAUTHORIZED
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow, Clone)]
 #[allow(non_snake_case)]
 pub struct ApplicationModel {
     pub id: String,
@@ -8,26 +9,50 @@ pub struct ApplicationModel {
     pub app_version: String,
     pub app_key: String,
     pub app_secret: String,
+    pub previous_app_secret: Option<String>,
+    pub previous_secret_expires_at: Option<chrono::DateTime<chrono::Utc>>,
     pub app_requests: i64,
     pub record_state: i8,
     pub protected: i8,
+    pub created_by: String,
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
     pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+// The secrets are never part of the response, they are shown once when generated
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[allow(non_snake_case)]
 pub struct ApplicationModelResponse {
     pub id: String,
     pub app_name: String,
     pub app_version: String,
     pub app_key: String,
-    pub app_secret: String,
     pub app_requests: i64,
     pub record_state: bool,
     pub protected: bool,
+    pub previous_secret_expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_by: String,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
     pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
 }
+
+impl ApplicationModelResponse {
+    pub fn filter_db(application: &ApplicationModel) -> Self {
+        Self {
+            id: application.id.to_owned(),
+            app_name: application.app_name.to_owned(),
+            app_version: application.app_version.to_owned(),
+            app_key: application.app_key.to_owned(),
+            app_requests: application.app_requests,
+            record_state: application.record_state != 0,
+            protected: application.protected != 0,
+            previous_secret_expires_at: application.previous_secret_expires_at,
+            created_by: application.created_by.to_owned(),
+            created_at: application.created_at.unwrap_or_default(),
+            updated_at: application.updated_at.unwrap_or_default(),
+            deleted_at: application.deleted_at,
+        }
+    }
+}
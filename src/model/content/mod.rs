@@ -1,7 +1,12 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use super::user::{CreatedByResponse, UserModel};
+use super::{
+    content_image_variant::{ContentImageVariantModel, ImageVariantResponse},
+    media::{GalleryImageResponse, GalleryMediaModel},
+    user::{CreatedByResponse, UserModel},
+};
+use crate::core::{enums::ContentState, storage::file_url};
 
 #[derive(Debug, Deserialize, Serialize, sqlx::FromRow, ToSchema, Clone)]
 #[allow(non_snake_case)]
@@ -9,11 +14,21 @@ pub struct ContentModel {
     pub id: String,
     pub content_type: String,
     pub title: String,
+    pub slug: Option<String>,
     pub summary: Option<String>,
     pub details: Option<String>,
     pub content_image: Option<String>,
+    pub content_image_width: Option<u32>,
+    pub content_image_height: Option<u32>,
+    pub content_image_media_id: Option<String>,
+    pub meta_title: Option<String>,
+    pub meta_description: Option<String>,
+    pub canonical_url: Option<String>,
     pub record_state: i8,
     pub protected: i8,
+    pub publish_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub unpublish_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub scheduled_by: Option<String>,
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
     pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
@@ -26,11 +41,24 @@ pub struct ContentModelResponse {
     pub id: String,
     pub content_type: String,
     pub title: String,
+    pub slug: Option<String>,
     pub summary: Option<String>,
     pub details: Option<String>,
     pub contentImage: Option<String>,
-    pub recordState: bool,
+    pub contentImageWidth: Option<u32>,
+    pub contentImageHeight: Option<u32>,
+    // Empty until the variants of the current image are processed
+    pub contentImageVariants: Vec<ImageVariantResponse>,
+    pub contentImageMediaId: Option<String>,
+    // Loaded by the endpoints of a single content, null in the lists
+    pub gallery: Option<Vec<GalleryImageResponse>>,
+    pub metaTitle: Option<String>,
+    pub metaDescription: Option<String>,
+    pub canonicalUrl: Option<String>,
+    pub recordState: String,
     pub protected: bool,
+    pub publishAt: Option<chrono::DateTime<chrono::Utc>>,
+    pub unpublishAt: Option<chrono::DateTime<chrono::Utc>>,
     pub createdAt: chrono::DateTime<chrono::Utc>,
     pub updatedAt: chrono::DateTime<chrono::Utc>,
     pub deletedAt: Option<chrono::DateTime<chrono::Utc>>,
@@ -43,15 +71,45 @@ impl ContentModelResponse {
             id: content.id.to_owned(),
             content_type: content.content_type.to_owned(),
             title: content.title.to_owned(),
+            slug: content.slug.to_owned(),
             summary: content.summary.to_owned(),
             details: content.details.to_owned(),
-            contentImage: content.content_image.to_owned(),
-            recordState: content.record_state != 0,
+            contentImage: content.content_image.as_deref().map(file_url),
+            contentImageWidth: content.content_image_width,
+            contentImageHeight: content.content_image_height,
+            contentImageVariants: Vec::new(),
+            contentImageMediaId: content.content_image_media_id.to_owned(),
+            gallery: None,
+            metaTitle: content.meta_title.to_owned(),
+            metaDescription: content.meta_description.to_owned(),
+            canonicalUrl: content.canonical_url.to_owned(),
+            recordState: ContentState::name_of(content.record_state).to_string(),
             protected: content.protected != 0,
+            publishAt: content.publish_at,
+            unpublishAt: content.unpublish_at,
             createdAt: content.created_at.unwrap(),
             updatedAt: content.updated_at.unwrap(),
             deletedAt: content.deleted_at,
             createdBy: CreatedByResponse::filter_db(&user),
         }
     }
+
+    // Variants are loaded apart, only the ones of this content are kept
+    pub fn with_image_variants(mut self, variants: &[ContentImageVariantModel]) -> Self {
+        self.contentImageVariants = ImageVariantResponse::of_content(&self.id, variants);
+        self
+    }
+
+    pub fn with_gallery(mut self, gallery: &[GalleryMediaModel]) -> Self {
+        self.gallery = Some(GalleryImageResponse::of_content(&self.id, gallery));
+        self
+    }
+}
+
+// A published content with the name of its author, read by the public API
+#[derive(Debug, sqlx::FromRow)]
+pub struct PublishedContentModel {
+    #[sqlx(flatten)]
+    pub content: ContentModel,
+    pub author_name: String,
 }
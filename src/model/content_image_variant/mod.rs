@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::core::storage::file_url;
+
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow, Clone)]
+pub struct ContentImageVariantModel {
+    pub id: i64,
+    pub content_id: String,
+    // Key of the uploaded image the variant was made from
+    pub source_key: String,
+    pub size: String,
+    pub format: String,
+    pub storage_key: String,
+    pub width: u32,
+    pub height: u32,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct ImageVariantResponse {
+    /// One of thumbnail, medium, large
+    pub size: String,
+    /// One of webp, jpeg
+    pub format: String,
+    pub url: String,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl ImageVariantResponse {
+    pub fn filter_db(variant: &ContentImageVariantModel) -> Self {
+        Self {
+            size: variant.size.to_owned(),
+            format: variant.format.to_owned(),
+            url: file_url(&variant.storage_key),
+            width: variant.width,
+            height: variant.height,
+        }
+    }
+
+    pub fn of_content(content_id: &str, variants: &[ContentImageVariantModel]) -> Vec<Self> {
+        variants
+            .iter()
+            .filter(|variant| variant.content_id == content_id)
+            .map(Self::filter_db)
+            .collect()
+    }
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow, Clone)]
+pub struct ContentRevisionModel {
+    pub id: i64,
+    pub content_id: String,
+    pub revision: i32,
+    pub title: String,
+    pub summary: Option<String>,
+    pub details: Option<String>,
+    pub user_id: Option<String>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[allow(non_snake_case)]
+pub struct ContentRevisionResponse {
+    pub id: i64,
+    pub contentId: String,
+    pub revision: i32,
+    pub title: String,
+    pub summary: Option<String>,
+    pub details: Option<String>,
+    pub userId: Option<String>,
+    pub createdAt: chrono::DateTime<chrono::Utc>,
+}
+
+impl ContentRevisionResponse {
+    pub fn filter_db(revision: &ContentRevisionModel) -> Self {
+        Self {
+            id: revision.id,
+            contentId: revision.content_id.to_owned(),
+            revision: revision.revision,
+            title: revision.title.to_owned(),
+            summary: revision.summary.to_owned(),
+            details: revision.details.to_owned(),
+            userId: revision.user_id.to_owned(),
+            createdAt: revision.created_at.unwrap(),
+        }
+    }
+}
+
+// A field that differs between two revisions
+#[derive(Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+pub struct FieldDiffResponse {
+    pub field: String,
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::core::enums::ContentState;
+
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow, Clone)]
+pub struct ContentTransitionModel {
+    pub id: i64,
+    pub content_id: String,
+    pub from_state: i8,
+    pub to_state: i8,
+    pub user_id: Option<String>,
+    pub note: Option<String>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[allow(non_snake_case)]
+pub struct ContentTransitionResponse {
+    pub id: i64,
+    pub contentId: String,
+    pub fromState: String,
+    pub toState: String,
+    pub userId: Option<String>,
+    pub note: Option<String>,
+    pub createdAt: chrono::DateTime<chrono::Utc>,
+}
+
+impl ContentTransitionResponse {
+    pub fn filter_db(transition: &ContentTransitionModel) -> Self {
+        Self {
+            id: transition.id,
+            contentId: transition.content_id.to_owned(),
+            fromState: ContentState::name_of(transition.from_state).to_string(),
+            toState: ContentState::name_of(transition.to_state).to_string(),
+            userId: transition.user_id.to_owned(),
+            note: transition.note.to_owned(),
+            createdAt: transition.created_at.unwrap(),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow, ToSchema, Clone)]
+#[allow(non_snake_case)]
+pub struct ImpersonationLogModel {
+    pub id: i64,
+    pub impersonator_id: Option<String>,
+    pub user_id: Option<String>,
+    pub token_id: String,
+    pub event: String,
+    pub method: String,
+    pub path: String,
+    pub ip_address: Option<String>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+}
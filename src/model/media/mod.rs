@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::core::storage::file_url;
+
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow, Clone)]
+pub struct MediaModel {
+    pub id: String,
+    pub storage_key: String,
+    pub file_name: String,
+    pub mime_type: String,
+    pub size_bytes: u64,
+    pub width: u32,
+    pub height: u32,
+    pub alt_text: Option<String>,
+    // Hex SHA-256 of the file
+    pub checksum: String,
+    pub uploaded_by: Option<String>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[allow(non_snake_case)]
+pub struct MediaModelResponse {
+    pub id: String,
+    pub url: String,
+    pub fileName: String,
+    pub mimeType: String,
+    pub sizeBytes: u64,
+    pub width: u32,
+    pub height: u32,
+    pub altText: Option<String>,
+    pub checksum: String,
+    pub uploadedBy: Option<String>,
+    pub createdAt: chrono::DateTime<chrono::Utc>,
+    pub updatedAt: chrono::DateTime<chrono::Utc>,
+}
+
+impl MediaModelResponse {
+    pub fn filter_db(media: &MediaModel) -> Self {
+        Self {
+            id: media.id.to_owned(),
+            url: file_url(&media.storage_key),
+            fileName: media.file_name.to_owned(),
+            mimeType: media.mime_type.to_owned(),
+            sizeBytes: media.size_bytes,
+            width: media.width,
+            height: media.height,
+            altText: media.alt_text.to_owned(),
+            checksum: media.checksum.to_owned(),
+            uploadedBy: media.uploaded_by.to_owned(),
+            createdAt: media.created_at.unwrap(),
+            updatedAt: media.updated_at.unwrap(),
+        }
+    }
+}
+
+// A media of a content gallery
+#[derive(Debug, sqlx::FromRow)]
+pub struct GalleryMediaModel {
+    pub content_id: String,
+    #[sqlx(flatten)]
+    pub media: MediaModel,
+}
+
+// Images of a gallery as the contents show them
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[allow(non_snake_case)]
+pub struct GalleryImageResponse {
+    pub mediaId: String,
+    pub url: String,
+    pub altText: Option<String>,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl GalleryImageResponse {
+    pub fn filter_db(media: &MediaModel) -> Self {
+        Self {
+            mediaId: media.id.to_owned(),
+            url: file_url(&media.storage_key),
+            altText: media.alt_text.to_owned(),
+            width: media.width,
+            height: media.height,
+        }
+    }
+
+    pub fn of_content(content_id: &str, gallery: &[GalleryMediaModel]) -> Vec<Self> {
+        gallery
+            .iter()
+            .filter(|image| image.content_id == content_id)
+            .map(|image| Self::filter_db(&image.media))
+            .collect()
+    }
+}
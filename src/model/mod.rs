@@ -1,3 +1,13 @@
 pub mod application;
 pub mod content;
+pub mod content_image_variant;
+pub mod content_revision;
+pub mod content_transition;
+pub mod impersonation_log;
+pub mod media;
+pub mod otp_rate_limit;
+pub mod role;
+pub mod session;
+pub mod two_factor;
 pub mod user;
+pub mod user_otp;
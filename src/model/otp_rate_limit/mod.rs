@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, sqlx::FromRow, Clone)]
+pub struct OtpRateLimitModel {
+    pub action: String,
+    pub subject_type: String,
+    pub subject: String,
+    pub attempts: i32,
+    pub window_started_at: chrono::DateTime<chrono::Utc>,
+    pub last_attempt_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub locked_until: Option<chrono::DateTime<chrono::Utc>>,
+}
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow, Clone)]
+#[allow(non_snake_case)]
+pub struct RoleModel {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub protected: i8,
+    pub requires_two_factor: i8,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow, ToSchema, Clone)]
+#[allow(non_snake_case)]
+pub struct PermissionModel {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[allow(non_snake_case)]
+pub struct RoleModelResponse {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub protected: bool,
+    pub requires_two_factor: bool,
+    pub permissions: Vec<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl RoleModelResponse {
+    pub fn filter_db(role: &RoleModel, permissions: Vec<String>) -> Self {
+        Self {
+            id: role.id,
+            name: role.name.to_owned(),
+            description: role.description.to_owned(),
+            protected: role.protected != 0,
+            requires_two_factor: role.requires_two_factor != 0,
+            permissions,
+            created_at: role.created_at.unwrap_or_default(),
+            updated_at: role.updated_at.unwrap_or_default(),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, sqlx::FromRow, Clone)]
+pub struct TwoFactorModel {
+    pub user_id: String,
+    pub secret: String,
+    pub confirmed_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub last_used_step: Option<i64>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
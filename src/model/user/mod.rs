@@ -1,21 +1,25 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use crate::core::avatar::avatar_url;
+
 #[derive(Debug, Deserialize, Serialize, sqlx::FromRow, ToSchema, Clone)]
 #[allow(non_snake_case)]
 pub struct UserModel {
     pub id: String,
     pub name: String,
     pub mobile: String,
-    pub mobile_token: Option<String>,
-    pub mobile_token_expire_at: Option<chrono::DateTime<chrono::Utc>>,
     pub email: Option<String>,
     pub gender: Option<String>,
+    // Storage key of the uploaded avatar
+    pub avatar: Option<String>,
     pub role_id: i32,
     pub active: i8,
     pub protected: i8,
+    pub verification_expires_at: Option<chrono::DateTime<chrono::Utc>>,
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
@@ -25,6 +29,7 @@ pub struct CreatedByResponse {
     pub name: String,
     pub roleId: i32,
     pub active: bool,
+    pub avatarUrl: String,
 }
 
 impl CreatedByResponse {
@@ -34,6 +39,7 @@ impl CreatedByResponse {
             name: user.name.to_owned(),
             roleId: user.role_id.to_owned(),
             active: user.active != 0,
+            avatarUrl: avatar_url(user),
         }
     }
 }
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, sqlx::FromRow, Clone)]
+pub struct UserOtpModel {
+    pub user_id: String,
+    pub code_hash: String,
+    pub salt: String,
+    pub attempts: i32,
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+}
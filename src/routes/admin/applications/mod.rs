@@ -0,0 +1,30 @@
+use actix_web::web;
+
+use crate::{
+    config::CONFIG,
+    handlers::admin::applications::{
+        create_application::create_application_handler,
+        delete_application::delete_application_handler,
+        get_application_by_id::get_application_by_id_handler,
+        get_applications::get_applications_handler,
+        rotate_application_secret::rotate_application_secret_handler,
+        update_application::update_application_handler,
+    },
+    middlewares::{
+        auth_admin_middleware::RequireAdminAuth, permission_middleware::RequirePermission,
+    },
+};
+
+pub fn config(conf: &mut web::ServiceConfig) {
+    let scope = web::scope("/applications")
+        .wrap(RequirePermission("applications.manage"))
+        .wrap(RequireAdminAuth::new().enforce_two_factor(CONFIG.two_factor.enforce_for_admins))
+        .service(get_applications_handler)
+        .service(get_application_by_id_handler)
+        .service(create_application_handler)
+        .service(update_application_handler)
+        .service(delete_application_handler)
+        .service(rotate_application_secret_handler);
+
+    conf.service(scope);
+}
@@ -1,23 +1,38 @@
 use actix_web::web;
 
 use crate::{
-    core::enums::UserRole,
+    config::CONFIG,
     handlers::admin::contents::{
         create_contents::create_contents_handler, delete_content::delete_contents_handler,
-        get_content_by_id::get_content_by_id_handler, get_contents::get_contents_handler,
-        update_contents::update_contents_handler,
+        get_content_by_id::get_content_by_id_handler,
+        get_content_revision_diff::get_content_revision_diff_handler,
+        get_content_revisions::get_content_revisions_handler,
+        get_content_transitions::get_content_transitions_handler,
+        get_contents::get_contents_handler, purge_content::purge_content_handler,
+        restore_content::restore_content_handler, rollback_content::rollback_content_handler,
+        transition_content::transition_content_handler, update_contents::update_contents_handler,
+        upload_content_image::upload_content_image_handler,
     },
     middlewares::auth_admin_middleware::RequireAdminAuth,
 };
 
 pub fn config(conf: &mut web::ServiceConfig) {
+    // Each endpoint also requires its contents.* permission
     let scope = web::scope("/contents")
-        .wrap(RequireAdminAuth::allowed_roles(vec![UserRole::Admin]))
+        .wrap(RequireAdminAuth::new().enforce_two_factor(CONFIG.two_factor.enforce_for_admins))
         .service(get_contents_handler)
         .service(get_content_by_id_handler)
         .service(create_contents_handler)
         .service(delete_contents_handler)
-        .service(update_contents_handler);
+        .service(update_contents_handler)
+        .service(restore_content_handler)
+        .service(purge_content_handler)
+        .service(transition_content_handler)
+        .service(get_content_transitions_handler)
+        .service(get_content_revisions_handler)
+        .service(get_content_revision_diff_handler)
+        .service(rollback_content_handler)
+        .service(upload_content_image_handler);
 
     conf.service(scope);
 }
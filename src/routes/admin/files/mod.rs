@@ -0,0 +1,15 @@
+use actix_web::web;
+
+use crate::{
+    config::CONFIG, handlers::admin::files::sign_file::sign_file_handler,
+    middlewares::auth_admin_middleware::RequireAdminAuth,
+};
+
+pub fn config(conf: &mut web::ServiceConfig) {
+    // Each endpoint also requires the files.share permission
+    let scope = web::scope("/files")
+        .wrap(RequireAdminAuth::new().enforce_two_factor(CONFIG.two_factor.enforce_for_admins))
+        .service(sign_file_handler);
+
+    conf.service(scope);
+}
@@ -0,0 +1,24 @@
+use actix_web::web;
+
+use crate::{
+    config::CONFIG,
+    handlers::admin::media::{
+        delete_media::delete_media_handler, get_media::get_media_handler,
+        get_media_by_id::get_media_by_id_handler, update_media::update_media_handler,
+        upload_media::upload_media_handler,
+    },
+    middlewares::auth_admin_middleware::RequireAdminAuth,
+};
+
+pub fn config(conf: &mut web::ServiceConfig) {
+    // Each endpoint also requires its media.* permission
+    let scope = web::scope("/media")
+        .wrap(RequireAdminAuth::new().enforce_two_factor(CONFIG.two_factor.enforce_for_admins))
+        .service(get_media_handler)
+        .service(get_media_by_id_handler)
+        .service(upload_media_handler)
+        .service(update_media_handler)
+        .service(delete_media_handler);
+
+    conf.service(scope);
+}
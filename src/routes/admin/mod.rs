@@ -1,2 +1,6 @@
+pub mod applications;
 pub mod content;
+pub mod files;
+pub mod media;
+pub mod roles;
 pub mod user;
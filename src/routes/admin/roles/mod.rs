@@ -0,0 +1,31 @@
+use actix_web::web;
+
+use crate::{
+    config::CONFIG,
+    handlers::admin::roles::{
+        create_role::create_role_handler, delete_role::delete_role_handler,
+        get_permissions::get_permissions_handler, get_role_by_id::get_role_by_id_handler,
+        get_roles::get_roles_handler, update_role::update_role_handler,
+    },
+    middlewares::{
+        auth_admin_middleware::RequireAdminAuth, permission_middleware::RequirePermission,
+    },
+};
+
+pub fn config(conf: &mut web::ServiceConfig) {
+    let roles_scope = web::scope("/roles")
+        .wrap(RequirePermission("roles.manage"))
+        .wrap(RequireAdminAuth::new().enforce_two_factor(CONFIG.two_factor.enforce_for_admins))
+        .service(get_roles_handler)
+        .service(get_role_by_id_handler)
+        .service(create_role_handler)
+        .service(update_role_handler)
+        .service(delete_role_handler);
+
+    let permissions_scope = web::scope("/permissions")
+        .wrap(RequirePermission("roles.manage"))
+        .wrap(RequireAdminAuth::new().enforce_two_factor(CONFIG.two_factor.enforce_for_admins))
+        .service(get_permissions_handler);
+
+    conf.service(roles_scope).service(permissions_scope);
+}
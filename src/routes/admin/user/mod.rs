@@ -1,23 +1,34 @@
 use actix_web::web;
 
 use crate::{
-    core::enums::UserRole,
+    config::CONFIG,
     handlers::admin::user::{
         create_user::create_user_handler, delete_user::delete_user_handler,
+        get_impersonation_logs::get_impersonation_logs_handler,
         get_user_by_id::get_user_by_id_handler, get_users::get_users_handler,
+        impersonate_user::impersonate_user_handler, purge_user::purge_user_handler,
+        restore_user::restore_user_handler, revoke_user_sessions::revoke_user_sessions_handler,
         update_user::update_user_handler,
     },
-    middlewares::auth_admin_middleware::RequireAdminAuth,
+    middlewares::{
+        auth_admin_middleware::RequireAdminAuth, permission_middleware::RequirePermission,
+    },
 };
 
 pub fn config(conf: &mut web::ServiceConfig) {
     let scope = web::scope("/users")
-        .wrap(RequireAdminAuth::allowed_roles(vec![UserRole::Admin]))
+        .wrap(RequirePermission("users.manage"))
+        .wrap(RequireAdminAuth::new().enforce_two_factor(CONFIG.two_factor.enforce_for_admins))
         .service(get_users_handler)
         .service(get_user_by_id_handler)
         .service(create_user_handler)
         .service(delete_user_handler)
-        .service(update_user_handler);
+        .service(update_user_handler)
+        .service(restore_user_handler)
+        .service(purge_user_handler)
+        .service(revoke_user_sessions_handler)
+        .service(impersonate_user_handler)
+        .service(get_impersonation_logs_handler);
 
     conf.service(scope);
 }
@@ -2,7 +2,13 @@ use actix_web::web;
 
 use crate::{
     handlers::auth::{
-        login::login_user_handler, logout::logout_user_handler, register::register_user_handler,
+        login::login_user_handler,
+        logout::{logout_all_user_handler, logout_user_handler},
+        refresh::refresh_token_handler,
+        register::register_user_handler,
+        two_factor::{
+            confirm_two_factor_handler, disable_two_factor_handler, enroll_two_factor_handler,
+        },
         verify::verify_otp_handler,
     },
     middlewares::auth_middleware::RequireAuth,
@@ -27,7 +33,20 @@ pub fn config(conf: &mut web::ServiceConfig) {
                 .wrap(RequireAuth {})
                 .route(web::post().to(logout_user_handler)),
         )
-        .service(verify_otp_handler);
+        .service(
+            web::resource("/logout/all")
+                .wrap(RequireAuth {})
+                .route(web::post().to(logout_all_user_handler)),
+        )
+        .service(verify_otp_handler)
+        .service(refresh_token_handler)
+        .service(
+            web::scope("/2fa")
+                .wrap(RequireAuth {})
+                .service(enroll_two_factor_handler)
+                .service(confirm_two_factor_handler)
+                .service(disable_two_factor_handler),
+        );
 
     conf.service(scope);
 }
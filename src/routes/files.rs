@@ -0,0 +1,10 @@
+use actix_web::web;
+
+use crate::handlers::files::{
+    get_public_file::get_public_file_handler, get_signed_file::get_signed_file_handler,
+};
+
+pub fn config(conf: &mut web::ServiceConfig) {
+    conf.service(web::scope("/public").service(get_public_file_handler))
+        .service(web::scope("/files").service(get_signed_file_handler));
+}
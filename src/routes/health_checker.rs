@@ -1,6 +1,8 @@
 use crate::{
-    handlers::health_checker::{health_checker_auth_handler, health_checker_handler},
-    middlewares::auth_middleware::RequireAuth,
+    handlers::health_checker::{
+        health_checker_auth_handler, health_checker_handler, health_checker_signed_handler,
+    },
+    middlewares::{auth_middleware::RequireAuth, signature_middleware::RequireSignature},
 };
 use actix_web::web;
 
@@ -11,6 +13,12 @@ pub fn config(conf: &mut web::ServiceConfig) {
             web::resource("/auth")
                 .to(health_checker_auth_handler)
                 .wrap(RequireAuth {}),
+        )
+        // The only signed route, for partners to check their signatures
+        .service(
+            web::resource("/signed")
+                .to(health_checker_signed_handler)
+                .wrap(RequireSignature {}),
         );
 
     conf.service(auth_scope);
@@ -1,4 +1,5 @@
 pub mod admin;
 pub mod auth;
+pub mod files;
 pub mod health_checker;
 pub mod project;
@@ -0,0 +1,15 @@
+use actix_web::web;
+
+use crate::handlers::project::contents::{
+    get_content_by_slug::get_public_content_by_slug_handler,
+    get_contents::get_public_contents_handler,
+};
+
+// Published contents are readable without a user, the app key is still required
+pub fn config(conf: &mut web::ServiceConfig) {
+    let scope = web::scope("/contents")
+        .service(get_public_contents_handler)
+        .service(get_public_content_by_slug_handler);
+
+    conf.service(scope);
+}
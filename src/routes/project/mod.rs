@@ -1 +1,2 @@
+pub mod contents;
 pub mod profile;
@@ -2,7 +2,8 @@ use actix_web::web;
 
 use crate::{
     handlers::project::profile::{
-        get_profile::profile_handler, update_profile::update_profile_handler,
+        delete_avatar::delete_avatar_handler, get_profile::profile_handler,
+        update_profile::update_profile_handler, upload_avatar::upload_avatar_handler,
     },
     middlewares::auth_middleware::RequireAuth,
 };
@@ -11,6 +12,8 @@ pub fn config(conf: &mut web::ServiceConfig) {
     let scope = web::scope("/profile")
         .wrap(RequireAuth {})
         .service(update_profile_handler)
+        .service(upload_avatar_handler)
+        .service(delete_avatar_handler)
         .service(profile_handler);
 
     conf.service(scope);
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct CreateApplicationSchema {
+    pub app_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_version: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, Clone)]
+pub struct UpdateApplicationSchema {
+    pub app_name: Option<String>,
+    pub app_version: Option<String>,
+    pub record_state: Option<bool>,
+    pub protected: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, IntoParams)]
+pub struct ApplicationsFilterOptions {
+    #[param(example = 10)]
+    pub limit: Option<i64>,
+    #[param(example = 1)]
+    pub page: Option<i64>,
+    #[param(example = "actix")]
+    pub app_name: Option<String>,
+}
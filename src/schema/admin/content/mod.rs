@@ -1,7 +1,9 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
 
-#[derive(Serialize, Deserialize, Debug, ToSchema)]
+#[derive(Serialize, Deserialize, Debug, ToSchema, Validate)]
 pub struct CreateContentSchema {
     pub content_type: String,
     pub title: String,
@@ -9,9 +11,36 @@ pub struct CreateContentSchema {
     pub summary: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub details: Option<String>,
+    /// Publish the content at this time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub publish_at: Option<DateTime<Utc>>,
+    /// Archive the content at this time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unpublish_at: Option<DateTime<Utc>>,
+    /// Generated from the title when it is not sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 255, message = "Meta title must be at most 255 characters"))]
+    pub meta_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 500, message = "Meta description must be at most 500 characters"))]
+    pub meta_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(
+        url(message = "Canonical URL must be a valid URL"),
+        length(max = 2048, message = "Canonical URL must be at most 2048 characters")
+    )]
+    pub canonical_url: Option<String>,
+    /// Media used as the image of the content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_media_id: Option<String>,
+    /// Media of the gallery in order, replaces the current gallery
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gallery: Option<Vec<String>>,
 }
 
-#[derive(Serialize, Deserialize, Debug, ToSchema)]
+#[derive(Serialize, Deserialize, Debug, ToSchema, Validate)]
 pub struct UpdateContentSchema {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content_type: Option<String>,
@@ -21,6 +50,55 @@ pub struct UpdateContentSchema {
     pub summary: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub details: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protected: Option<bool>,
+    /// Publish the content at this time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub publish_at: Option<DateTime<Utc>>,
+    /// Archive the content at this time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unpublish_at: Option<DateTime<Utc>>,
+    /// Cancel the publish and unpublish times, times sent with it are scheduled again
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clear_schedule: Option<bool>,
+    /// Generated from the title when it is not sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 255, message = "Meta title must be at most 255 characters"))]
+    pub meta_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 500, message = "Meta description must be at most 500 characters"))]
+    pub meta_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(
+        url(message = "Canonical URL must be a valid URL"),
+        length(max = 2048, message = "Canonical URL must be at most 2048 characters")
+    )]
+    pub canonical_url: Option<String>,
+    /// Media used as the image of the content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_media_id: Option<String>,
+    /// Media of the gallery in order, replaces the current gallery
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gallery: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct TransitionContentSchema {
+    pub state: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, IntoParams)]
+pub struct RevisionDiffOptions {
+    /// Revision to compare from
+    #[param(example = 1)]
+    pub from: i32,
+    /// Revision to compare to
+    #[param(example = 2)]
+    pub to: i32,
 }
 
 #[derive(Serialize, Deserialize, Debug, ToSchema, IntoParams)]
@@ -33,6 +111,24 @@ pub struct ContentsFilterOptions {
     pub content_type: Option<String>,
     #[param(example = "content")]
     pub title: Option<String>,
+    /// One of draft, in_review, published, archived
+    #[param(example = "published")]
+    pub state: Option<String>,
     #[param(example = "false")]
     pub export: Option<bool>,
+    /// Include soft deleted rows
+    #[param(example = "false")]
+    pub with_trashed: Option<bool>,
+    /// Only soft deleted rows
+    #[param(example = "false")]
+    pub only_trashed: Option<bool>,
+}
+
+// Multipart body of the image uploads, only read by the API documentation
+#[derive(Debug, ToSchema)]
+#[allow(dead_code)]
+pub struct UploadImageSchema {
+    /// JPEG, PNG, GIF or WebP file
+    #[schema(value_type = String, format = Binary)]
+    pub image: Vec<u8>,
 }
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct SignFileSchema {
+    /// Path of the file in the private directory
+    #[schema(example = "exports/report.csv")]
+    pub path: String,
+}
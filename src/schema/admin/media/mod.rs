@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+// Multipart body of the media uploads, only read by the API documentation
+#[derive(Debug, ToSchema)]
+#[allow(dead_code)]
+pub struct UploadMediaSchema {
+    /// JPEG, PNG, GIF or WebP file
+    #[schema(value_type = String, format = Binary)]
+    pub image: Vec<u8>,
+    /// Text describing the image
+    pub alt_text: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct UpdateMediaSchema {
+    /// Text describing the image, cleared when empty
+    pub alt_text: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, IntoParams)]
+pub struct MediaFilterOptions {
+    #[param(example = 10)]
+    pub limit: Option<i64>,
+    #[param(example = 1)]
+    pub page: Option<i64>,
+    /// Matches the file name or the alt text
+    #[param(example = "logo")]
+    pub search: Option<String>,
+    #[param(example = "image/png")]
+    pub mime_type: Option<String>,
+}
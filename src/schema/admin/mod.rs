@@ -1,2 +1,6 @@
+pub mod application;
 pub mod content;
+pub mod file;
+pub mod media;
+pub mod role;
 pub mod user;
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct CreateRoleSchema {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub permissions: Vec<String>,
+    // Members have to enable two-factor authentication to use the admin endpoints
+    #[serde(default)]
+    pub requires_two_factor: bool,
+}
+
+// permissions replaces the whole set of permissions of the role when given
+#[derive(Serialize, Deserialize, Debug, ToSchema, Clone)]
+pub struct UpdateRoleSchema {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub permissions: Option<Vec<String>>,
+    pub requires_two_factor: Option<bool>,
+    // Changing it requires the records.protect permission
+    pub protected: Option<bool>,
+}
@@ -30,4 +30,18 @@ pub struct UsersFilterOptions {
     pub mobile: Option<String>,
     #[param(example = "false")]
     pub export: Option<bool>,
+    /// Include soft deleted rows
+    #[param(example = "false")]
+    pub with_trashed: Option<bool>,
+    /// Only soft deleted rows
+    #[param(example = "false")]
+    pub only_trashed: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, IntoParams)]
+pub struct ImpersonationLogsFilterOptions {
+    #[param(example = 10)]
+    pub limit: Option<i64>,
+    #[param(example = 1)]
+    pub page: Option<i64>,
 }
@@ -17,3 +17,12 @@ pub struct FilterOptions {
 pub struct ParamOptions {
     pub id: String,
 }
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct SignedFileOptions {
+    /// Unix timestamp the URL stops working at
+    #[param(example = 1721900000)]
+    pub expires: i64,
+    /// Hex HMAC-SHA256 of the request with the signing key
+    pub signature: String,
+}
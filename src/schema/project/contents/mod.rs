@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, IntoParams)]
+pub struct PublicContentsFilterOptions {
+    #[param(example = 10)]
+    pub limit: Option<i64>,
+    #[param(example = 1)]
+    pub page: Option<i64>,
+    #[param(example = "page")]
+    pub content_type: Option<String>,
+}
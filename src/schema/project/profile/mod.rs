@@ -1 +1,2 @@
 pub mod update_profile;
+pub mod upload_avatar;
@@ -0,0 +1,16 @@
+use utoipa::ToSchema;
+
+// Multipart body of the avatar uploads, only read by the API documentation
+#[derive(Debug, ToSchema)]
+#[allow(dead_code)]
+pub struct UploadAvatarSchema {
+    /// JPEG, PNG, GIF or WebP file
+    #[schema(value_type = String, format = Binary)]
+    pub avatar: Vec<u8>,
+    /// Left of the square to keep, in pixels
+    pub x: Option<u32>,
+    /// Top of the square to keep, in pixels
+    pub y: Option<u32>,
+    /// Side of the square to keep, in pixels
+    pub size: Option<u32>,
+}
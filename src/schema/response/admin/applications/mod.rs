@@ -0,0 +1,51 @@
+use std::future;
+
+use actix_web::{FromRequest, HttpMessage as _};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    model::application::ApplicationModel, schema::response::api_response_error::ApiResponseError,
+};
+
+// Application authenticated by its app key, the secret is never exposed to handlers
+#[derive(Debug, Deserialize, Serialize, ToSchema, Clone)]
+pub struct AuthApplication {
+    pub id: String,
+    pub app_name: String,
+    pub app_version: String,
+    pub app_key: String,
+    pub app_requests: i64,
+}
+
+impl AuthApplication {
+    pub fn filter_db(application: &ApplicationModel) -> Self {
+        Self {
+            id: application.id.to_owned(),
+            app_name: application.app_name.to_owned(),
+            app_version: application.app_version.to_owned(),
+            app_key: application.app_key.to_owned(),
+            app_requests: application.app_requests,
+        }
+    }
+}
+
+impl FromRequest for AuthApplication {
+    type Error = ApiResponseError;
+
+    type Future = future::Ready<Result<Self, Self::Error>>;
+
+    fn from_request(
+        req: &actix_web::HttpRequest,
+        _payload: &mut actix_web::dev::Payload,
+    ) -> std::future::Ready<Result<AuthApplication, ApiResponseError>> {
+        match req.extensions().get::<AuthApplication>() {
+            Some(application) => future::ready(Ok((*application).clone())),
+            None => future::ready(Err(ApiResponseError::new(
+                400,
+                "Bad Application Data".to_string(),
+                None,
+            ))),
+        }
+    }
+}
@@ -1 +1,2 @@
+pub mod applications;
 pub mod users;
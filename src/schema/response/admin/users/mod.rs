@@ -5,7 +5,8 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 use crate::{
-    core::enums::UserRole, model::user::UserModel,
+    core::{avatar::avatar_url, enums::UserRole},
+    model::user::UserModel,
     schema::response::api_response_error::ApiResponseError,
 };
 
@@ -18,10 +19,12 @@ pub struct UserModelResponse {
     pub email: Option<String>,
     pub gender: Option<String>,
     pub role: String,
+    pub avatarUrl: String,
     pub active: bool,
     pub protected: bool,
     pub createdAt: chrono::DateTime<chrono::Utc>,
     pub updatedAt: chrono::DateTime<chrono::Utc>,
+    pub deletedAt: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 impl UserModelResponse {
@@ -39,10 +42,12 @@ impl UserModelResponse {
             email: user.email.to_owned(),
             gender: user.gender.to_owned(),
             role,
+            avatarUrl: avatar_url(user),
             active: user.active != 0,
             protected: user.protected != 0,
             createdAt: user.created_at.unwrap(),
             updatedAt: user.updated_at.unwrap(),
+            deletedAt: user.deleted_at,
         }
     }
 }
@@ -60,6 +65,9 @@ pub struct AuthUser {
     pub protected: bool,
     pub createdAt: chrono::DateTime<chrono::Utc>,
     pub updatedAt: chrono::DateTime<chrono::Utc>,
+    // Admin acting as the user with an impersonation token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impersonator: Option<String>,
 }
 
 impl AuthUser {
@@ -75,6 +83,7 @@ impl AuthUser {
             protected: user.protected != 0,
             createdAt: user.created_at.unwrap(),
             updatedAt: user.updated_at.unwrap(),
+            impersonator: None,
         }
     }
 }
@@ -1,6 +1,10 @@
 use std::fmt::Display;
 
-use actix_web::{body::BoxBody, http::StatusCode, web, HttpResponse, Responder, ResponseError};
+use actix_web::{
+    body::BoxBody,
+    http::{header, StatusCode},
+    web, HttpResponse, Responder, ResponseError,
+};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -15,6 +19,8 @@ pub struct ApiResponseError {
     pub message: String,
     pub status: u16,
     pub validation_errors: Option<Vec<ValidationErrorDetail>>,
+    #[serde(skip)]
+    pub retry_after: Option<u64>, // Seconds sent in the `Retry-After` header
 }
 impl ApiResponseError {
     pub fn new(
@@ -26,22 +32,36 @@ impl ApiResponseError {
             status,
             message,
             validation_errors,
+            retry_after: None,
         }
     }
-}
 
-impl Responder for ApiResponseError {
-    type Body = BoxBody;
+    pub fn with_retry_after(mut self, seconds: u64) -> Self {
+        self.retry_after = Some(seconds);
+        self
+    }
 
-    fn respond_to(self, _req: &actix_web::HttpRequest) -> HttpResponse<Self::Body> {
+    fn build_response(&self) -> HttpResponse<BoxBody> {
         let json_body = serde_json::to_string(&self).unwrap();
+        let mut response = HttpResponse::build(StatusCode::from_u16(self.status).unwrap());
+        if let Some(seconds) = self.retry_after {
+            response.insert_header((header::RETRY_AFTER, seconds.to_string()));
+        }
 
-        HttpResponse::build(StatusCode::from_u16(self.status).unwrap())
+        response
             .content_type("application/json")
             .body(BoxBody::new(web::BytesMut::from(json_body.as_bytes())))
     }
 }
 
+impl Responder for ApiResponseError {
+    type Body = BoxBody;
+
+    fn respond_to(self, _req: &actix_web::HttpRequest) -> HttpResponse<Self::Body> {
+        self.build_response()
+    }
+}
+
 impl Display for ApiResponseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "Error: {}", self.message)
@@ -54,9 +74,6 @@ impl ResponseError for ApiResponseError {
     }
 
     fn error_response(&self) -> HttpResponse<BoxBody> {
-        let json_body = serde_json::to_string(&self).unwrap();
-        HttpResponse::build(StatusCode::from_u16(self.status).unwrap())
-            .content_type("application/json")
-            .body(BoxBody::new(web::BytesMut::from(json_body.as_bytes())))
+        self.build_response()
     }
 }
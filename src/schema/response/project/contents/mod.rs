@@ -0,0 +1,71 @@
+use crate::{
+    core::storage::file_url,
+    model::{
+        content::PublishedContentModel,
+        content_image_variant::{ContentImageVariantModel, ImageVariantResponse},
+        media::{GalleryImageResponse, GalleryMediaModel},
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+// Contents as the public API shows them, without the internal fields of the admin response
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[allow(non_snake_case)]
+pub struct PublicContentResponse {
+    pub id: String,
+    pub slug: String,
+    pub content_type: String,
+    pub title: String,
+    pub summary: Option<String>,
+    pub details: Option<String>,
+    pub contentImage: Option<String>,
+    pub contentImageWidth: Option<u32>,
+    pub contentImageHeight: Option<u32>,
+    pub contentImageVariants: Vec<ImageVariantResponse>,
+    // Loaded for a single content, null in the lists
+    pub gallery: Option<Vec<GalleryImageResponse>>,
+    pub metaTitle: String,
+    pub metaDescription: Option<String>,
+    pub canonicalUrl: Option<String>,
+    pub authorName: String,
+    pub createdAt: chrono::DateTime<chrono::Utc>,
+    pub updatedAt: chrono::DateTime<chrono::Utc>,
+}
+
+impl PublicContentResponse {
+    pub fn new(published: &PublishedContentModel, variants: &[ContentImageVariantModel]) -> Self {
+        let content = &published.content;
+        Self {
+            id: content.id.to_owned(),
+            slug: content.slug.to_owned().unwrap_or_default(),
+            content_type: content.content_type.to_owned(),
+            title: content.title.to_owned(),
+            summary: content.summary.to_owned(),
+            details: content.details.to_owned(),
+            contentImage: content.content_image.as_deref().map(file_url),
+            contentImageWidth: content.content_image_width,
+            contentImageHeight: content.content_image_height,
+            contentImageVariants: ImageVariantResponse::of_content(&content.id, variants),
+            gallery: None,
+            // Contents without SEO fields are described by their title and summary
+            metaTitle: content
+                .meta_title
+                .to_owned()
+                .unwrap_or_else(|| content.title.to_owned()),
+            metaDescription: content
+                .meta_description
+                .to_owned()
+                .or_else(|| content.summary.to_owned()),
+            canonicalUrl: content.canonical_url.to_owned(),
+            authorName: published.author_name.to_owned(),
+            createdAt: content.created_at.unwrap(),
+            updatedAt: content.updated_at.unwrap(),
+        }
+    }
+
+    pub fn with_gallery(mut self, gallery: &[GalleryMediaModel]) -> Self {
+        self.gallery = Some(GalleryImageResponse::of_content(&self.id, gallery));
+        self
+    }
+}
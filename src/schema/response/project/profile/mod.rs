@@ -1,4 +1,4 @@
-use crate::model::user::UserModel;
+use crate::{core::avatar::avatar_url, model::user::UserModel};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -10,6 +10,7 @@ pub struct ProfileResponse {
     pub mobile: String,
     pub email: String,
     pub gender: String,
+    pub avatarUrl: String,
     pub roleId: i32,
     pub active: bool,
     pub protected: bool,
@@ -23,6 +24,7 @@ impl ProfileResponse {
             mobile: profile.mobile.to_owned(),
             email: profile.email.to_owned().unwrap(),
             gender: profile.gender.to_owned().unwrap(),
+            avatarUrl: avatar_url(profile),
             roleId: profile.role_id.to_owned(),
             active: profile.active != 0,
             protected: profile.protected != 0,